@@ -0,0 +1,309 @@
+//! Batch multilinear-opening reduction: many `f_i(z_i) = y_i` to one point.
+//!
+//! Polynomial commitment layers often have to open several multilinears
+//! `f_0, ..., f_{k-1}` over `{0,1}^v` at distinct points `z_i`. This module
+//! reduces all of them to openings at a single random point `r`.
+//!
+//! # Protocol
+//!
+//! 1. The prover sends the claimed values `y_i = f_i(z_i)`; the verifier
+//!    checks them against its own claims and squeezes `γ`.
+//! 2. Both sides run an eq-factored sumcheck (degree 2) for
+//!
+//!    ```text
+//!    Σ_x Σ_i γ^i · eq(z_i, x) · f_i(x) = Σ_i γ^i · y_i
+//!    ```
+//!
+//!    ending at `r`. The prover runs one
+//!    [`EqFactoredProver`] per claim and sends the `γ^i`-combination of
+//!    their round polynomials.
+//! 3. The prover sends `f_i(r)` for every `i`; the verifier checks the
+//!    sumcheck's final claim against `Σ_i γ^i · eq(z_i, r) · f_i(r)`.
+//!
+//! The output is one point `r` with `k` claimed values, which the caller
+//! checks with a single batched PCS opening.
+//!
+//! ```ignore
+//! // prover
+//! let claim = batch_opening_prove(polys, &points, &mut pt);
+//! // verifier
+//! let claim = batch_opening_verify(&points, &values, &mut vt)?;
+//! // caller: check f_i(claim.point) == claim.values[i] (e.g. PCS opening)
+//! ```
+
+use crate::field::SumcheckField;
+use crate::hypercube::eq_poly_non_binary;
+use crate::polynomial::multilinear::mle_eval;
+use crate::proof::SumcheckError;
+use crate::provers::eq_factored::EqFactoredProver;
+use crate::runner::sumcheck;
+use crate::sumcheck_prover::SumcheckProver;
+use crate::transcript::{ProverTranscript, VerifierTranscript};
+use crate::verifier::sumcheck_verify;
+
+use alloc::{vec, vec::Vec};
+
+/// A reduced claim `f_i(point) = values[i]` for every polynomial.
+///
+/// The caller must check this claim, e.g. with a batched polynomial
+/// commitment opening.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BatchOpeningClaim<F> {
+    /// Common evaluation point in `F^v` (MSB order).
+    pub point: Vec<F>,
+    /// Claimed value of each `f_i` at `point`.
+    pub values: Vec<F>,
+}
+
+/// `Σ_i γ^i · eq(z_i, x) · f_i(x)` as a `γ`-combination of eq-factored
+/// provers sharing the same challenges.
+struct BatchedEqProver<F: SumcheckField> {
+    provers: Vec<EqFactoredProver<F>>,
+    /// `γ^i` for each prover.
+    coeffs: Vec<F>,
+}
+
+impl<F: SumcheckField> SumcheckProver<F> for BatchedEqProver<F> {
+    fn degree(&self) -> usize {
+        2
+    }
+
+    fn round(&mut self, challenge: Option<F>) -> Vec<F> {
+        let mut acc = vec![F::ZERO; 2];
+        for (prover, &c) in self.provers.iter_mut().zip(&self.coeffs) {
+            for (a, t) in acc.iter_mut().zip(prover.round(challenge)) {
+                *a += c * t;
+            }
+        }
+        acc
+    }
+
+    fn finalize(&mut self, last_challenge: F) {
+        for prover in &mut self.provers {
+            prover.finalize(last_challenge);
+        }
+    }
+
+    fn final_value(&self) -> F {
+        self.provers
+            .iter()
+            .zip(&self.coeffs)
+            .map(|(p, &c)| c * p.final_value())
+            .sum()
+    }
+}
+
+/// `[1, γ, γ^2, ..., γ^{k-1}]`.
+fn powers<F: SumcheckField>(gamma: F, k: usize) -> Vec<F> {
+    let mut out = Vec::with_capacity(k);
+    let mut acc = F::ONE;
+    for _ in 0..k {
+        out.push(acc);
+        acc *= gamma;
+    }
+    out
+}
+
+/// Prover side of the batch opening reduction.
+///
+/// `polys[i]` holds the evaluations of `f_i` over `{0,1}^v` (MSB layout)
+/// and `points[i] ∈ F^v` is its opening point. All tables must have length
+/// `2^v`, with `v = points[i].len()` for every `i`.
+///
+/// Sends `y_i = f_i(z_i)` and returns the reduced claim at the sumcheck
+/// point.
+pub fn batch_opening_prove<F: SumcheckField, T: ProverTranscript<F>>(
+    polys: Vec<Vec<F>>,
+    points: &[Vec<F>],
+    transcript: &mut T,
+) -> BatchOpeningClaim<F> {
+    assert_eq!(
+        polys.len(),
+        points.len(),
+        "need exactly one opening point per polynomial"
+    );
+    let num_vars = points.first().map_or(0, Vec::len);
+
+    let mut values = Vec::with_capacity(polys.len());
+    let provers: Vec<EqFactoredProver<F>> = polys
+        .into_iter()
+        .zip(points)
+        .map(|(f, z)| {
+            assert_eq!(z.len(), num_vars, "opening points must share one arity");
+            assert_eq!(
+                f.len(),
+                1 << num_vars,
+                "polynomial must have 2^v evaluations"
+            );
+            values.push(mle_eval(&f, z));
+            EqFactoredProver::new(z.clone(), f)
+        })
+        .collect();
+    for &y in &values {
+        transcript.send(y);
+    }
+
+    let gamma = transcript.challenge();
+    let mut prover = BatchedEqProver {
+        coeffs: powers(gamma, provers.len()),
+        provers,
+    };
+    let proof = sumcheck(&mut prover, num_vars, transcript, |_, _| {});
+
+    let values: Vec<F> = prover.provers.iter().map(|p| p.final_factors().0).collect();
+    for &v in &values {
+        transcript.send(v);
+    }
+
+    BatchOpeningClaim {
+        point: proof.challenges,
+        values,
+    }
+}
+
+/// Verifier side of the batch opening reduction.
+///
+/// `points[i]` and `values[i]` are the claims `f_i(points[i]) = values[i]`
+/// (all points in `F^v`). Returns the reduced claim at the sumcheck point,
+/// which the caller must check.
+///
+/// Errors:
+/// - [`SumcheckError::ClaimMismatch`] for the first value sent by the
+///   prover that differs from `values`.
+/// - [`SumcheckError::FinalEvaluation`] if the sumcheck does not reduce to
+///   `Σ_i γ^i · eq(z_i, r) · f_i(r)`.
+/// - Any error from [`sumcheck_verify`].
+pub fn batch_opening_verify<F: SumcheckField, T: VerifierTranscript<F>>(
+    points: &[Vec<F>],
+    values: &[F],
+    transcript: &mut T,
+) -> Result<BatchOpeningClaim<F>, SumcheckError> {
+    assert_eq!(
+        points.len(),
+        values.len(),
+        "need exactly one value per opening point"
+    );
+    let num_vars = points.first().map_or(0, Vec::len);
+    assert!(
+        points.iter().all(|z| z.len() == num_vars),
+        "opening points must share one arity"
+    );
+    let receive = |transcript: &mut T, round: usize| {
+        transcript
+            .receive()
+            .map_err(|_| SumcheckError::TranscriptError { round })
+    };
+
+    for (index, &y) in values.iter().enumerate() {
+        if receive(transcript, 0)? != y {
+            return Err(SumcheckError::ClaimMismatch { index });
+        }
+    }
+
+    let gamma = transcript.challenge();
+    let coeffs = powers(gamma, values.len());
+    let claimed_sum = coeffs.iter().zip(values).map(|(&c, &y)| c * y).sum();
+    let result = sumcheck_verify(claimed_sum, 2, num_vars, transcript, |_, _| Ok(()))?;
+
+    let mut opened = Vec::with_capacity(values.len());
+    let mut expected = F::ZERO;
+    for (z, &c) in points.iter().zip(&coeffs) {
+        let v = receive(transcript, num_vars)?;
+        expected += c * eq_poly_non_binary(z, &result.challenges) * v;
+        opened.push(v);
+    }
+    if result.final_claim != expected {
+        return Err(SumcheckError::FinalEvaluation);
+    }
+
+    Ok(BatchOpeningClaim {
+        point: result.challenges,
+        values: opened,
+    })
+}
+
+#[cfg(all(test, feature = "arkworks"))]
+mod tests {
+    use super::*;
+    use crate::tests::{ReplayTranscript, F64};
+    use ark_ff::UniformRand;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    fn random_instance(k: usize, num_vars: usize, seed: u64) -> (Vec<Vec<F64>>, Vec<Vec<F64>>) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let polys = (0..k)
+            .map(|_| (0..1 << num_vars).map(|_| F64::rand(&mut rng)).collect())
+            .collect();
+        let points = (0..k)
+            .map(|_| (0..num_vars).map(|_| F64::rand(&mut rng)).collect())
+            .collect();
+        (polys, points)
+    }
+
+    #[test]
+    fn batch_opening_roundtrip() {
+        for num_vars in 0..=6 {
+            for k in [1, 3] {
+                let (polys, points) = random_instance(k, num_vars, 0xC00 ^ num_vars as u64);
+                let values: Vec<F64> = polys
+                    .iter()
+                    .zip(&points)
+                    .map(|(f, z)| mle_eval(f, z))
+                    .collect();
+
+                let mut t = ReplayTranscript::new(0xC01);
+                let p_claim = batch_opening_prove(polys.clone(), &points, &mut t);
+
+                let mut vt = t.replay();
+                let v_claim = batch_opening_verify(&points, &values, &mut vt)
+                    .unwrap_or_else(|e| panic!("n={num_vars}, k={k}: {e}"));
+                assert_eq!(v_claim, p_claim);
+                assert_eq!(vt.cursor, vt.tape.len(), "verifier consumed the whole tape");
+                for (f, &v) in polys.iter().zip(&v_claim.values) {
+                    assert_eq!(mle_eval(f, &v_claim.point), v);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn batch_opening_rejects_wrong_value() {
+        let (polys, points) = random_instance(3, 4, 0xC02);
+        let mut values: Vec<F64> = polys
+            .iter()
+            .zip(&points)
+            .map(|(f, z)| mle_eval(f, z))
+            .collect();
+        let mut t = ReplayTranscript::new(0xC03);
+        batch_opening_prove(polys, &points, &mut t);
+
+        values[1] += F64::from(1u64);
+        let mut vt = t.replay();
+        assert_eq!(
+            batch_opening_verify(&points, &values, &mut vt),
+            Err(SumcheckError::ClaimMismatch { index: 1 })
+        );
+    }
+
+    #[test]
+    fn batch_opening_rejects_tampered_opening() {
+        let num_vars = 4;
+        let (polys, points) = random_instance(2, num_vars, 0xC04);
+        let values: Vec<F64> = polys
+            .iter()
+            .zip(&points)
+            .map(|(f, z)| mle_eval(f, z))
+            .collect();
+        let mut t = ReplayTranscript::new(0xC05);
+        batch_opening_prove(polys, &points, &mut t);
+
+        // Tape: [y_0, y_1, γ, (q0, q∞, r) × v, f_0(r), f_1(r)].
+        let last = t.tape.len() - 1;
+        t.tape[last] += F64::from(1u64);
+        let mut vt = t.replay();
+        assert_eq!(
+            batch_opening_verify(&points, &values, &mut vt),
+            Err(SumcheckError::FinalEvaluation)
+        );
+    }
+}
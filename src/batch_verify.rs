@@ -0,0 +1,187 @@
+//! Batched verification of many independent sumcheck proofs.
+//!
+//! An aggregator checking hundreds of stored [`SumcheckProof`]s of the same
+//! degree repeats the same interpolation setup for every proof. This
+//! module builds the per-degree round kernel (barycentric weights of the
+//! evaluation nodes and their `d`-th powers) once and verifies the proofs
+//! against it, in parallel with rayon under the `parallel` feature.
+//!
+//! Each proof is checked exactly as by [`verify_proof`]: its round
+//! polynomials are replayed through a fresh Fiat–Shamir transcript, the
+//! challenges are re-derived and compared, and `final_value` is compared
+//! against the reduced claim.
+//!
+//! ```ignore
+//! let instances: Vec<(F, SumcheckProof<F>)> = ...;
+//! let results = batch_verify(&instances, 2, |i| Sponge::new(domain(i)));
+//! // results[i]: Ok(SumcheckResult) or Err(BatchVerifyError { instance: i, round, error })
+//! ```
+//!
+//! [`verify_proof`]: crate::verifier::verify_proof
+
+use crate::field::SumcheckField;
+use crate::proof::{SumcheckError, SumcheckProof};
+use crate::transcript::ProverTranscript;
+use crate::verifier::{verify_proof_with, RoundKernel, SumcheckResult};
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Failure of one instance in [`batch_verify`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BatchVerifyError {
+    /// Index of the failing instance.
+    pub instance: usize,
+    /// Round whose check failed; the number of rounds for the final
+    /// evaluation check.
+    pub round: usize,
+    /// Why it failed.
+    pub error: SumcheckError,
+}
+
+impl BatchVerifyError {
+    fn new(instance: usize, num_rounds: usize, error: SumcheckError) -> Self {
+        let round = match error {
+            SumcheckError::ConsistencyCheck { round }
+            | SumcheckError::DegreeMismatch { round, .. }
+            | SumcheckError::TranscriptError { round }
+            | SumcheckError::HookError { round }
+            | SumcheckError::ChallengeMismatch { round } => round,
+            _ => num_rounds,
+        };
+        Self {
+            instance,
+            round,
+            error,
+        }
+    }
+}
+
+impl fmt::Display for BatchVerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "instance {} (round {}): {}",
+            self.instance, self.round, self.error
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchVerifyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Verify many independent `(claimed_sum, proof)` pairs of degree `degree`.
+///
+/// `new_transcript(i)` must return the fresh Fiat–Shamir transcript that
+/// instance `i` was proven with (e.g. a sponge with a per-instance domain
+/// separator). The round kernel is built once and shared by all instances.
+///
+/// Returns one verdict per instance, in order: the [`SumcheckResult`] of
+/// an accepted proof, whose `final_claim` the caller must still check
+/// against its oracle, or the [`BatchVerifyError`] of a rejected one.
+pub fn batch_verify<F, T, N>(
+    instances: &[(F, SumcheckProof<F>)],
+    degree: usize,
+    new_transcript: N,
+) -> Vec<Result<SumcheckResult<F>, BatchVerifyError>>
+where
+    F: SumcheckField,
+    T: ProverTranscript<F>,
+    N: Fn(usize) -> T + Sync,
+{
+    let kernel = RoundKernel::new(degree);
+    let verify_one = |(instance, (claim, proof)): (usize, &(F, SumcheckProof<F>))| {
+        verify_proof_with(&kernel, proof, *claim, &mut new_transcript(instance))
+            .map_err(|error| BatchVerifyError::new(instance, proof.round_polys.len(), error.into()))
+    };
+
+    #[cfg(feature = "parallel")]
+    let results = instances.par_iter().enumerate().map(verify_one).collect();
+    #[cfg(not(feature = "parallel"))]
+    let results = instances.iter().enumerate().map(verify_one).collect();
+
+    results
+}
+
+#[cfg(all(test, feature = "arkworks"))]
+mod tests {
+    use super::*;
+    use crate::provers::inner_product::InnerProductProver;
+    use crate::runner::sumcheck;
+    use crate::tests::{ChainTranscript, F64};
+    use ark_ff::UniformRand;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    fn transcript(instance: usize) -> ChainTranscript<F64> {
+        ChainTranscript::new(0xB00 + instance as u64)
+    }
+
+    fn instances(k: usize, num_vars: usize) -> Vec<(F64, SumcheckProof<F64>)> {
+        let mut rng = StdRng::seed_from_u64(0x39);
+        (0..k)
+            .map(|i| {
+                let a: Vec<F64> = (0..1 << num_vars).map(|_| F64::rand(&mut rng)).collect();
+                let b: Vec<F64> = (0..1 << num_vars).map(|_| F64::rand(&mut rng)).collect();
+                let claim = a.iter().zip(&b).map(|(x, y)| *x * y).sum();
+                let mut prover = InnerProductProver::new(a, b);
+                let proof = sumcheck(&mut prover, num_vars, &mut transcript(i), |_, _| {});
+                (claim, proof)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn batch_matches_single_verification() {
+        let batch = instances(16, 5);
+        let results = batch_verify(&batch, 2, transcript);
+        assert_eq!(results.len(), batch.len());
+        for (i, ((claim, proof), result)) in batch.iter().zip(results).enumerate() {
+            let result = result.unwrap();
+            let single =
+                crate::verifier::verify_proof(proof, *claim, 2, &mut transcript(i)).unwrap();
+            assert_eq!(result.challenges, single.challenges);
+            assert_eq!(result.final_claim, proof.final_value);
+        }
+        assert!(batch_verify::<F64, ChainTranscript<F64>, _>(&[], 2, transcript).is_empty());
+    }
+
+    #[test]
+    fn batch_reports_every_failing_instance_and_round() {
+        let mut batch = instances(12, 4);
+        batch[9].1.challenges[1] += F64::from(1u64);
+        batch[5].1.round_polys[2][1] += F64::from(1u64);
+        batch[7].0 += F64::from(1u64);
+        let results = batch_verify(&batch, 2, transcript);
+
+        let failures: Vec<BatchVerifyError> =
+            results.iter().filter_map(|r| r.clone().err()).collect();
+        assert_eq!(
+            failures,
+            [
+                BatchVerifyError {
+                    instance: 5,
+                    round: 2,
+                    error: SumcheckError::ChallengeMismatch { round: 2 },
+                },
+                BatchVerifyError {
+                    instance: 7,
+                    round: 4,
+                    error: SumcheckError::FinalEvaluation,
+                },
+                BatchVerifyError {
+                    instance: 9,
+                    round: 1,
+                    error: SumcheckError::ChallengeMismatch { round: 1 },
+                },
+            ]
+        );
+        for (i, result) in results.iter().enumerate() {
+            assert_eq!(result.is_ok(), ![5, 7, 9].contains(&i), "instance {i}");
+        }
+    }
+}
@@ -0,0 +1,214 @@
+//! Reference binary field GF(2^128) for characteristic-2 sumcheck.
+//!
+//! Binius-style protocols run sumcheck over binary fields, where `2 = 0`:
+//! the integer evaluation points `2, 3, ...` collapse onto `0, 1`, and
+//! nothing may divide by 2. [`Gf2_128`] implements [`SumcheckField`] with
+//! [`eval_node`](SumcheckField::eval_node) mapping `i` to the field element
+//! whose bit pattern is `i`, so the round-polynomial nodes stay distinct and
+//! the canonical provers and verifier run unchanged.
+//!
+//! This is a portable reference implementation (bitwise carry-less
+//! multiplication, inversion by exponentiation), not a constant-time or
+//! high-performance one.
+//!
+//! ```ignore
+//! let mut prover = InnerProductProver::new(a, b); // Vec<Gf2_128>
+//! let proof = sumcheck(&mut prover, num_vars, &mut transcript, noop_hook);
+//! ```
+
+use crate::field::{FieldBytes, SumcheckField};
+use alloc::vec::Vec;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Low 128 bits of the reduction polynomial `x^128 + x^7 + x^2 + x + 1`.
+const REDUCTION: u128 = 0x87;
+
+/// An element of GF(2^128) = GF(2)\[x\] / (x^128 + x^7 + x^2 + x + 1) in the
+/// polynomial basis: bit `k` is the coefficient of `x^k`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gf2_128(u128);
+
+impl Gf2_128 {
+    /// The element with polynomial-basis bits `bits`.
+    pub const fn new(bits: u128) -> Self {
+        Self(bits)
+    }
+
+    /// The polynomial-basis bits of this element.
+    pub const fn to_bits(self) -> u128 {
+        self.0
+    }
+
+    /// `self^exp` by square-and-multiply.
+    pub fn pow(self, mut exp: u128) -> Self {
+        let mut base = self;
+        let mut acc = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        acc
+    }
+}
+
+impl Add for Gf2_128 {
+    type Output = Self;
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 ^ rhs.0)
+    }
+}
+
+impl Sub for Gf2_128 {
+    type Output = Self;
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 ^ rhs.0)
+    }
+}
+
+impl Neg for Gf2_128 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl Mul for Gf2_128 {
+    type Output = Self;
+    /// Shift-and-add multiplication, reducing after every shift.
+    fn mul(self, rhs: Self) -> Self {
+        let (mut a, mut b) = (self.0, rhs.0);
+        let mut acc = 0u128;
+        while b != 0 {
+            if b & 1 == 1 {
+                acc ^= a;
+            }
+            let carry = a >> 127;
+            a <<= 1;
+            if carry == 1 {
+                a ^= REDUCTION;
+            }
+            b >>= 1;
+        }
+        Self(acc)
+    }
+}
+
+impl AddAssign for Gf2_128 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Gf2_128 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Gf2_128 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Sum for Gf2_128 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl SumcheckField for Gf2_128 {
+    const ZERO: Self = Self(0);
+    const ONE: Self = Self(1);
+
+    /// The image of the integer `val`, i.e. `val mod 2`.
+    fn from_u64(val: u64) -> Self {
+        Self((val & 1) as u128)
+    }
+
+    /// `a^(2^128 − 2)`.
+    fn inverse(&self) -> Option<Self> {
+        if self.0 == 0 {
+            None
+        } else {
+            Some(self.pow(u128::MAX - 1))
+        }
+    }
+
+    #[inline]
+    fn double(&self) -> Self {
+        Self::ZERO
+    }
+
+    fn field_size_bits() -> f64 {
+        128.0
+    }
+
+    /// The element whose bit pattern is `i`: `0, 1, x, x + 1, x^2, ...`.
+    #[inline]
+    fn eval_node(i: usize) -> Self {
+        Self(i as u128)
+    }
+}
+
+/// Little-endian `to_bits`: every 16-byte string is canonical.
+impl FieldBytes for Gf2_128 {
+    fn num_bytes() -> usize {
+        16
+    }
+
+    fn write_bytes(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.0.to_le_bytes());
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Option<Self> {
+        Some(Self(u128::from_le_bytes(bytes.try_into().ok()?)))
+    }
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::read_bytes(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reduction_and_inverse() {
+        let x = Gf2_128::new(2);
+        // x^128 = x^7 + x^2 + x + 1.
+        assert_eq!(Gf2_128::new(1 << 127) * x, Gf2_128::new(REDUCTION));
+        assert_eq!(x.pow(128), Gf2_128::new(REDUCTION));
+
+        for bits in [1u128, 2, 3, 0x87, u128::MAX, 0x0123_4567_89ab_cdef << 40] {
+            let a = Gf2_128::new(bits);
+            assert_eq!(a * a.inverse().unwrap(), Gf2_128::ONE, "{bits:#x}");
+        }
+        assert_eq!(Gf2_128::ZERO.inverse(), None);
+        assert_eq!(Gf2_128::from_u64(2), Gf2_128::ZERO);
+    }
+
+    #[test]
+    fn multiplication_is_a_ring_operation() {
+        let a = Gf2_128::new(0xdead_beef_0000_0000_1234_5678_9abc_def0);
+        let b = Gf2_128::new(0x0f0f_0f0f_f0f0_f0f0_1111_2222_3333_4444);
+        let c = Gf2_128::new(0x8000_0000_0000_0000_0000_0000_0000_0001);
+        assert_eq!(a * b, b * a);
+        assert_eq!((a * b) * c, a * (b * c));
+        assert_eq!(a * (b + c), a * b + a * c);
+    }
+}
@@ -51,6 +51,17 @@ pub trait RoundPolyEvaluator<F: SumcheckField>: Sync {
     /// The degree of the round polynomial (number of coefficients = degree + 1).
     fn degree(&self) -> usize;
 
+    /// Degree of the round polynomial in round `round`, at most
+    /// [`degree`](Self::degree).
+    ///
+    /// Defaults to `degree()` for every round. Evaluators whose polynomial
+    /// has lower degree in some variables override this; coefficients above
+    /// `round_degree(j)` must then accumulate to zero in round `j`.
+    fn round_degree(&self, round: usize) -> usize {
+        let _ = round;
+        self.degree()
+    }
+
     /// Accumulate this pair's contribution into `coeffs[0..=degree]`.
     ///
     /// `coeffs` is pre-zeroed at the start of each round. The evaluator
@@ -0,0 +1,311 @@
+//! R1CS gadget for the sumcheck verifier, for recursive proving.
+//!
+//! [`sumcheck_verify_gadget`] mirrors
+//! [`sumcheck_verify`](crate::verifier::sumcheck_verify) over
+//! [`FpVar`]s: it decodes the EvalsInfty round messages, derives
+//! `h_j(1) = claim − h_j(0)` from the consistency constraint and
+//! interpolates `h_j(r_j)` with constant barycentric weights. As in the
+//! native verifier, the consistency check is implicit (nothing to
+//! enforce), and the oracle check on
+//! [`final_claim`](SumcheckResultVar::final_claim) is the caller's.
+//!
+//! Prover messages and challenges come from a [`TranscriptVar`], the
+//! in-circuit counterpart of [`VerifierTranscript`](crate::transcript::VerifierTranscript)
+//! (typically a sponge gadget absorbing the messages it allocates).
+//!
+//! # Cost
+//!
+//! Per round of degree `d ≥ 2`, `5d − 6` multiplication constraints: `r^d`,
+//! the prefix/suffix products of the linear factors `(r − x_j)`, the
+//! Lagrange terms and `h(∞)·r^d` (products with constants are free). A
+//! degree-1 round costs one constraint. Transcript costs come on top.
+//!
+//! Requires the `r1cs` feature.
+//!
+//! ```ignore
+//! let sum = FpVar::new_input(cs.clone(), || Ok(claimed_sum))?;
+//! let result = sumcheck_verify_gadget(&sum, degree, num_rounds, &mut sponge_var)?;
+//! result.final_claim.enforce_equal(&oracle_value_var)?;
+//! ```
+
+use crate::polynomial::BarycentricWeights;
+use alloc::{vec, vec::Vec};
+use ark_ff::PrimeField;
+use ark_r1cs_std::fields::{fp::FpVar, FieldVar};
+use ark_relations::gr1cs::SynthesisError;
+
+/// In-circuit verifier transcript.
+///
+/// Same protocol as [`VerifierTranscript`](crate::transcript::VerifierTranscript):
+/// `receive` allocates (and absorbs) the next prover message, `challenge`
+/// squeezes the next verifier challenge.
+pub trait TranscriptVar<F: PrimeField> {
+    /// Read the next prover message.
+    fn receive(&mut self) -> Result<FpVar<F>, SynthesisError>;
+
+    /// Squeeze a verifier challenge.
+    fn challenge(&mut self) -> Result<FpVar<F>, SynthesisError>;
+}
+
+/// Output of [`sumcheck_verify_gadget`]: the in-circuit counterpart of
+/// [`SumcheckResult`](crate::verifier::SumcheckResult).
+///
+/// The caller **must** constrain `final_claim`, e.g. against an opening
+/// or the next layer's claim.
+#[derive(Clone, Debug)]
+pub struct SumcheckResultVar<F: PrimeField> {
+    /// Verifier challenges `r_1, ..., r_v`.
+    pub challenges: Vec<FpVar<F>>,
+    /// The reduced claim after all rounds: `g_v(r_v)`.
+    pub final_claim: FpVar<F>,
+}
+
+/// Sumcheck verifier gadget: constraints for
+/// [`sumcheck_verify`](crate::verifier::sumcheck_verify) with
+/// `expected_degree` and `num_rounds` fixed at circuit-construction time.
+///
+/// For each round, reads `max(expected_degree, 1)` values from
+/// `transcript`, squeezes `r_j` and sets `claim = h_j(r_j)`. The constraint
+/// system is satisfied for every assignment; soundness comes from the
+/// caller's constraint on the returned `final_claim`, exactly as the native
+/// verifier's soundness comes from the caller's oracle check.
+pub fn sumcheck_verify_gadget<F: PrimeField, T: TranscriptVar<F>>(
+    claimed_sum: &FpVar<F>,
+    expected_degree: usize,
+    num_rounds: usize,
+    transcript: &mut T,
+) -> Result<SumcheckResultVar<F>, SynthesisError> {
+    let d = expected_degree;
+    let interpolator = RoundInterpolator::<F>::new(d);
+    let mut challenges = Vec::with_capacity(num_rounds);
+    let mut claim = claimed_sum.clone();
+
+    for _ in 0..num_rounds {
+        let mut recv = Vec::with_capacity(d.max(1));
+        for _ in 0..d.max(1) {
+            recv.push(transcript.receive()?);
+        }
+        let r = transcript.challenge()?;
+        claim = interpolator.eval(&claim, &recv, &r)?;
+        challenges.push(r);
+    }
+
+    Ok(SumcheckResultVar {
+        challenges,
+        final_claim: claim,
+    })
+}
+
+/// Constants for one degree: nodes `x_i`, powers `x_i^d` and barycentric
+/// weights of the `d` nodes of the remainder `q(x) = h(x) − h(∞)·x^d`.
+struct RoundInterpolator<F: PrimeField> {
+    degree: usize,
+    nodes: Vec<F>,
+    node_pows: Vec<F>,
+    weights: BarycentricWeights<F>,
+}
+
+impl<F: PrimeField> RoundInterpolator<F> {
+    fn new(degree: usize) -> Self {
+        let nodes: Vec<F> = (0..degree)
+            .map(<F as crate::field::SumcheckField>::eval_node)
+            .collect();
+        let node_pows = nodes.iter().map(|x| x.pow([degree as u64])).collect();
+        Self {
+            degree,
+            nodes,
+            node_pows,
+            weights: BarycentricWeights::new(degree.saturating_sub(1)),
+        }
+    }
+
+    /// `h(r)` from the wire values `recv` and `h(0) + h(1) = claim`.
+    fn eval(
+        &self,
+        claim: &FpVar<F>,
+        recv: &[FpVar<F>],
+        r: &FpVar<F>,
+    ) -> Result<FpVar<F>, SynthesisError> {
+        let d = self.degree;
+        let h0 = &recv[0];
+        let h1 = claim - h0;
+        if d == 0 {
+            return Ok(h0.clone());
+        }
+        if d == 1 {
+            return Ok(h0 + r * &(h1 - h0));
+        }
+
+        // q(x_i) = h(x_i) − h(∞)·x_i^d; x_0 = 0 and x_1 = 1, so
+        // q(0) = h(0) and q(1) = h(1) − h(∞).
+        let h_inf = &recv[1];
+        let mut q = Vec::with_capacity(d);
+        q.push(h0.clone());
+        q.push(&h1 - h_inf);
+        for (hi, &x_d) in recv[2..d].iter().zip(&self.node_pows[2..]) {
+            q.push(hi - &(h_inf * x_d));
+        }
+
+        // Lagrange: q(r) = Σ_i q_i · w_i · Π_{j≠i} (r − x_j), with the
+        // products from prefix/suffix runs of the linear factors.
+        let diffs: Vec<FpVar<F>> = self.nodes.iter().map(|&x| r - x).collect();
+        let mut suffix = vec![FpVar::one(); d];
+        for i in (0..d - 1).rev() {
+            suffix[i] = &suffix[i + 1] * &diffs[i + 1];
+        }
+        let mut prefix = FpVar::one();
+        let mut q_r = FpVar::zero();
+        for i in 0..d {
+            let basis = &prefix * &suffix[i];
+            q_r += &(&q[i] * &basis) * self.weights.weights()[i];
+            if i + 1 < d {
+                prefix *= &diffs[i];
+            }
+        }
+
+        let mut r_d = r.clone();
+        for _ in 1..d {
+            r_d *= r;
+        }
+        Ok(q_r + h_inf * &r_d)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provers::eq_product::EqProductProver;
+    use crate::provers::inner_product::InnerProductProver;
+    use crate::provers::multilinear::MultilinearProver;
+    use crate::runner::sumcheck;
+    use crate::sumcheck_prover::SumcheckProver;
+    use crate::tests::{ReplayTranscript, F64};
+    use crate::verifier::sumcheck_verify;
+    use ark_ff::UniformRand;
+    use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, GR1CSVar};
+    use ark_relations::gr1cs::{ConstraintSystem, ConstraintSystemRef};
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    /// Allocates the recorded tape as witnesses, in order.
+    struct ReplayTranscriptVar {
+        cs: ConstraintSystemRef<F64>,
+        tape: Vec<F64>,
+        cursor: usize,
+    }
+
+    impl ReplayTranscriptVar {
+        fn next(&mut self) -> Result<FpVar<F64>, SynthesisError> {
+            let v = self.tape[self.cursor];
+            self.cursor += 1;
+            FpVar::new_witness(self.cs.clone(), || Ok(v))
+        }
+    }
+
+    impl TranscriptVar<F64> for ReplayTranscriptVar {
+        fn receive(&mut self) -> Result<FpVar<F64>, SynthesisError> {
+            self.next()
+        }
+
+        fn challenge(&mut self) -> Result<FpVar<F64>, SynthesisError> {
+            self.next()
+        }
+    }
+
+    /// Native verdict: verifier runs and the final claim matches.
+    fn native_accepts(tape: &[F64], claim: F64, degree: usize, v: usize, final_value: F64) -> bool {
+        let mut t = ReplayTranscript::new(0);
+        t.tape = tape.to_vec();
+        sumcheck_verify(claim, degree, v, &mut t, |_, _| Ok(()))
+            .is_ok_and(|r| r.final_claim == final_value)
+    }
+
+    /// Gadget verdict: constraints with `final_claim == final_value` hold.
+    fn gadget_accepts(tape: &[F64], claim: F64, degree: usize, v: usize, final_value: F64) -> bool {
+        let cs = ConstraintSystem::<F64>::new_ref();
+        let claim = FpVar::new_input(cs.clone(), || Ok(claim)).unwrap();
+        let final_value = FpVar::new_input(cs.clone(), || Ok(final_value)).unwrap();
+        let mut t = ReplayTranscriptVar {
+            cs: cs.clone(),
+            tape: tape.to_vec(),
+            cursor: 0,
+        };
+        let result = sumcheck_verify_gadget(&claim, degree, v, &mut t).unwrap();
+        assert_eq!(result.challenges.len(), v);
+        result.final_claim.enforce_equal(&final_value).unwrap();
+        cs.is_satisfied().unwrap()
+    }
+
+    /// Honest proof accepted by both; every single-element tampering of a
+    /// prover message (and of the final value) rejected by both.
+    fn assert_same_verdicts(prover: &mut impl SumcheckProver<F64>, claim: F64, v: usize) {
+        let degree = prover.degree();
+        let mut t = ReplayTranscript::new(0x42 ^ v as u64);
+        let proof = sumcheck(prover, v, &mut t, |_, _| {});
+        let (tape, final_value) = (t.tape, proof.final_value);
+
+        assert!(native_accepts(&tape, claim, degree, v, final_value));
+        assert!(gadget_accepts(&tape, claim, degree, v, final_value));
+        let one = F64::from(1u64);
+        assert!(!gadget_accepts(&tape, claim, degree, v, final_value + one));
+        assert!(!gadget_accepts(&tape, claim + one, degree, v, final_value));
+
+        let per_round = degree.max(1) + 1;
+        for pos in (0..tape.len()).filter(|p| p % per_round != per_round - 1) {
+            let mut tampered = tape.clone();
+            tampered[pos] += one;
+            assert_eq!(
+                gadget_accepts(&tampered, claim, degree, v, final_value),
+                native_accepts(&tampered, claim, degree, v, final_value),
+                "degree {degree}, position {pos}"
+            );
+        }
+    }
+
+    #[test]
+    fn gadget_matches_native_verifier() {
+        let mut rng = StdRng::seed_from_u64(0x420);
+        for v in 1..=4 {
+            let a: Vec<F64> = (0..1 << v).map(|_| F64::rand(&mut rng)).collect();
+            let b: Vec<F64> = (0..1 << v).map(|_| F64::rand(&mut rng)).collect();
+            let w: Vec<F64> = (0..v).map(|_| F64::rand(&mut rng)).collect();
+
+            let claim = a.iter().sum();
+            assert_same_verdicts(&mut MultilinearProver::new(a.clone()), claim, v);
+
+            let claim = a.iter().zip(&b).map(|(x, y)| *x * y).sum();
+            assert_same_verdicts(&mut InnerProductProver::new(a.clone(), b.clone()), claim, v);
+
+            let eq = crate::provers::eq_factored::build_eq_table(&w);
+            let claim = (0..1 << v).map(|i| eq[i] * a[i] * b[i]).sum();
+            assert_same_verdicts(&mut EqProductProver::new(w, a, b), claim, v);
+        }
+    }
+
+    #[test]
+    fn gadget_final_claim_matches_native_value() {
+        let mut rng = StdRng::seed_from_u64(0x421);
+        let v = 3;
+        let a: Vec<F64> = (0..1 << v).map(|_| F64::rand(&mut rng)).collect();
+        let b: Vec<F64> = (0..1 << v).map(|_| F64::rand(&mut rng)).collect();
+        let claim = a.iter().zip(&b).map(|(x, y)| *x * y).sum();
+        let mut t = ReplayTranscript::new(0x422);
+        sumcheck(&mut InnerProductProver::new(a, b), v, &mut t, |_, _| {});
+        let native = sumcheck_verify(claim, 2, v, &mut t.replay(), |_, _| Ok(())).unwrap();
+
+        let cs = ConstraintSystem::<F64>::new_ref();
+        let sum = FpVar::new_input(cs.clone(), || Ok(claim)).unwrap();
+        let mut tv = ReplayTranscriptVar {
+            cs: cs.clone(),
+            tape: t.tape,
+            cursor: 0,
+        };
+        let result = sumcheck_verify_gadget(&sum, 2, v, &mut tv).unwrap();
+        assert_eq!(result.final_claim.value().unwrap(), native.final_claim);
+        let challenges: Vec<F64> = result
+            .challenges
+            .iter()
+            .map(|c| c.value().unwrap())
+            .collect();
+        assert_eq!(challenges, native.challenges);
+    }
+}
@@ -9,6 +9,7 @@
 //! is provided for all types implementing [`ark_ff::Field`], so existing
 //! arkworks users change nothing.
 
+use alloc::vec::Vec;
 use core::fmt::Debug;
 use core::iter::Sum;
 use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
@@ -21,6 +22,10 @@ use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 /// - Additive and multiplicative identities ([`ZERO`](Self::ZERO),
 ///   [`ONE`](Self::ONE)).
 /// - Conversion from small integers ([`from_u64`](Self::from_u64)).
+/// - Optionally, a custom evaluation domain ([`eval_node`](Self::eval_node))
+///   for fields of small characteristic.
+/// - Optionally, the field size ([`field_size_bits`](Self::field_size_bits))
+///   for soundness accounting.
 /// - Multiplicative inverse ([`inverse`](Self::inverse)).
 ///
 /// The SIMD acceleration layer for Goldilocks (p = 2^64 − 2^32 + 1) is
@@ -56,6 +61,21 @@ pub trait SumcheckField:
     /// Multiplicative inverse, or `None` for zero.
     fn inverse(&self) -> Option<Self>;
 
+    /// The `i`-th node of the round-polynomial evaluation domain.
+    ///
+    /// Round polynomials of degree `d` are sent as evaluations at `0`, `∞`
+    /// and `eval_node(2), ..., eval_node(d - 1)`, and the verifier
+    /// interpolates over `eval_node(0), ..., eval_node(d - 1)`. Nodes must
+    /// be pairwise distinct, with `eval_node(0) = 0` and `eval_node(1) = 1`.
+    ///
+    /// The default is the integer `i`, which is distinct whenever the
+    /// characteristic exceeds the degree. Characteristic-2 fields, where
+    /// `2 = 0`, override it (see [`Gf2_128`](crate::binary_field::Gf2_128)).
+    #[inline]
+    fn eval_node(i: usize) -> Self {
+        Self::from_u64(i as u64)
+    }
+
     /// Returns `true` if this element is zero.
     #[inline]
     fn is_zero(&self) -> bool {
@@ -76,6 +96,21 @@ pub trait SumcheckField:
         1
     }
 
+    /// `log2 |F|`, the field size in bits, for soundness accounting
+    /// (see [`crate::soundness`]).
+    ///
+    /// Rounded down, so bounds derived from it are conservative. The
+    /// arkworks blanket impl computes it from the base modulus and the
+    /// extension degree. The default covers fields with a
+    /// [`_simd_field_config`](Self::_simd_field_config) (`extension_degree
+    /// · log2 p`) and otherwise returns `0.0`, i.e. no soundness: a field
+    /// that does not report its size never passes a security requirement.
+    fn field_size_bits() -> f64 {
+        Self::_simd_field_config().map_or(0.0, |config| {
+            Self::extension_degree() as f64 * crate::soundness::log2_u128(config.modulus as u128)
+        })
+    }
+
     /// SIMD configuration (internal dispatch hook).
     ///
     /// For **arkworks types**: overridden by the blanket impl to auto-detect
@@ -244,11 +279,59 @@ pub fn simd_config<F: SumcheckField>() -> Option<SimdFieldConfig> {
     F::_simd_field_config()
 }
 
-/// Marker trait for cross-field (base field → extension field) sumcheck.
+/// Cross-field (base field → extension field) sumcheck.
 ///
 /// The prover starts with evaluations in `BF` and folds into `EF` once
-/// the first challenge arrives.
-pub trait ExtensionOf<BF: SumcheckField>: SumcheckField + From<BF> {}
+/// the first challenge arrives; see
+/// [`CrossFieldProver`](crate::provers::cross_field::CrossFieldProver).
+///
+/// `from_base` must be a field embedding: `BF` a genuine subfield of
+/// `EF`. With the `arkworks` feature it is implemented for every field over
+/// its base prime field and itself, and for the intermediate levels of the
+/// arkworks tower models (`Fp2` in `Fp4`, `Fp6` and `Fp12`; `Fp3` in the
+/// 2-over-3 `Fp6`; `Fp6` in `Fp12`). Two extensions that merely share a
+/// prime field are not related:
+///
+/// ```compile_fail
+/// use effsc::field::{ExtensionOf, SumcheckField};
+/// use effsc::tests::{F64Ext2, F64Ext3};
+///
+/// fn embed<BF: SumcheckField, EF: ExtensionOf<BF>>(x: BF) -> EF {
+///     EF::from_base(x)
+/// }
+/// let _: F64Ext3 = embed(F64Ext2::from(1u64));
+/// ```
+///
+/// Other types implement [`from_base`](Self::from_base) directly,
+/// typically as `base.into()`.
+pub trait ExtensionOf<BF: SumcheckField>: SumcheckField {
+    /// Embed a base-field element.
+    fn from_base(base: BF) -> Self;
+}
+
+/// Byte encoding and uniform sampling, for byte-oriented (hash-based)
+/// transcripts such as
+/// [`DuplexProverTranscript`](crate::transcript::DuplexProverTranscript).
+pub trait FieldBytes: SumcheckField {
+    /// Length of the canonical encoding, in bytes.
+    fn num_bytes() -> usize;
+
+    /// Append the canonical encoding of `self` (`num_bytes()` bytes).
+    fn write_bytes(&self, out: &mut Vec<u8>);
+
+    /// Decode a canonical encoding, or `None` if `bytes` is not one.
+    fn read_bytes(bytes: &[u8]) -> Option<Self>;
+
+    /// Number of uniform bytes consumed per sampling attempt.
+    fn sample_bytes() -> usize {
+        Self::num_bytes()
+    }
+
+    /// Map `sample_bytes()` uniform bytes to a uniform element, or `None`
+    /// to reject the attempt. Callers draw fresh bytes until an attempt
+    /// succeeds (rejection sampling), so the result is exactly uniform.
+    fn from_uniform_bytes(bytes: &[u8]) -> Option<Self>;
+}
 
 // ─── Arkworks blanket implementation ────────────────────────────────────────
 
@@ -288,6 +371,23 @@ mod ark_impl {
             <Self as ark_ff::Field>::extension_degree()
         }
 
+        fn field_size_bits() -> f64 {
+            use ark_ff::{BigInteger, PrimeField};
+
+            // log2 of the top 128 bits of the modulus, plus the bits below.
+            let modulus = F::BasePrimeField::MODULUS;
+            let limbs: &[u64] = modulus.as_ref();
+            let top = limbs.iter().rposition(|&l| l != 0).unwrap_or(0);
+            let log2_p = if top == 0 {
+                crate::soundness::log2_u128(limbs[0] as u128)
+            } else {
+                let high = ((limbs[top] as u128) << 64) | limbs[top - 1] as u128;
+                crate::soundness::log2_u128(high) + (64 * (top - 1)) as f64
+            };
+            debug_assert!(log2_p <= modulus.num_bits() as f64);
+            <Self as ark_ff::Field>::extension_degree() as f64 * log2_p
+        }
+
         #[inline(always)]
         fn _simd_field_config() -> Option<SimdFieldConfig> {
             use ark_ff::PrimeField;
@@ -375,11 +475,155 @@ mod ark_impl {
         }
     }
 
-    // Blanket ExtensionOf for arkworks extension fields.
-    impl<BF, EF> ExtensionOf<BF> for EF
+    /// Bytes per base prime field component: `⌈log2 p / 8⌉`.
+    fn base_bytes<F: ark_ff::Field>() -> usize {
+        use ark_ff::PrimeField;
+        (F::BasePrimeField::MODULUS_BIT_SIZE as usize).div_ceil(8)
+    }
+
+    /// Split `bytes` into base prime field components, each read from the
+    /// low `bits` bits of its little-endian chunk; `None` if one is `≥ p`.
+    fn from_le_components<F: ark_ff::Field>(bytes: &[u8], bits: usize) -> Option<F> {
+        use ark_ff::{BigInteger, PrimeField};
+        let components = bytes.chunks(base_bytes::<F>()).map(|chunk| {
+            let le_bits: Vec<bool> = (0..bits)
+                .map(|i| (chunk[i / 8] >> (i % 8)) & 1 == 1)
+                .collect();
+            let repr = <F::BasePrimeField as PrimeField>::BigInt::from_bits_le(&le_bits);
+            F::BasePrimeField::from_bigint(repr)
+        });
+        F::from_base_prime_field_elems(components.collect::<Option<Vec<_>>>()?)
+    }
+
+    /// Base prime field components in order, each as its integer value in
+    /// `⌈log2 p / 8⌉` little-endian bytes. Unlike `CanonicalSerialize`,
+    /// which writes the Montgomery form for some backends, the encoding
+    /// does not depend on the representation.
+    impl<F> FieldBytes for F
     where
-        BF: ark_ff::Field,
-        EF: ark_ff::Field + From<BF>,
+        F: ark_ff::Field,
     {
+        fn num_bytes() -> usize {
+            <Self as ark_ff::Field>::extension_degree() as usize * base_bytes::<F>()
+        }
+
+        fn write_bytes(&self, out: &mut Vec<u8>) {
+            use ark_ff::{BigInteger, PrimeField};
+            for component in self.to_base_prime_field_elements() {
+                out.extend_from_slice(&component.into_bigint().to_bytes_le()[..base_bytes::<F>()]);
+            }
+        }
+
+        fn read_bytes(bytes: &[u8]) -> Option<Self> {
+            if bytes.len() != Self::num_bytes() {
+                return None;
+            }
+            from_le_components(bytes, 8 * base_bytes::<F>())
+        }
+
+        /// Each component keeps the low `MODULUS_BIT_SIZE` bits of its
+        /// chunk; the attempt is rejected if one is `≥ p`.
+        fn from_uniform_bytes(bytes: &[u8]) -> Option<Self> {
+            use ark_ff::PrimeField;
+            debug_assert_eq!(bytes.len(), Self::sample_bytes());
+            from_le_components(bytes, F::BasePrimeField::MODULUS_BIT_SIZE as usize)
+        }
+    }
+
+    // ExtensionOf for arkworks fields. The embedding is only sound for a
+    // genuine subfield, which the trait bounds cannot express in general
+    // (two extensions over the same prime field need not nest), so each
+    // pair is spelled out per field model: every field over its base prime
+    // field and itself, and the intermediate levels of the tower models.
+    use ark_ff::fields::{
+        fp12_2over3over2::{Fp12, Fp12Config},
+        fp6_2over3, fp6_3over2, CubicExtConfig, CubicExtField, Fp, Fp2, Fp3, Fp4, Fp4Config,
+        FpConfig, QuadExtConfig, QuadExtField, SmallFp, SmallFpConfig,
+    };
+    use ark_ff::Zero;
+
+    impl<C: QuadExtConfig> ExtensionOf<QuadExtField<C>> for QuadExtField<C> {
+        #[inline]
+        fn from_base(base: QuadExtField<C>) -> Self {
+            base
+        }
+    }
+
+    impl<C: CubicExtConfig> ExtensionOf<CubicExtField<C>> for CubicExtField<C> {
+        #[inline]
+        fn from_base(base: CubicExtField<C>) -> Self {
+            base
+        }
+    }
+
+    /// A prime field over itself, and every quadratic and cubic extension
+    /// whose base prime field it is.
+    macro_rules! impl_extension_of_prime {
+        ([$($generics:tt)*], $prime:ty) => {
+            impl<$($generics)*> ExtensionOf<$prime> for $prime {
+                #[inline]
+                fn from_base(base: $prime) -> Self {
+                    base
+                }
+            }
+
+            impl<C: QuadExtConfig<BasePrimeField = $prime>, $($generics)*> ExtensionOf<$prime>
+                for QuadExtField<C>
+            {
+                #[inline]
+                fn from_base(base: $prime) -> Self {
+                    <Self as ark_ff::Field>::from_base_prime_field(base)
+                }
+            }
+
+            impl<C: CubicExtConfig<BasePrimeField = $prime>, $($generics)*> ExtensionOf<$prime>
+                for CubicExtField<C>
+            {
+                #[inline]
+                fn from_base(base: $prime) -> Self {
+                    <Self as ark_ff::Field>::from_base_prime_field(base)
+                }
+            }
+        };
+    }
+
+    impl_extension_of_prime!([P: FpConfig<N>, const N: usize], Fp<P, N>);
+    impl_extension_of_prime!([P: SmallFpConfig], SmallFp<P>);
+
+    impl<P: Fp4Config> ExtensionOf<Fp2<P::Fp2Config>> for Fp4<P> {
+        #[inline]
+        fn from_base(base: Fp2<P::Fp2Config>) -> Self {
+            Self::new(base, Zero::zero())
+        }
+    }
+
+    impl<P: fp6_2over3::Fp6Config> ExtensionOf<Fp3<P::Fp3Config>> for fp6_2over3::Fp6<P> {
+        #[inline]
+        fn from_base(base: Fp3<P::Fp3Config>) -> Self {
+            Self::new(base, Zero::zero())
+        }
+    }
+
+    impl<P: fp6_3over2::Fp6Config> ExtensionOf<Fp2<P::Fp2Config>> for fp6_3over2::Fp6<P> {
+        #[inline]
+        fn from_base(base: Fp2<P::Fp2Config>) -> Self {
+            Self::new(base, Zero::zero(), Zero::zero())
+        }
+    }
+
+    type Fp12Fp2Config<P> = <<P as Fp12Config>::Fp6Config as fp6_3over2::Fp6Config>::Fp2Config;
+
+    impl<P: Fp12Config> ExtensionOf<fp6_3over2::Fp6<P::Fp6Config>> for Fp12<P> {
+        #[inline]
+        fn from_base(base: fp6_3over2::Fp6<P::Fp6Config>) -> Self {
+            Self::new(base, Zero::zero())
+        }
+    }
+
+    impl<P: Fp12Config> ExtensionOf<Fp2<Fp12Fp2Config<P>>> for Fp12<P> {
+        #[inline]
+        fn from_base(base: Fp2<Fp12Fp2Config<P>>) -> Self {
+            Self::new(ExtensionOf::from_base(base), Zero::zero())
+        }
     }
 }
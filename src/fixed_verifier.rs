@@ -0,0 +1,245 @@
+//! Allocation-free sumcheck verifier with a const maximum degree.
+//!
+//! [`sumcheck_verify`](crate::verifier::sumcheck_verify) allocates per round
+//! and rebuilds its interpolation data every round. [`FixedVerifier`]
+//! instead precomputes, once per degree `d ≤ D`:
+//!
+//! - the barycentric weights of the `d` nodes `{x_0, ..., x_{d-1}}`
+//!   ([`FixedBarycentricWeights`]), and
+//! - the powers `x_i^d`,
+//!
+//! and keeps every per-round value in `[F; D]` arrays. Verification never
+//! touches the heap, and the caller provides the challenge buffer, so it
+//! runs in `no_std` targets without an allocator in the hot path. The
+//! number of reads, multiplications and challenges depends only on `d` and
+//! the number of rounds, so the same steps translate directly into a
+//! circuit for recursive verification (the only data-dependent branch is
+//! the interpolation shortcut for a challenge that hits a node).
+//!
+//! The wire format and checks are those of
+//! [`sumcheck_verify`](crate::verifier::sumcheck_verify) (EvalsInfty,
+//! nodes [`SumcheckField::eval_node`]); both return the same final claim.
+//!
+//! ```ignore
+//! let verifier = FixedVerifier::<F, 4>::new(3); // degree 3, capacity 4
+//! let mut challenges = [F::ZERO; NUM_ROUNDS];
+//! let final_claim = verifier.verify(sum, &mut challenges, &mut t, noop_hook_verify)?;
+//! ```
+
+use crate::field::SumcheckField;
+use crate::polynomial::FixedBarycentricWeights;
+use crate::proof::SumcheckError;
+use crate::transcript::VerifierTranscript;
+
+/// Sumcheck verifier for round polynomials of degree `d ≤ D`, with
+/// precomputed interpolation data and no heap allocation.
+#[derive(Clone, Copy, Debug)]
+pub struct FixedVerifier<F: SumcheckField, const D: usize> {
+    degree: usize,
+    /// `x_i^d` for `i ∈ {0, ..., d-1}`.
+    node_pows: [F; D],
+    /// Weights for the degree-`(d-1)` remainder `q(x) = h(x) − h(∞)·x^d`.
+    weights: FixedBarycentricWeights<F, D>,
+}
+
+impl<F: SumcheckField, const D: usize> FixedVerifier<F, D> {
+    /// Precompute the verifier for degree `degree`.
+    ///
+    /// # Panics
+    ///
+    /// If `degree > D`, or `D == 0`.
+    pub fn new(degree: usize) -> Self {
+        assert!(D > 0, "capacity D must be at least 1");
+        assert!(degree <= D, "degree {degree} exceeds capacity {D}");
+        let mut node_pows = [F::ZERO; D];
+        for (i, x_d) in node_pows.iter_mut().enumerate().take(degree) {
+            *x_d = pow(F::eval_node(i), degree);
+        }
+        Self {
+            degree,
+            node_pows,
+            weights: FixedBarycentricWeights::new(degree.saturating_sub(1)),
+        }
+    }
+
+    /// The round-polynomial degree this verifier checks.
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Verify `challenges.len()` rounds against `claimed_sum`.
+    ///
+    /// Identical to [`sumcheck_verify`](crate::verifier::sumcheck_verify)
+    /// except that challenge `r_j` is written to `challenges[j]` and the
+    /// final claim is returned directly. The caller must check it against
+    /// `g(challenges)`.
+    pub fn verify<T: VerifierTranscript<F>>(
+        &self,
+        claimed_sum: F,
+        challenges: &mut [F],
+        transcript: &mut T,
+        mut hook: impl FnMut(usize, &mut T) -> Result<(), SumcheckError>,
+    ) -> Result<F, SumcheckError> {
+        let d = self.degree;
+        let mut claim = claimed_sum;
+        for (round, challenge) in challenges.iter_mut().enumerate() {
+            let mut receive = || {
+                transcript
+                    .receive()
+                    .map_err(|_| SumcheckError::TranscriptError { round })
+            };
+
+            // EvalsInfty: [h(0)] for d ≤ 1, [h(0), h(∞), h(x_2), ..., h(x_{d-1})]
+            // otherwise. q holds the remainder q(x_i) = h(x_i) − h(∞)·x_i^d.
+            let h0 = receive()?;
+            let h1 = claim - h0;
+            let mut q = [F::ZERO; D];
+            let h_inf = if d >= 2 {
+                let h_inf = receive()?;
+                q[0] = h0;
+                q[1] = h1 - h_inf;
+                for (q_i, &x_d) in q[2..d].iter_mut().zip(&self.node_pows[2..d]) {
+                    *q_i = receive()? - h_inf * x_d;
+                }
+                h_inf
+            } else {
+                F::ZERO
+            };
+
+            hook(round, transcript)?;
+            let r = transcript.challenge();
+            *challenge = r;
+
+            claim = match d {
+                0 => h0,
+                1 => h0 + r * (h1 - h0),
+                _ => self.weights.eval(&q[..d], r) + h_inf * pow(r, d),
+            };
+        }
+        Ok(claim)
+    }
+}
+
+/// `x^e` by repeated multiplication (`e` is a small degree).
+fn pow<F: SumcheckField>(x: F, e: usize) -> F {
+    (0..e).fold(F::ONE, |acc, _| acc * x)
+}
+
+#[cfg(all(test, feature = "arkworks"))]
+mod tests {
+    use super::*;
+    use crate::coefficient_sumcheck::RoundPolyEvaluator;
+    use crate::provers::coefficient::CoefficientProver;
+    use crate::provers::inner_product::InnerProductProver;
+    use crate::provers::multilinear::MultilinearProver;
+    use crate::runner::sumcheck;
+    use crate::sumcheck_prover::SumcheckProver;
+    use crate::tests::{ReplayTranscript, F64};
+    use crate::verifier::sumcheck_verify;
+    use ark_ff::UniformRand;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    /// `Π_k t_k` over `n` pairwise tables (degree `n`).
+    struct Product(usize);
+
+    impl RoundPolyEvaluator<F64> for Product {
+        fn degree(&self) -> usize {
+            self.0
+        }
+
+        fn accumulate_pair(
+            &self,
+            coeffs: &mut [F64],
+            _tablewise: &[(&[F64], &[F64])],
+            pw: &[(F64, F64)],
+        ) {
+            // Multiply the linear factors (e + (o − e)·X) coefficient-wise.
+            let mut acc = vec![F64::from(1u64)];
+            for &(e, o) in pw {
+                let mut next = vec![F64::from(0u64); acc.len() + 1];
+                for (k, &c) in acc.iter().enumerate() {
+                    next[k] += c * e;
+                    next[k + 1] += c * (o - e);
+                }
+                acc = next;
+            }
+            for (c, a) in coeffs.iter_mut().zip(acc) {
+                *c += a;
+            }
+        }
+
+        fn parallelize(&self) -> bool {
+            false
+        }
+    }
+
+    /// Prove, then check that the fixed and heap verifiers agree.
+    fn assert_agrees<const D: usize>(prover: &mut impl SumcheckProver<F64>, claim: F64, v: usize) {
+        let degree = prover.degree();
+        let mut t = ReplayTranscript::new(0x41 ^ degree as u64);
+        let proof = sumcheck(prover, v, &mut t, |_, _| {});
+
+        let expected = sumcheck_verify(claim, degree, v, &mut t.replay(), |_, _| Ok(())).unwrap();
+        let mut challenges = [F64::from(0u64); 8];
+        let final_claim = FixedVerifier::<F64, D>::new(degree)
+            .verify(claim, &mut challenges[..v], &mut t.replay(), |_, _| Ok(()))
+            .unwrap();
+        assert_eq!(final_claim, expected.final_claim);
+        assert_eq!(final_claim, proof.final_value);
+        assert_eq!(&challenges[..v], &expected.challenges[..]);
+    }
+
+    #[test]
+    fn fixed_verifier_matches_sumcheck_verify() {
+        let mut rng = StdRng::seed_from_u64(0x410);
+        for v in 1..=6 {
+            let tables: Vec<Vec<F64>> = (0..4)
+                .map(|_| (0..1 << v).map(|_| F64::rand(&mut rng)).collect())
+                .collect();
+
+            let claim = tables[0].iter().sum();
+            assert_agrees::<1>(&mut MultilinearProver::new(tables[0].clone()), claim, v);
+
+            let claim = tables[0].iter().zip(&tables[1]).map(|(a, b)| *a * b).sum();
+            let prover = &mut InnerProductProver::new(tables[0].clone(), tables[1].clone());
+            assert_agrees::<4>(prover, claim, v);
+
+            for n in 3..=4 {
+                let claim = (0..1 << v)
+                    .map(|i| tables[..n].iter().map(|t| t[i]).product::<F64>())
+                    .sum();
+                let evaluator = Product(n);
+                let prover = &mut CoefficientProver::new(&evaluator, vec![], tables[..n].to_vec());
+                assert_agrees::<4>(prover, claim, v);
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_verifier_reports_transcript_errors() {
+        let mut rng = StdRng::seed_from_u64(0x411);
+        let a: Vec<F64> = (0..16).map(|_| F64::rand(&mut rng)).collect();
+        let b: Vec<F64> = (0..16).map(|_| F64::rand(&mut rng)).collect();
+        let claim = a.iter().zip(&b).map(|(x, y)| *x * y).sum();
+        let mut t = ReplayTranscript::new(0x412);
+        sumcheck(&mut InnerProductProver::new(a, b), 4, &mut t, |_, _| {});
+
+        // Ask for one more round than was proven.
+        let mut challenges = [F64::from(0u64); 5];
+        assert_eq!(
+            FixedVerifier::<F64, 2>::new(2).verify(
+                claim,
+                &mut challenges,
+                &mut t.replay(),
+                |_, _| { Ok(()) }
+            ),
+            Err(SumcheckError::TranscriptError { round: 4 })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds capacity")]
+    fn fixed_verifier_rejects_degree_above_capacity() {
+        FixedVerifier::<F64, 2>::new(3);
+    }
+}
@@ -1,8 +1,466 @@
 pub mod protogalaxy {
+    //! Protogalaxy folding (Gabizon–Khovratovich, ePrint 2023/1106).
+    //!
+    //! A relation is a list of `m = 2^t` constraints `f_i(x, w)` of total
+    //! degree `d`, one per row, supplied through a [`RoundPolyEvaluator`].
+    //! The instance `x` and witness `w` are each a whole number of `m`-row
+    //! columns (column `j` of `x` is `x[j·m..(j+1)·m]`); `f_i` is the
+    //! evaluator applied to row `i` of the instance columns followed by the
+    //! witness columns, passed as constant pairs `(v, v)` so that the round
+    //! polynomial it accumulates is the constant `f_i(x, w)`. The same
+    //! evaluator therefore drives the coefficient sumcheck provers over
+    //! those columns. An accumulator relaxes the relation with a point
+    //! `β ∈ F^t` and an error `e`:
+    //!
+    //! ```text
+    //! Σ_i pow_i(β) · f_i(x, w) = e,   pow_i(β) = Π_j β_j^{bit_j(i)}
+    //! ```
+    //!
+    //! (bit `j` is the `j`-th least-significant bit of `i`). Fresh instances
+    //! satisfy `f_i(x, w) = 0` for all `i`.
+    //!
+    //! # Protocol
+    //!
+    //! Folding an accumulator with `k` instances, over the Lagrange basis
+    //! `L_0, ..., L_k` of the nodes `{0, 1, ..., k}` with vanishing
+    //! polynomial `Z`:
+    //!
+    //! 1. Squeeze `δ ∈ F^t`. The prover sends `F(X) = Σ_i pow_i(β + X·δ) ·
+    //!    f_i(x_0, w_0)` (coefficients `1..=t`; `F(0) = e`), computed with
+    //!    the power-of-β tree [`pow_tree`].
+    //! 2. Squeeze `α`; set `β* = β + α·δ`. The prover sends `K(X)` with
+    //!    `G(X) = F(α)·L_0(X) + Z(X)·K(X)`, where
+    //!    `G(X) = Σ_i pow_i(β*) · f_i(Σ_j L_j(X)·(x_j, w_j))`
+    //!    ([`compute_k_poly`], `k·(d − 1)` coefficients).
+    //! 3. Squeeze `γ`. The folded accumulator is
+    //!    `(Σ_j L_j(γ)·x_j, β*, F(α)·L_0(γ) + Z(γ)·K(γ))` with witness
+    //!    `Σ_j L_j(γ)·w_j`.
+    //!
+    //! The verifier ([`fold_verify`]) performs no checks; it recomputes the
+    //! folded instance, and [`decide`] checks the final accumulator. Instance
+    //! vectors are folded as field elements — commitments are folded by the
+    //! caller with the same `L_j(γ)`. The caller must absorb the
+    //! accumulator and incoming instances into the transcript first.
+    //!
+    //! ```ignore
+    //! let folded = fold_prove(&evaluator, &acc, &incoming, &mut pt);
+    //! let folded_instance = fold_verify(&evaluator, &acc.instance, &incoming_x, &mut vt)?;
+    //! assert!(decide(&evaluator, &folded));
+    //! ```
+
     use ark_ff::{Field, Zero};
     use ark_poly::{univariate::DensePolynomial, Polynomial};
+    use core::fmt;
 
+    use crate::coefficient_sumcheck::RoundPolyEvaluator;
     use crate::poly_ops;
+    use crate::transcript::{ProverTranscript, VerifierTranscript};
+
+    /// Folding verification error.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum FoldingError {
+        /// The transcript failed to produce the coefficients of `F`
+        /// (message 0) or `K` (message 1).
+        Transcript { message: usize },
+        /// Incoming instance `index` has `got` entries instead of the
+        /// accumulator's `expected`.
+        InstanceLength {
+            index: usize,
+            expected: usize,
+            got: usize,
+        },
+    }
+
+    impl fmt::Display for FoldingError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                FoldingError::Transcript { message } => {
+                    write!(f, "message {message}: transcript error")
+                }
+                FoldingError::InstanceLength {
+                    index,
+                    expected,
+                    got,
+                } => write!(
+                    f,
+                    "instance {index}: expected {expected} entries, got {got}"
+                ),
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl std::error::Error for FoldingError {}
+
+    /// The public part of an accumulator: instance, `β` and error `e`.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct AccumulatorInstance<F> {
+        /// Public instance `x` (folded linearly).
+        pub instance: Vec<F>,
+        /// `β ∈ F^t`, `t = log2(m)`.
+        pub betas: Vec<F>,
+        /// Error term `e = Σ_i pow_i(β) · f_i(x, w)`.
+        pub error: F,
+    }
+
+    /// An accumulator with its witness.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Accumulator<F> {
+        /// Public part.
+        pub instance: AccumulatorInstance<F>,
+        /// Witness `w` (folded linearly).
+        pub witness: Vec<F>,
+    }
+
+    impl<F: Field> Accumulator<F> {
+        /// Accumulator for a single instance at `betas`, with the error
+        /// computed from the constraints (zero for a satisfying instance).
+        pub fn new(
+            evaluator: &impl RoundPolyEvaluator<F>,
+            instance: Vec<F>,
+            witness: Vec<F>,
+            betas: Vec<F>,
+        ) -> Self {
+            let error = weighted_constraints(evaluator, &instance, &witness, &pow_weights(&betas));
+            Self {
+                instance: AccumulatorInstance {
+                    instance,
+                    betas,
+                    error,
+                },
+                witness,
+            }
+        }
+    }
+
+    /// `pow_i(β)` for `i in 0..2^t` (bit `j` of `i` selects `β_j`).
+    fn pow_weights<F: Field>(betas: &[F]) -> Vec<F> {
+        let mut table = Vec::with_capacity(1 << betas.len());
+        table.push(F::ONE);
+        for &b in betas {
+            let n = table.len();
+            for i in 0..n {
+                let t = table[i] * b;
+                table.push(t);
+            }
+        }
+        table
+    }
+
+    /// `f_i(instance, witness)` for the `m` rows: the constant coefficient
+    /// of the evaluator on row `i` as pairs `(v, v)`.
+    fn constraint_values<F: Field>(
+        evaluator: &impl RoundPolyEvaluator<F>,
+        instance: &[F],
+        witness: &[F],
+        m: usize,
+    ) -> Vec<F> {
+        assert!(
+            instance.len() % m == 0 && witness.len() % m == 0,
+            "instance and witness must be whole columns of 2^t rows"
+        );
+        let columns: Vec<&[F]> = instance.chunks(m).chain(witness.chunks(m)).collect();
+        let mut coeffs = vec![F::ZERO; evaluator.degree() + 1];
+        let mut row = Vec::with_capacity(columns.len());
+        (0..m)
+            .map(|i| {
+                row.clear();
+                row.extend(columns.iter().map(|c| (c[i], c[i])));
+                poly_ops::zero(&mut coeffs);
+                evaluator.accumulate_pair(&mut coeffs, &[], &row);
+                coeffs[0]
+            })
+            .collect()
+    }
+
+    /// `Σ_i weights[i] · f_i(instance, witness)`, with one weight per row.
+    fn weighted_constraints<F: Field>(
+        evaluator: &impl RoundPolyEvaluator<F>,
+        instance: &[F],
+        witness: &[F],
+        weights: &[F],
+    ) -> F {
+        constraint_values(evaluator, instance, witness, weights.len())
+            .iter()
+            .zip(weights)
+            .map(|(&f, &p)| f * p)
+            .sum()
+    }
+
+    /// Lagrange basis `L_0(x), ..., L_{n-1}(x)` over the nodes `{0, ..., n-1}`.
+    fn lagrange_at<F: Field>(n: usize, x: F) -> Vec<F> {
+        (0..n)
+            .map(|j| {
+                let xj = F::from(j as u64);
+                let (mut num, mut den) = (F::ONE, F::ONE);
+                for m in (0..n).filter(|&m| m != j) {
+                    let xm = F::from(m as u64);
+                    num *= x - xm;
+                    den *= xj - xm;
+                }
+                num * den.inverse().expect("distinct nodes")
+            })
+            .collect()
+    }
+
+    /// `Z(x) = Π_{j<n} (x − j)`.
+    fn vanishing_at<F: Field>(n: usize, x: F) -> F {
+        (0..n).map(|j| x - F::from(j as u64)).product()
+    }
+
+    /// Coefficients of the polynomial through `(xs[i], ys[i])`.
+    fn interpolate<F: Field>(xs: &[F], ys: &[F]) -> Vec<F> {
+        let n = xs.len();
+        let mut coeffs = vec![F::ZERO; n];
+        let mut basis = vec![F::ZERO; n];
+        for (i, (&xi, &yi)) in xs.iter().zip(ys).enumerate() {
+            // basis = Π_{m≠i} (X − x_m), built by repeated multiplication.
+            poly_ops::zero(&mut basis);
+            basis[0] = F::ONE;
+            let mut den = F::ONE;
+            let others = xs.iter().enumerate().filter(|&(m, _)| m != i);
+            for (deg, (_, &xm)) in others.enumerate() {
+                for c in (1..=deg + 1).rev() {
+                    basis[c] = basis[c - 1] - xm * basis[c];
+                }
+                basis[0] = -xm * basis[0];
+                den *= xi - xm;
+            }
+            poly_ops::add_scaled(
+                &mut coeffs,
+                yi * den.inverse().expect("distinct nodes"),
+                &basis,
+            );
+        }
+        coeffs
+    }
+
+    /// `Σ_j lagrange[j] · vectors[j]`.
+    fn combine<F: Field>(lagrange: &[F], vectors: &[&[F]]) -> Vec<F> {
+        let mut out = vec![F::ZERO; vectors[0].len()];
+        for (&l, v) in lagrange.iter().zip(vectors) {
+            assert_eq!(v.len(), out.len(), "folded vectors must have equal length");
+            poly_ops::add_scaled(&mut out, l, v);
+        }
+        out
+    }
+
+    /// Power-of-β tree: `F(X) = Σ_i pow_i(β + X·δ) · leaves[i]`.
+    ///
+    /// Level `j` combines siblings as `left + (β_j + X·δ_j)·right`, so the
+    /// nodes at level `j` have degree `j` and the root has degree `t`.
+    /// `O(m)` field operations for `m = 2^t` leaves.
+    pub fn pow_tree<F: Field>(leaves: &[F], betas: &[F], deltas: &[F]) -> DensePolynomial<F> {
+        assert_eq!(leaves.len(), 1 << betas.len(), "need 2^t leaves");
+        assert_eq!(betas.len(), deltas.len(), "β and δ must have equal length");
+        let slot = betas.len() + 1;
+        let mut buf = vec![F::ZERO; leaves.len() * slot];
+        for (i, &l) in leaves.iter().enumerate() {
+            buf[i * slot] = l;
+        }
+
+        let mut n = leaves.len();
+        for (j, (&b, &d)) in betas.iter().zip(deltas).enumerate() {
+            for i in 0..n / 2 {
+                let (lo, hi, out) = (2 * i * slot, (2 * i + 1) * slot, i * slot);
+                // Ascending `c` is safe in place: `out ≤ lo` and `out + c`
+                // never aliases a later read.
+                let mut prev = F::ZERO;
+                for c in 0..=j + 1 {
+                    let r = buf[hi + c];
+                    buf[out + c] = buf[lo + c] + b * r + d * prev;
+                    prev = r;
+                }
+            }
+            n /= 2;
+        }
+        poly_ops::to_dense_poly(&buf[..slot])
+    }
+
+    /// `F(X)` for the accumulator: constraints evaluated at its
+    /// `(instance, witness)`, reduced with [`pow_tree`].
+    pub fn compute_f_poly<F: Field>(
+        evaluator: &impl RoundPolyEvaluator<F>,
+        accumulator: &Accumulator<F>,
+        deltas: &[F],
+    ) -> DensePolynomial<F> {
+        let acc = &accumulator.instance;
+        let leaves = constraint_values(
+            evaluator,
+            &acc.instance,
+            &accumulator.witness,
+            1 << acc.betas.len(),
+        );
+        pow_tree(&leaves, &acc.betas, deltas)
+    }
+
+    /// `K(X) = (G(X) − F(α)·L_0(X)) / Z(X)` for `instances = [(x_0, w_0),
+    /// ..., (x_k, w_k)]` (the accumulator first), with `betas_star = β*`.
+    ///
+    /// `K` has degree `k·(d − 1) − 1`; it is interpolated from `G` at the
+    /// `k·(d − 1)` points `k + 1, k + 2, ...` off the Lagrange domain.
+    pub fn compute_k_poly<F: Field>(
+        evaluator: &impl RoundPolyEvaluator<F>,
+        betas_star: &[F],
+        f_alpha: F,
+        instances: &[(&[F], &[F])],
+    ) -> DensePolynomial<F> {
+        let n = instances.len();
+        let k = n - 1;
+        let num_coeffs = k * evaluator.degree().saturating_sub(1);
+        let weights = pow_weights(betas_star);
+        let xs: Vec<F> = (0..num_coeffs).map(|s| F::from((n + s) as u64)).collect();
+        let (xs_inst, ws): (Vec<&[F]>, Vec<&[F]>) = instances.iter().copied().unzip();
+        let ys: Vec<F> = xs
+            .iter()
+            .map(|&x| {
+                let l = lagrange_at(n, x);
+                let g = weighted_constraints(
+                    evaluator,
+                    &combine(&l, &xs_inst),
+                    &combine(&l, &ws),
+                    &weights,
+                );
+                (g - f_alpha * l[0]) * vanishing_at(n, x).inverse().expect("x off the domain")
+            })
+            .collect();
+        poly_ops::to_dense_poly(&interpolate(&xs, &ys))
+    }
+
+    /// Send `coeffs[..len]`, zero-padded.
+    fn send_padded<F: Field, T: ProverTranscript<F>>(coeffs: &[F], len: usize, transcript: &mut T) {
+        for i in 0..len {
+            transcript.send(coeffs.get(i).copied().unwrap_or(F::ZERO));
+        }
+    }
+
+    /// Prover side: fold `accumulator` with `incoming = [(x_j, w_j)]`
+    /// (satisfying instances) into a new accumulator.
+    pub fn fold_prove<F: Field, T: ProverTranscript<F>>(
+        evaluator: &impl RoundPolyEvaluator<F>,
+        accumulator: &Accumulator<F>,
+        incoming: &[(Vec<F>, Vec<F>)],
+        transcript: &mut T,
+    ) -> Accumulator<F> {
+        let betas = &accumulator.instance.betas;
+        let t = betas.len();
+        let deltas: Vec<F> = (0..t).map(|_| transcript.challenge()).collect();
+        let f_poly = compute_f_poly(evaluator, accumulator, &deltas);
+        send_padded(f_poly.coeffs.get(1..).unwrap_or(&[]), t, transcript);
+
+        let alpha = transcript.challenge();
+        let f_alpha = f_poly.evaluate(&alpha);
+        let betas_star: Vec<F> = betas
+            .iter()
+            .zip(&deltas)
+            .map(|(&b, &d)| b + alpha * d)
+            .collect();
+
+        let mut instances: Vec<(&[F], &[F])> = Vec::with_capacity(incoming.len() + 1);
+        instances.push((&accumulator.instance.instance, &accumulator.witness));
+        instances.extend(incoming.iter().map(|(x, w)| (x.as_slice(), w.as_slice())));
+        let k_poly = compute_k_poly(evaluator, &betas_star, f_alpha, &instances);
+        let k = incoming.len();
+        send_padded(
+            &k_poly.coeffs,
+            k * evaluator.degree().saturating_sub(1),
+            transcript,
+        );
+
+        let gamma = transcript.challenge();
+        let n = k + 1;
+        let l = lagrange_at(n, gamma);
+        let (xs, ws): (Vec<&[F]>, Vec<&[F]>) = instances.into_iter().unzip();
+        Accumulator {
+            instance: AccumulatorInstance {
+                instance: combine(&l, &xs),
+                betas: betas_star,
+                error: f_alpha * l[0] + vanishing_at(n, gamma) * k_poly.evaluate(&gamma),
+            },
+            witness: combine(&l, &ws),
+        }
+    }
+
+    /// Verifier side: fold the accumulator instance with the incoming
+    /// public instances `x_1, ..., x_k`.
+    ///
+    /// Errors:
+    /// - [`FoldingError::InstanceLength`] if an incoming instance differs in
+    ///   length from the accumulator's.
+    /// - [`FoldingError::Transcript`] (message 0 for `F`, message 1 for `K`)
+    ///   if a prover message is malformed.
+    pub fn fold_verify<F: Field, T: VerifierTranscript<F>>(
+        evaluator: &impl RoundPolyEvaluator<F>,
+        accumulator: &AccumulatorInstance<F>,
+        incoming: &[Vec<F>],
+        transcript: &mut T,
+    ) -> Result<AccumulatorInstance<F>, FoldingError> {
+        let expected = accumulator.instance.len();
+        if let Some((index, x)) = incoming
+            .iter()
+            .enumerate()
+            .find(|(_, x)| x.len() != expected)
+        {
+            return Err(FoldingError::InstanceLength {
+                index,
+                expected,
+                got: x.len(),
+            });
+        }
+        let receive = |transcript: &mut T, len: usize, message: usize| {
+            (0..len)
+                .map(|_| {
+                    transcript
+                        .receive()
+                        .map_err(|_| FoldingError::Transcript { message })
+                })
+                .collect::<Result<Vec<F>, _>>()
+        };
+
+        let t = accumulator.betas.len();
+        let deltas: Vec<F> = (0..t).map(|_| transcript.challenge()).collect();
+        let mut f_coeffs = Vec::with_capacity(t + 1);
+        f_coeffs.push(accumulator.error);
+        f_coeffs.extend(receive(transcript, t, 0)?);
+
+        let alpha = transcript.challenge();
+        let f_alpha = poly_ops::eval_at(&f_coeffs, alpha);
+        let betas_star = accumulator
+            .betas
+            .iter()
+            .zip(&deltas)
+            .map(|(&b, &d)| b + alpha * d)
+            .collect();
+
+        let k = incoming.len();
+        let k_coeffs = receive(transcript, k * evaluator.degree().saturating_sub(1), 1)?;
+
+        let gamma = transcript.challenge();
+        let n = k + 1;
+        let l = lagrange_at(n, gamma);
+        let mut xs: Vec<&[F]> = Vec::with_capacity(n);
+        xs.push(&accumulator.instance);
+        xs.extend(incoming.iter().map(Vec::as_slice));
+        Ok(AccumulatorInstance {
+            instance: combine(&l, &xs),
+            betas: betas_star,
+            error: f_alpha * l[0] + vanishing_at(n, gamma) * poly_ops::eval_at(&k_coeffs, gamma),
+        })
+    }
+
+    /// Decider: `Σ_i pow_i(β) · f_i(x, w) == e`.
+    pub fn decide<F: Field>(
+        evaluator: &impl RoundPolyEvaluator<F>,
+        accumulator: &Accumulator<F>,
+    ) -> bool {
+        let acc = &accumulator.instance;
+        weighted_constraints(
+            evaluator,
+            &acc.instance,
+            &accumulator.witness,
+            &pow_weights(&acc.betas),
+        ) == acc.error
+    }
 
     /// Fold `n` polynomials using `log_n` linear coefficient pairs `(a, b)`.
     ///
@@ -77,8 +535,12 @@ pub mod protogalaxy {
 
 #[cfg(test)]
 mod tests {
-    use super::protogalaxy::fold;
+    use super::protogalaxy::{
+        decide, fold, fold_prove, fold_verify, pow_tree, Accumulator, FoldingError,
+    };
+    use crate::coefficient_sumcheck::RoundPolyEvaluator;
     use crate::tests::F64;
+    use ark_ff::{AdditiveGroup, Field};
     use ark_poly::{univariate::DensePolynomial, DenseUVPolynomial};
 
     #[test]
@@ -160,4 +622,186 @@ mod tests {
             assert_eq!(*n, *o, "coefficient mismatch");
         }
     }
+
+    /// `f_i(x, w) = a_i^{d-1} · b_i − c_i` over `m` rows (degree `d`), with
+    /// instance column `c` and witness columns `a, b`.
+    struct PowerChain {
+        m: usize,
+        d: usize,
+    }
+
+    impl RoundPolyEvaluator<F64> for PowerChain {
+        fn degree(&self) -> usize {
+            self.d
+        }
+
+        fn accumulate_pair(&self, coeffs: &mut [F64], _tw: &[(&[F64], &[F64])], pw: &[(F64, F64)]) {
+            let ((c0, c1), (a0, a1), (b0, b1)) = (pw[0], pw[1], pw[2]);
+            // b(X) · a(X)^{d-1}, multiplying in place from the top.
+            let mut prod = vec![F64::ZERO; self.d + 1];
+            prod[0] = b0;
+            prod[1] = b1 - b0;
+            for deg in 1..self.d {
+                for j in (1..=deg + 1).rev() {
+                    prod[j] = prod[j] * a0 + prod[j - 1] * (a1 - a0);
+                }
+                prod[0] *= a0;
+            }
+            for (c, p) in coeffs.iter_mut().zip(&prod) {
+                *c += p;
+            }
+            coeffs[0] -= c0;
+            coeffs[1] -= c1 - c0;
+        }
+
+        fn parallelize(&self) -> bool {
+            false
+        }
+    }
+
+    /// A satisfying `(x, w)`: pick `a, b` at random, solve for `c`.
+    fn satisfying(c: &PowerChain, rng: &mut impl ark_std::rand::RngCore) -> (Vec<F64>, Vec<F64>) {
+        use ark_ff::UniformRand;
+        let w: Vec<F64> = (0..2 * c.m).map(|_| F64::rand(rng)).collect();
+        let (a, b) = w.split_at(c.m);
+        let x = a
+            .iter()
+            .zip(b)
+            .map(|(&ai, &bi)| ai.pow([c.d as u64 - 1]) * bi)
+            .collect();
+        (x, w)
+    }
+
+    /// The evaluator's round polynomial along a line of rows matches the
+    /// constraint evaluated pointwise.
+    #[test]
+    fn test_power_chain_accumulates_line() {
+        use ark_ff::UniformRand;
+        use ark_std::test_rng;
+
+        let mut rng = test_rng();
+        for d in 1..=4 {
+            let c = PowerChain { m: 1, d };
+            let pw: Vec<(F64, F64)> = (0..3)
+                .map(|_| (F64::rand(&mut rng), F64::rand(&mut rng)))
+                .collect();
+            let mut coeffs = vec![F64::ZERO; d + 1];
+            c.accumulate_pair(&mut coeffs, &[], &pw);
+
+            let x = F64::rand(&mut rng);
+            let at = |(lo, hi): (F64, F64)| lo + x * (hi - lo);
+            let expected = at(pw[1]).pow([d as u64 - 1]) * at(pw[2]) - at(pw[0]);
+            let got = coeffs.iter().rev().fold(F64::ZERO, |acc, &k| acc * x + k);
+            assert_eq!(got, expected, "d={d}");
+        }
+    }
+
+    #[test]
+    fn test_pow_tree_matches_naive() {
+        use ark_ff::UniformRand;
+        use ark_poly::Polynomial;
+        use ark_std::test_rng;
+
+        let mut rng = test_rng();
+        let t = 4;
+        let leaves: Vec<F64> = (0..1 << t).map(|_| F64::rand(&mut rng)).collect();
+        let betas: Vec<F64> = (0..t).map(|_| F64::rand(&mut rng)).collect();
+        let deltas: Vec<F64> = (0..t).map(|_| F64::rand(&mut rng)).collect();
+        let poly = pow_tree(&leaves, &betas, &deltas);
+        assert!(poly.degree() <= t);
+
+        let x = F64::rand(&mut rng);
+        let naive: F64 = leaves
+            .iter()
+            .enumerate()
+            .map(|(i, &l)| {
+                (0..t)
+                    .filter(|j| (i >> j) & 1 == 1)
+                    .map(|j| betas[j] + x * deltas[j])
+                    .product::<F64>()
+                    * l
+            })
+            .sum();
+        assert_eq!(poly.evaluate(&x), naive);
+    }
+
+    /// Folding `k` satisfying instances into an accumulator keeps the
+    /// relaxed relation, and prover and verifier agree on the instance.
+    #[test]
+    fn test_protogalaxy_fold_roundtrip() {
+        use crate::tests::ReplayTranscript;
+        use ark_ff::UniformRand;
+        use ark_std::test_rng;
+
+        let mut rng = test_rng();
+        for (d, k) in [(1, 1), (2, 1), (2, 3), (3, 2)] {
+            let c = PowerChain { m: 8, d };
+            let betas: Vec<F64> = (0..3).map(|_| F64::rand(&mut rng)).collect();
+            // A relaxed (non-zero error) starting accumulator.
+            let x0: Vec<F64> = (0..c.m).map(|_| F64::rand(&mut rng)).collect();
+            let w0: Vec<F64> = (0..2 * c.m).map(|_| F64::rand(&mut rng)).collect();
+            let mut acc = Accumulator::new(&c, x0, w0, betas);
+            assert!(decide(&c, &acc));
+
+            for _ in 0..2 {
+                let incoming: Vec<_> = (0..k).map(|_| satisfying(&c, &mut rng)).collect();
+                let mut t = ReplayTranscript::new(0xE00);
+                let folded = fold_prove(&c, &acc, &incoming, &mut t);
+                assert!(decide(&c, &folded), "d={d}, k={k}");
+
+                let xs: Vec<Vec<F64>> = incoming.iter().map(|(x, _)| x.clone()).collect();
+                let mut vt = t.replay();
+                let v_instance = fold_verify(&c, &acc.instance, &xs, &mut vt).unwrap();
+                assert_eq!(v_instance, folded.instance, "d={d}, k={k}");
+                assert_eq!(vt.cursor, vt.tape.len());
+                acc = folded;
+            }
+        }
+    }
+
+    #[test]
+    fn test_protogalaxy_rejects_unsatisfying_instance() {
+        use crate::tests::ReplayTranscript;
+        use ark_ff::UniformRand;
+        use ark_std::test_rng;
+
+        let mut rng = test_rng();
+        let c = PowerChain { m: 4, d: 2 };
+        let betas: Vec<F64> = (0..2).map(|_| F64::rand(&mut rng)).collect();
+        let (x0, w0) = satisfying(&c, &mut rng);
+        let acc = Accumulator::new(&c, x0, w0, betas);
+
+        let (mut x1, w1) = satisfying(&c, &mut rng);
+        x1[0] += F64::from(1u64);
+        let mut t = ReplayTranscript::new(0xE01);
+        let folded = fold_prove(&c, &acc, &[(x1, w1)], &mut t);
+        assert!(!decide(&c, &folded));
+    }
+
+    #[test]
+    fn test_protogalaxy_verify_rejects_instance_length() {
+        use crate::tests::ReplayTranscript;
+        use ark_ff::UniformRand;
+        use ark_std::test_rng;
+
+        let mut rng = test_rng();
+        let c = PowerChain { m: 4, d: 2 };
+        let betas: Vec<F64> = (0..2).map(|_| F64::rand(&mut rng)).collect();
+        let (x0, w0) = satisfying(&c, &mut rng);
+        let acc = Accumulator::new(&c, x0, w0, betas);
+
+        let (x1, w1) = satisfying(&c, &mut rng);
+        let mut t = ReplayTranscript::new(0xE02);
+        fold_prove(&c, &acc, &[(x1.clone(), w1)], &mut t);
+
+        let mut vt = t.replay();
+        assert_eq!(
+            fold_verify(&c, &acc.instance, &[x1[1..].to_vec()], &mut vt),
+            Err(FoldingError::InstanceLength {
+                index: 0,
+                expected: 4,
+                got: 3
+            })
+        );
+    }
 }
@@ -0,0 +1,281 @@
+//! Grand-product argument via a binary product tree (GKR-style).
+//!
+//! Proves `Π_{x ∈ {0,1}^n} f(x) = P` by reducing it to a single claim
+//! about `f` at a random point, as used by permutation and
+//! memory-checking arguments.
+//!
+//! # Tree
+//!
+//! Layer `n` is `f` itself; layer `i` has `2^i` entries and each node is the
+//! product of its two children in layer `i + 1`. With the MSB layout of the
+//! canonical provers the children of node `x` are `V_{i+1}(0, x)` and
+//! `V_{i+1}(1, x)`, i.e. the low and high halves of the child layer:
+//!
+//! ```text
+//! V_i(x) = V_{i+1}(0, x) · V_{i+1}(1, x)
+//! ```
+//!
+//! # Protocol
+//!
+//! The prover sends the top layer (`2^k` entries, `k = top_vars`) in the
+//! clear; the verifier checks that their product is `P` and squeezes a
+//! point `z ∈ F^k`. Then, layer by layer, a claim `V_i(z) = c` is reduced
+//! to a claim on `V_{i+1}`:
+//!
+//! 1. Run an eq-weighted product sumcheck
+//!    ([`EqProductProver`]) for
+//!    `c = Σ_x eq(z, x) · V_{i+1}(0, x) · V_{i+1}(1, x)`, ending at `r`.
+//! 2. The prover sends `v0 = V_{i+1}(0, r)` and `v1 = V_{i+1}(1, r)`; the
+//!    verifier checks the sumcheck's final claim against `eq(z, r)·v0·v1`.
+//! 3. The verifier squeezes `τ` and continues with the line reduction
+//!    `V_{i+1}(τ, r) = v0 + τ·(v1 − v0)`.
+//!
+//! The final claim is about `f` at a random point in `F^n`. Sending the
+//! top layers in the clear (`top_vars > 0`) trades `2^k` field elements
+//! for `k` fewer layer sumchecks.
+//!
+//! ```ignore
+//! // prover
+//! let (product, claim) = grand_product_prove(f_evals, top_vars, &mut pt);
+//! // verifier
+//! let claim = grand_product_verify(product, num_vars, top_vars, &mut vt)?;
+//! // caller: check f(claim.point) == claim.value (e.g. PCS opening)
+//! ```
+
+use crate::field::SumcheckField;
+use crate::hypercube::eq_poly_non_binary;
+use crate::polynomial::multilinear::mle_eval;
+use crate::proof::SumcheckError;
+use crate::provers::eq_product::EqProductProver;
+use crate::runner::sumcheck;
+use crate::transcript::{ProverTranscript, VerifierTranscript};
+use crate::verifier::sumcheck_verify;
+
+use alloc::vec::Vec;
+
+/// A reduced evaluation claim `f(point) = value`.
+///
+/// The caller must check this claim, e.g. with a polynomial commitment
+/// opening.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GrandProductClaim<F> {
+    /// Evaluation point in `F^n` (MSB order).
+    pub point: Vec<F>,
+    /// Claimed value of `f` at `point`.
+    pub value: F,
+}
+
+/// Build the product tree, leaves first: `layers[0] = f`, last layer `[P]`.
+fn product_layers<F: SumcheckField>(f_evals: Vec<F>) -> Vec<Vec<F>> {
+    let mut layers = Vec::with_capacity(f_evals.len().trailing_zeros() as usize + 1);
+    layers.push(f_evals);
+    while let Some(prev) = layers.last().filter(|l| l.len() > 1) {
+        let (lo, hi) = prev.split_at(prev.len() / 2);
+        let next = lo.iter().zip(hi).map(|(&a, &b)| a * b).collect();
+        layers.push(next);
+    }
+    layers
+}
+
+/// Prover side of the grand-product argument.
+///
+/// `f_evals` holds `f` over `{0,1}^n` (MSB order) and its length must be a
+/// power of two; pad with `F::ONE` to preserve the product. The top
+/// `2^top_vars` layer is sent in the clear.
+///
+/// Returns the product `P` and the reduced claim on `f`.
+pub fn grand_product_prove<F: SumcheckField, T: ProverTranscript<F>>(
+    f_evals: Vec<F>,
+    top_vars: usize,
+    transcript: &mut T,
+) -> (F, GrandProductClaim<F>) {
+    assert!(
+        f_evals.len().is_power_of_two(),
+        "f_evals length must be a power of two"
+    );
+    let num_vars = f_evals.len().trailing_zeros() as usize;
+    assert!(
+        top_vars <= num_vars,
+        "top_vars exceeds the number of variables"
+    );
+
+    let layers = product_layers(f_evals);
+    let product = layers[num_vars][0];
+
+    // Top layer in the clear.
+    let top = &layers[num_vars - top_vars];
+    for &v in top {
+        transcript.send(v);
+    }
+    let mut point: Vec<F> = (0..top_vars).map(|_| transcript.challenge()).collect();
+    let mut value = mle_eval(top, &point);
+
+    // Layer-by-layer reduction towards the leaves.
+    for layer in layers[..num_vars - top_vars].iter().rev() {
+        let (lo, hi) = layer.split_at(layer.len() / 2);
+        let m = point.len();
+        let mut prover = EqProductProver::new(point, lo.to_vec(), hi.to_vec());
+        let proof = sumcheck(&mut prover, m, transcript, |_, _| {});
+        let (evals, _) = prover.final_factors();
+        let (v0, v1) = (evals[0], evals[1]);
+        transcript.send(v0);
+        transcript.send(v1);
+        let tau = transcript.challenge();
+
+        point = Vec::with_capacity(m + 1);
+        point.push(tau);
+        point.extend(proof.challenges);
+        value = v0 + tau * (v1 - v0);
+    }
+
+    (product, GrandProductClaim { point, value })
+}
+
+/// Verifier side of the grand-product argument.
+///
+/// Checks `Π f = product` for `f` over `{0,1}^num_vars`, with the top
+/// `2^top_vars` layer received in the clear. Returns the reduced claim on
+/// `f`, which the caller must check.
+///
+/// Errors:
+/// - [`SumcheckError::ConsistencyCheck`] (round 0) if the clear top layer
+///   does not multiply to `product`.
+/// - [`SumcheckError::FinalEvaluation`] if a layer's sumcheck does not
+///   reduce to `eq(z, r) · v0 · v1`.
+/// - Any error from the per-layer [`sumcheck_verify`].
+pub fn grand_product_verify<F: SumcheckField, T: VerifierTranscript<F>>(
+    product: F,
+    num_vars: usize,
+    top_vars: usize,
+    transcript: &mut T,
+) -> Result<GrandProductClaim<F>, SumcheckError> {
+    assert!(
+        top_vars <= num_vars,
+        "top_vars exceeds the number of variables"
+    );
+
+    let mut top = Vec::with_capacity(1 << top_vars);
+    for _ in 0..1usize << top_vars {
+        top.push(
+            transcript
+                .receive()
+                .map_err(|_| SumcheckError::TranscriptError { round: 0 })?,
+        );
+    }
+    if top.iter().fold(F::ONE, |acc, &v| acc * v) != product {
+        return Err(SumcheckError::ConsistencyCheck { round: 0 });
+    }
+    let mut point: Vec<F> = (0..top_vars).map(|_| transcript.challenge()).collect();
+    let mut value = mle_eval(&top, &point);
+
+    for _ in top_vars..num_vars {
+        let m = point.len();
+        let result = sumcheck_verify(value, 3, m, transcript, |_, _| Ok(()))?;
+        let mut receive = || {
+            transcript
+                .receive()
+                .map_err(|_| SumcheckError::TranscriptError { round: m })
+        };
+        let v0 = receive()?;
+        let v1 = receive()?;
+        if result.final_claim != eq_poly_non_binary(&point, &result.challenges) * v0 * v1 {
+            return Err(SumcheckError::FinalEvaluation);
+        }
+        let tau = transcript.challenge();
+
+        point = Vec::with_capacity(m + 1);
+        point.push(tau);
+        point.extend(result.challenges);
+        value = v0 + tau * (v1 - v0);
+    }
+
+    Ok(GrandProductClaim { point, value })
+}
+
+#[cfg(all(test, feature = "arkworks"))]
+mod tests {
+    use super::*;
+    use crate::tests::{ReplayTranscript, F64};
+    use ark_ff::UniformRand;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    fn random_evals(num_vars: usize, seed: u64) -> Vec<F64> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..1 << num_vars).map(|_| F64::rand(&mut rng)).collect()
+    }
+
+    #[test]
+    fn grand_product_roundtrip() {
+        for num_vars in 0..=6 {
+            for top_vars in 0..=num_vars.min(3) {
+                let f = random_evals(num_vars, 0x600 ^ num_vars as u64);
+                let expected: F64 = f.iter().product();
+
+                let mut t = ReplayTranscript::new(0x601);
+                let (product, p_claim) = grand_product_prove(f.clone(), top_vars, &mut t);
+                assert_eq!(product, expected);
+
+                let mut vt = t.replay();
+                let v_claim = grand_product_verify(product, num_vars, top_vars, &mut vt)
+                    .unwrap_or_else(|e| panic!("n={num_vars}, k={top_vars}: {e}"));
+                assert_eq!(v_claim, p_claim, "n={num_vars}, k={top_vars}");
+                assert_eq!(v_claim.point.len(), num_vars);
+                assert_eq!(mle_eval(&f, &v_claim.point), v_claim.value);
+                assert_eq!(vt.cursor, vt.tape.len(), "verifier consumed the whole tape");
+            }
+        }
+    }
+
+    #[test]
+    fn grand_product_rejects_wrong_product() {
+        let num_vars = 5;
+        let f = random_evals(num_vars, 0x602);
+
+        for top_vars in [0, 2] {
+            let mut t = ReplayTranscript::new(0x603);
+            let (product, _) = grand_product_prove(f.clone(), top_vars, &mut t);
+
+            let mut vt = t.replay();
+            let wrong = product + F64::from(1u64);
+            assert_eq!(
+                grand_product_verify(wrong, num_vars, top_vars, &mut vt),
+                Err(SumcheckError::ConsistencyCheck { round: 0 })
+            );
+        }
+    }
+
+    /// Tape slot of `v0` after the sumcheck of `layer` (0 = first below
+    /// the clear top layer).
+    ///
+    /// Tape: `2^k` top values and `k` challenges, then per layer with `m`
+    /// rounds: `m × (3 round values, r)`, `v0`, `v1`, `τ`.
+    fn v0_slot(top_vars: usize, layer: usize) -> usize {
+        let round = 3 + 1;
+        let earlier: usize = (top_vars..top_vars + layer).map(|m| m * round + 3).sum();
+        (1 << top_vars) + top_vars + earlier + (top_vars + layer) * round
+    }
+
+    /// A cheating prover that claims the product of a different `f` gets
+    /// through the clear top layer but is caught further down.
+    #[test]
+    fn grand_product_rejects_tampered_layer_value() {
+        let num_vars = 4;
+        let f = random_evals(num_vars, 0x604);
+
+        let mut t = ReplayTranscript::new(0x605);
+        grand_product_prove(f, 1, &mut t);
+
+        for layer in 0..num_vars - 1 {
+            let mut vt = t.replay();
+            vt.tape[v0_slot(1, layer)] += F64::from(1u64);
+            let product: F64 = vt.tape[0] * vt.tape[1];
+            assert_eq!(
+                grand_product_verify(product, num_vars, 1, &mut vt),
+                Err(SumcheckError::FinalEvaluation),
+                "layer {layer}"
+            );
+        }
+        // The last layer's `v0, v1, τ` close the tape.
+        assert_eq!(v0_slot(1, num_vars - 2) + 3, t.tape.len());
+    }
+}
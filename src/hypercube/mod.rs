@@ -1,7 +1,5 @@
-#[cfg(feature = "arkworks")]
 mod eq_evals;
 mod iter;
 
-#[cfg(feature = "arkworks")]
 pub use eq_evals::{compute_hypercube_eq_evals, eq_poly, eq_poly_non_binary};
 pub use iter::{Ascending, BitReverse, HypercubePoint};
@@ -2,23 +2,34 @@
 //!
 //! Sumcheck protocol (Thaler Proposition 4.1) with SIMD acceleration.
 
-#![cfg_attr(not(feature = "arkworks"), no_std)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
 
 // ─── Generic field trait ─────────────────────────────────────────────────────
 
+pub mod binary_field;
 pub mod field;
 pub mod proof;
+pub mod soundness;
 
 // ─── New canonical API (Thaler §4.1) ────────────────────────────────────────
 
+pub mod batch_opening;
+pub mod batch_verify;
+pub mod fixed_verifier;
 pub mod fold;
+pub mod grand_product;
+pub mod logup;
+pub mod oracle;
 pub mod polynomial;
 pub mod provers;
 pub mod runner;
+pub mod serialization;
+pub mod session;
 pub mod sumcheck_prover;
 pub mod verifier;
+pub mod zk_sumcheck;
 
 /// No-op per-round hook for the prover. Pass to `sumcheck()` when no hook is needed.
 ///
@@ -58,12 +69,16 @@ pub use multilinear_sumcheck::{
 
 #[cfg(feature = "arkworks")]
 pub mod coefficient_sumcheck;
+#[cfg(feature = "r1cs")]
+pub mod constraints;
 #[cfg(feature = "arkworks")]
 pub mod folding;
 pub mod hypercube;
 #[cfg(feature = "arkworks")]
 pub mod poly_ops;
 #[cfg(feature = "arkworks")]
+pub mod reduce_to_one;
+#[cfg(feature = "arkworks")]
 pub(crate) mod reductions;
 #[cfg(all(feature = "arkworks", feature = "simd"))]
 pub(crate) mod simd_fields;
@@ -0,0 +1,364 @@
+//! LogUp-GKR fractional sumcheck (Papini–Haböck).
+//!
+//! Proves `Σ_{x ∈ {0,1}^n} p(x) / q(x) = S` for lookup arguments by
+//! reducing it to a single claim about `p` and `q` at a random point.
+//!
+//! # Fraction tree
+//!
+//! Layer `n` holds the leaf fractions `(p, q)`; each parent adds its two
+//! children without dividing:
+//!
+//! ```text
+//! (p_L, q_L) + (p_R, q_R) = (p_L·q_R + p_R·q_L, q_L·q_R)
+//! ```
+//!
+//! The root `(P, Q)` satisfies `P / Q = S` whenever no denominator is zero.
+//!
+//! # Protocol
+//!
+//! The prover sends `(P, Q)`; the verifier checks `P = S·Q` with `Q ≠ 0`.
+//! Then, layer by layer, claims `p_i(z) = c_p`, `q_i(z) = c_q` are batched
+//! with a fresh `λ` and reduced by one eq-weighted sumcheck:
+//!
+//! ```text
+//! c_p + λ·c_q = Σ_x eq(z, x) · (p_L·q_R + p_R·q_L + λ·q_L·q_R)(x)
+//! ```
+//!
+//! After the prover sends the four child evaluations at the sumcheck
+//! point `r`, the verifier squeezes `τ` and continues with the line
+//! reduction `p_{i+1}(τ, r) = p_L(r) + τ·(p_R(r) − p_L(r))` (same for `q`).
+//!
+//! # Layout
+//!
+//! [`Layout::Msb`] pairs siblings across halves (`x` and `x + 2^{n-1}`),
+//! matching the MSB provers; [`Layout::Lsb`] pairs adjacent entries
+//! (`2x` and `2x + 1`), matching the LSB provers and streamed inputs.
+//! The returned point uses the same convention as the layout: `point[0]`
+//! binds the most (MSB) or least (LSB) significant bit of a leaf index.
+//!
+//! ```ignore
+//! // prover
+//! let (sum, claim) = logup_prove(p, q, Layout::Msb, &mut pt);
+//! // verifier
+//! let claim = logup_verify(sum, num_vars, Layout::Msb, &mut vt)?;
+//! // caller: check p(claim.point) == claim.p_value, q(claim.point) == claim.q_value
+//! ```
+
+use crate::field::SumcheckField;
+use crate::hypercube::eq_poly_non_binary;
+use crate::proof::SumcheckError;
+use crate::provers::eq_product::EqProductProver;
+use crate::runner::sumcheck;
+use crate::transcript::{ProverTranscript, VerifierTranscript};
+use crate::verifier::sumcheck_verify;
+
+use alloc::{vec, vec::Vec};
+
+/// Which leaves are siblings in the fraction tree.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Layout {
+    /// Half-split: leaf `x` pairs with `x + 2^{n-1}`.
+    #[default]
+    Msb,
+    /// Pair-split: leaf `2x` pairs with `2x + 1`.
+    Lsb,
+}
+
+impl Layout {
+    /// Split a layer into its (left, right) children tables over the parent index.
+    fn split<F: SumcheckField>(self, layer: &[F]) -> (Vec<F>, Vec<F>) {
+        match self {
+            Layout::Msb => {
+                let (lo, hi) = layer.split_at(layer.len() / 2);
+                (lo.to_vec(), hi.to_vec())
+            }
+            Layout::Lsb => (
+                layer.iter().step_by(2).copied().collect(),
+                layer.iter().skip(1).step_by(2).copied().collect(),
+            ),
+        }
+    }
+
+    /// Convert between this layout's point order and the MSB order used
+    /// by [`EqProductProver`]. The map is an involution.
+    fn to_msb<F: SumcheckField>(self, point: &[F]) -> Vec<F> {
+        match self {
+            Layout::Msb => point.to_vec(),
+            Layout::Lsb => point.iter().rev().copied().collect(),
+        }
+    }
+
+    /// The child-layer point `(τ, r)` from the sibling challenge `τ` and
+    /// the MSB-ordered sumcheck challenges `r`.
+    fn child_point<F: SumcheckField>(self, tau: F, r_msb: &[F]) -> Vec<F> {
+        let mut point = Vec::with_capacity(r_msb.len() + 1);
+        point.push(tau);
+        point.extend(self.to_msb(r_msb));
+        point
+    }
+}
+
+/// A reduced claim `p(point) = p_value`, `q(point) = q_value` on the leaves.
+///
+/// The caller must check this claim, e.g. with polynomial commitment openings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogupClaim<F> {
+    /// Evaluation point in `F^n`, ordered according to the [`Layout`].
+    pub point: Vec<F>,
+    /// Claimed value of the numerator MLE at `point`.
+    pub p_value: F,
+    /// Claimed value of the denominator MLE at `point`.
+    pub q_value: F,
+}
+
+/// Build the fraction tree, leaves first: `layers[0] = (p, q)`, last layer the root.
+fn fraction_layers<F: SumcheckField>(
+    p: Vec<F>,
+    q: Vec<F>,
+    layout: Layout,
+) -> Vec<(Vec<F>, Vec<F>)> {
+    let mut layers = Vec::with_capacity(p.len().trailing_zeros() as usize + 1);
+    layers.push((p, q));
+    while let Some((p, q)) = layers.last().filter(|(p, _)| p.len() > 1) {
+        let (p_l, p_r) = layout.split(p);
+        let (q_l, q_r) = layout.split(q);
+        let parent_p = (0..p_l.len())
+            .map(|i| p_l[i] * q_r[i] + p_r[i] * q_l[i])
+            .collect();
+        let parent_q = q_l.iter().zip(&q_r).map(|(&a, &b)| a * b).collect();
+        layers.push((parent_p, parent_q));
+    }
+    layers
+}
+
+/// Prover side of the LogUp-GKR fractional sumcheck.
+///
+/// `p` and `q` hold the leaf numerators and denominators over `{0,1}^n`
+/// (indexed according to `layout`); their length must be a power of two.
+/// Pad with `p = 0`, `q = 1` to preserve the sum.
+///
+/// Returns `S = Σ p/q` and the reduced claim on `(p, q)`.
+///
+/// # Panics
+///
+/// If the root denominator is zero (some `q(x) = 0`).
+pub fn logup_prove<F: SumcheckField, T: ProverTranscript<F>>(
+    p: Vec<F>,
+    q: Vec<F>,
+    layout: Layout,
+    transcript: &mut T,
+) -> (F, LogupClaim<F>) {
+    assert_eq!(p.len(), q.len(), "p and q must have the same length");
+    assert!(
+        p.len().is_power_of_two(),
+        "leaf count must be a power of two"
+    );
+    let num_vars = p.len().trailing_zeros() as usize;
+
+    let layers = fraction_layers(p, q, layout);
+    let (root_p, root_q) = (layers[num_vars].0[0], layers[num_vars].1[0]);
+    let sum = root_p
+        * root_q
+            .inverse()
+            .expect("LogUp denominators must be non-zero");
+
+    transcript.send(root_p);
+    transcript.send(root_q);
+
+    let mut claim = LogupClaim {
+        point: Vec::new(),
+        p_value: root_p,
+        q_value: root_q,
+    };
+
+    for (p, q) in layers[..num_vars].iter().rev() {
+        let lambda = transcript.challenge();
+        let (p_l, p_r) = layout.split(p);
+        let (q_l, q_r) = layout.split(q);
+        let m = claim.point.len();
+
+        // Tables [p_L, p_R, q_L, q_R]; terms p_L·q_R + p_R·q_L + λ·q_L·q_R.
+        let mut prover = EqProductProver::from_terms(
+            layout.to_msb(&claim.point),
+            vec![p_l, p_r, q_l, q_r],
+            vec![(F::ONE, 0, 3), (F::ONE, 1, 2), (lambda, 2, 3)],
+        );
+        let proof = sumcheck(&mut prover, m, transcript, |_, _| {});
+        let (children, _) = prover.final_factors();
+        for &v in &children {
+            transcript.send(v);
+        }
+        let tau = transcript.challenge();
+
+        claim = LogupClaim {
+            point: layout.child_point(tau, &proof.challenges),
+            p_value: children[0] + tau * (children[1] - children[0]),
+            q_value: children[2] + tau * (children[3] - children[2]),
+        };
+    }
+
+    (sum, claim)
+}
+
+/// Verifier side of the LogUp-GKR fractional sumcheck.
+///
+/// Checks `Σ p/q = claimed_sum` over `{0,1}^num_vars` and returns the
+/// reduced claim on `(p, q)`, which the caller must check.
+///
+/// Errors:
+/// - [`SumcheckError::ConsistencyCheck`] (round 0) if the root fraction
+///   `(P, Q)` has `Q = 0` or `P ≠ claimed_sum · Q`.
+/// - [`SumcheckError::FinalEvaluation`] if a layer's sumcheck does not
+///   reduce to the batched child combination.
+/// - Any error from the per-layer [`sumcheck_verify`].
+pub fn logup_verify<F: SumcheckField, T: VerifierTranscript<F>>(
+    claimed_sum: F,
+    num_vars: usize,
+    layout: Layout,
+    transcript: &mut T,
+) -> Result<LogupClaim<F>, SumcheckError> {
+    let receive = |transcript: &mut T, round: usize| {
+        transcript
+            .receive()
+            .map_err(|_| SumcheckError::TranscriptError { round })
+    };
+
+    let root_p = receive(transcript, 0)?;
+    let root_q = receive(transcript, 0)?;
+    if root_q.is_zero() || root_p != claimed_sum * root_q {
+        return Err(SumcheckError::ConsistencyCheck { round: 0 });
+    }
+
+    let mut claim = LogupClaim {
+        point: Vec::new(),
+        p_value: root_p,
+        q_value: root_q,
+    };
+
+    for _ in 0..num_vars {
+        let lambda = transcript.challenge();
+        let m = claim.point.len();
+        let batched = claim.p_value + lambda * claim.q_value;
+        let result = sumcheck_verify(batched, 3, m, transcript, |_, _| Ok(()))?;
+
+        let p_l = receive(transcript, m)?;
+        let p_r = receive(transcript, m)?;
+        let q_l = receive(transcript, m)?;
+        let q_r = receive(transcript, m)?;
+        let eq = eq_poly_non_binary(&layout.to_msb(&claim.point), &result.challenges);
+        if result.final_claim != eq * (p_l * q_r + p_r * q_l + lambda * q_l * q_r) {
+            return Err(SumcheckError::FinalEvaluation);
+        }
+        let tau = transcript.challenge();
+
+        claim = LogupClaim {
+            point: layout.child_point(tau, &result.challenges),
+            p_value: p_l + tau * (p_r - p_l),
+            q_value: q_l + tau * (q_r - q_l),
+        };
+    }
+
+    Ok(claim)
+}
+
+#[cfg(all(test, feature = "arkworks"))]
+mod tests {
+    use super::*;
+    use crate::polynomial::multilinear::mle_eval;
+    use crate::tests::{ReplayTranscript, F64};
+    use ark_ff::UniformRand;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    fn random_fractions(num_vars: usize, seed: u64) -> (Vec<F64>, Vec<F64>) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let n = 1 << num_vars;
+        let p = (0..n).map(|_| F64::rand(&mut rng)).collect();
+        let q = (0..n).map(|_| F64::rand(&mut rng)).collect();
+        (p, q)
+    }
+
+    /// Evaluate a leaf table at a point ordered according to `layout`.
+    fn leaf_eval(evals: &[F64], point: &[F64], layout: Layout) -> F64 {
+        mle_eval(evals, &layout.to_msb(point))
+    }
+
+    #[test]
+    fn logup_roundtrip_both_layouts() {
+        for layout in [Layout::Msb, Layout::Lsb] {
+            for num_vars in 0..=6 {
+                let (p, q) = random_fractions(num_vars, 0x700 ^ num_vars as u64);
+                let expected: F64 = p.iter().zip(&q).map(|(&a, &b)| a / b).sum();
+
+                let mut t = ReplayTranscript::new(0x701);
+                let (sum, p_claim) = logup_prove(p.clone(), q.clone(), layout, &mut t);
+                assert_eq!(sum, expected, "{layout:?}, n={num_vars}");
+
+                let mut vt = t.replay();
+                let v_claim = logup_verify(sum, num_vars, layout, &mut vt)
+                    .unwrap_or_else(|e| panic!("{layout:?}, n={num_vars}: {e}"));
+                assert_eq!(v_claim, p_claim);
+                assert_eq!(vt.cursor, vt.tape.len(), "verifier consumed the whole tape");
+                assert_eq!(leaf_eval(&p, &v_claim.point, layout), v_claim.p_value);
+                assert_eq!(leaf_eval(&q, &v_claim.point, layout), v_claim.q_value);
+            }
+        }
+    }
+
+    /// The root fraction depends only on the multiset of leaves, so both
+    /// layouts agree on the sum but not on the transcript.
+    #[test]
+    fn logup_layouts_agree_on_sum() {
+        let (p, q) = random_fractions(5, 0x702);
+        let mut t_msb = ReplayTranscript::new(0x703);
+        let mut t_lsb = ReplayTranscript::new(0x703);
+        let (s_msb, _) = logup_prove(p.clone(), q.clone(), Layout::Msb, &mut t_msb);
+        let (s_lsb, _) = logup_prove(p, q, Layout::Lsb, &mut t_lsb);
+        assert_eq!(s_msb, s_lsb);
+        assert_ne!(t_msb.tape, t_lsb.tape);
+    }
+
+    #[test]
+    fn logup_rejects_wrong_sum() {
+        let num_vars = 4;
+        let (p, q) = random_fractions(num_vars, 0x704);
+        let mut t = ReplayTranscript::new(0x705);
+        let (sum, _) = logup_prove(p, q, Layout::Lsb, &mut t);
+
+        let mut vt = t.replay();
+        assert_eq!(
+            logup_verify(sum + F64::from(1u64), num_vars, Layout::Lsb, &mut vt),
+            Err(SumcheckError::ConsistencyCheck { round: 0 })
+        );
+    }
+
+    /// Tape slot of `p_L` after the sumcheck of `layer` (0 = root).
+    ///
+    /// Tape: `P`, `Q`, then per layer with `m` rounds: `λ`,
+    /// `m × (3 round values, r)`, `p_L`, `p_R`, `q_L`, `q_R`, `τ`.
+    fn children_slot(layer: usize) -> usize {
+        let round = 3 + 1;
+        let earlier: usize = (0..layer).map(|m| 1 + m * round + 4 + 1).sum();
+        2 + earlier + 1 + layer * round
+    }
+
+    #[test]
+    fn logup_rejects_tampered_child() {
+        let num_vars = 4;
+        let (p, q) = random_fractions(num_vars, 0x706);
+        let mut t = ReplayTranscript::new(0x707);
+        let (sum, _) = logup_prove(p, q, Layout::Msb, &mut t);
+
+        // Corrupt each layer's p_R.
+        for layer in 0..num_vars {
+            let mut vt = t.replay();
+            vt.tape[children_slot(layer) + 1] += F64::from(1u64);
+            assert_eq!(
+                logup_verify(sum, num_vars, Layout::Msb, &mut vt),
+                Err(SumcheckError::FinalEvaluation),
+                "layer {layer}"
+            );
+        }
+        // The last layer's children and `τ` close the tape.
+        assert_eq!(children_slot(num_vars - 1) + 5, t.tape.len());
+    }
+}
@@ -0,0 +1,302 @@
+//! Oracle checks for the built-in prover shapes.
+//!
+//! [`sumcheck_verify`](crate::verifier::sumcheck_verify) stops at the
+//! reduced claim `final_claim = g_v(r_v)`; the caller must check it against
+//! `g(r_1, ..., r_v)`. An [`OracleCheck`] evaluates `g` at the challenge
+//! point, so the check becomes one call, or none with
+//! [`sumcheck_verify_with_oracle`](crate::verifier::sumcheck_verify_with_oracle).
+//!
+//! | Oracle | `g(x)` | Prover |
+//! |---|---|---|
+//! | [`MultilinearOracle`] | `p(x)` | [`MultilinearProver`](crate::provers::multilinear::MultilinearProver) |
+//! | [`InnerProductOracle`] | `a(x) · b(x)` | [`InnerProductProver`](crate::provers::inner_product::InnerProductProver) |
+//! | [`EqFactoredOracle`] | `eq(w, x) · p(x)` | [`EqFactoredProver`](crate::provers::eq_factored::EqFactoredProver) |
+//! | [`GkrOracle`] | `add(b, c)·(W(b) + W(c)) + mult(b, c)·W(b)·W(c)` | [`GkrProver`](crate::provers::gkr::GkrProver) |
+//!
+//! Tables are evaluations over `{0,1}^v` in the MSB layout of the canonical
+//! provers (the first challenge binds the top index bit), zero-padded to
+//! `2^v` as the provers do. Any `Fn(&[F]) -> F` is also an oracle.
+//!
+//! ```ignore
+//! let oracle = InnerProductOracle::new(&a, &b);
+//! let result = sumcheck_verify_with_oracle(sum, 2, v, &oracle, &mut t, noop_hook_verify)?;
+//! // or, after a plain sumcheck_verify:
+//! oracle.check(&result)?;
+//! ```
+
+use crate::field::SumcheckField;
+use crate::hypercube::eq_poly_non_binary;
+use crate::polynomial::multilinear::mle_eval;
+use crate::proof::SumcheckError;
+use crate::verifier::SumcheckResult;
+use alloc::vec::Vec;
+
+/// The verifier's oracle access to the summed polynomial `g`.
+pub trait OracleCheck<F: SumcheckField> {
+    /// `g(point)`, with `point` the sumcheck challenges `r_1, ..., r_v`.
+    fn evaluate(&self, point: &[F]) -> F;
+
+    /// Check `result.final_claim == g(result.challenges)`.
+    ///
+    /// Returns [`SumcheckError::FinalEvaluation`] on mismatch.
+    fn check(&self, result: &SumcheckResult<F>) -> Result<(), SumcheckError> {
+        if self.evaluate(&result.challenges) == result.final_claim {
+            Ok(())
+        } else {
+            Err(SumcheckError::FinalEvaluation)
+        }
+    }
+}
+
+impl<F: SumcheckField, G: Fn(&[F]) -> F> OracleCheck<F> for G {
+    fn evaluate(&self, point: &[F]) -> F {
+        self(point)
+    }
+}
+
+/// Multilinear extension of `evals` at `point`, zero-padding to
+/// `2^point.len()` entries.
+fn padded_mle<F: SumcheckField>(evals: &[F], point: &[F]) -> F {
+    let n = 1usize << point.len();
+    assert!(
+        evals.len() <= n,
+        "table length {} exceeds 2^{}",
+        evals.len(),
+        point.len()
+    );
+    if evals.len() == n {
+        return mle_eval(evals, point);
+    }
+    let mut table = Vec::with_capacity(n);
+    table.extend_from_slice(evals);
+    table.resize(n, F::ZERO);
+    mle_eval(&table, point)
+}
+
+/// `g(x) = p(x)`: the multilinear extension of one table.
+#[derive(Clone, Copy, Debug)]
+pub struct MultilinearOracle<'a, F> {
+    evals: &'a [F],
+}
+
+impl<'a, F: SumcheckField> MultilinearOracle<'a, F> {
+    /// Oracle for the multilinear extension of `evals`.
+    pub fn new(evals: &'a [F]) -> Self {
+        Self { evals }
+    }
+}
+
+impl<F: SumcheckField> OracleCheck<F> for MultilinearOracle<'_, F> {
+    fn evaluate(&self, point: &[F]) -> F {
+        padded_mle(self.evals, point)
+    }
+}
+
+/// `g(x) = a(x) · b(x)`.
+#[derive(Clone, Copy, Debug)]
+pub struct InnerProductOracle<'a, F> {
+    a: &'a [F],
+    b: &'a [F],
+}
+
+impl<'a, F: SumcheckField> InnerProductOracle<'a, F> {
+    /// Oracle for the product of the multilinear extensions of `a` and `b`.
+    pub fn new(a: &'a [F], b: &'a [F]) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<F: SumcheckField> OracleCheck<F> for InnerProductOracle<'_, F> {
+    fn evaluate(&self, point: &[F]) -> F {
+        padded_mle(self.a, point) * padded_mle(self.b, point)
+    }
+}
+
+/// `g(x) = eq(w, x) · p(x)`.
+#[derive(Clone, Copy, Debug)]
+pub struct EqFactoredOracle<'a, F> {
+    w: &'a [F],
+    p: &'a [F],
+}
+
+impl<'a, F: SumcheckField> EqFactoredOracle<'a, F> {
+    /// Oracle for `eq(w, ·) · p`, with `w ∈ F^v` and `p` over `{0,1}^v`.
+    pub fn new(w: &'a [F], p: &'a [F]) -> Self {
+        Self { w, p }
+    }
+}
+
+impl<F: SumcheckField> OracleCheck<F> for EqFactoredOracle<'_, F> {
+    /// `eq(w, r) · p(r)`, where `eq(w, r) = Π_j (w_j·r_j + (1 − w_j)(1 − r_j))`.
+    fn evaluate(&self, point: &[F]) -> F {
+        assert_eq!(self.w.len(), point.len(), "eq point arity mismatch");
+        eq_poly_non_binary(self.w, point) * padded_mle(self.p, point)
+    }
+}
+
+/// `g(b, c) = add(b, c) · (W(b) + W(c)) + mult(b, c) · W(b) · W(c)`.
+///
+/// `point = (b*, c*)` with `b*, c* ∈ F^k`: the first `k` challenges bind
+/// the `b` half of the gate tables (`add[b · 2^k + c]`).
+#[derive(Clone, Copy, Debug)]
+pub struct GkrOracle<'a, F> {
+    add: &'a [F],
+    mult: &'a [F],
+    w: &'a [F],
+}
+
+impl<'a, F: SumcheckField> GkrOracle<'a, F> {
+    /// Oracle for gate tables over `{0,1}^{2k}` and witness `W` over
+    /// `{0,1}^k`.
+    pub fn new(add: &'a [F], mult: &'a [F], w: &'a [F]) -> Self {
+        Self { add, mult, w }
+    }
+
+    /// `(W(b*), W(c*))` at `point = (b*, c*)`: the two claims handed to
+    /// the reduce-to-one step.
+    pub fn w_values(&self, point: &[F]) -> (F, F) {
+        assert!(point.len() % 2 == 0, "GKR point must have even arity");
+        let (b, c) = point.split_at(point.len() / 2);
+        (padded_mle(self.w, b), padded_mle(self.w, c))
+    }
+}
+
+impl<F: SumcheckField> OracleCheck<F> for GkrOracle<'_, F> {
+    fn evaluate(&self, point: &[F]) -> F {
+        let (w_b, w_c) = self.w_values(point);
+        padded_mle(self.add, point) * (w_b + w_c) + padded_mle(self.mult, point) * (w_b * w_c)
+    }
+}
+
+#[cfg(all(test, feature = "arkworks"))]
+mod tests {
+    use super::*;
+    use crate::provers::eq_factored::{build_eq_table, EqFactoredProver};
+    use crate::provers::gkr::GkrProver;
+    use crate::provers::inner_product::InnerProductProver;
+    use crate::provers::multilinear::MultilinearProver;
+    use crate::runner::sumcheck;
+    use crate::sumcheck_prover::SumcheckProver;
+    use crate::tests::{ReplayTranscript, F64};
+    use crate::verifier::{sumcheck_verify, sumcheck_verify_with_oracle};
+    use ark_ff::UniformRand;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    fn random_vec(n: usize, rng: &mut StdRng) -> Vec<F64> {
+        (0..n).map(|_| F64::rand(rng)).collect()
+    }
+
+    /// Prove, then verify against `oracle` in one call.
+    fn verify_with(
+        prover: &mut impl SumcheckProver<F64>,
+        claim: F64,
+        num_vars: usize,
+        oracle: &impl OracleCheck<F64>,
+    ) -> Result<SumcheckResult<F64>, SumcheckError> {
+        let degree = prover.degree();
+        let mut t = ReplayTranscript::new(0x400 ^ num_vars as u64);
+        let proof = sumcheck(prover, num_vars, &mut t, |_, _| {});
+        let result = sumcheck_verify_with_oracle(
+            claim,
+            degree,
+            num_vars,
+            oracle,
+            &mut t.replay(),
+            |_, _| Ok(()),
+        );
+        if let Ok(result) = &result {
+            assert_eq!(result.final_claim, proof.final_value);
+        }
+        result
+    }
+
+    #[test]
+    fn oracles_accept_honest_proofs() {
+        let mut rng = StdRng::seed_from_u64(0x401);
+        for v in 1..=5 {
+            let a = random_vec(1 << v, &mut rng);
+            let b = random_vec(1 << v, &mut rng);
+            let w = random_vec(v, &mut rng);
+
+            let claim = a.iter().sum();
+            let oracle = MultilinearOracle::new(&a);
+            verify_with(&mut MultilinearProver::new(a.clone()), claim, v, &oracle).unwrap();
+
+            let claim = a.iter().zip(&b).map(|(x, y)| *x * y).sum();
+            let oracle = InnerProductOracle::new(&a, &b);
+            let prover = &mut InnerProductProver::new(a.clone(), b.clone());
+            verify_with(prover, claim, v, &oracle).unwrap();
+
+            let claim = build_eq_table(&w).iter().zip(&a).map(|(e, x)| *e * x).sum();
+            let oracle = EqFactoredOracle::new(&w, &a);
+            verify_with(
+                &mut EqFactoredProver::new(w.clone(), a.clone()),
+                claim,
+                v,
+                &oracle,
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn gkr_oracle_accepts_honest_proof() {
+        let mut rng = StdRng::seed_from_u64(0x402);
+        for k in 1..=3 {
+            let n = 1 << k;
+            let add = random_vec(n * n, &mut rng);
+            let mult = random_vec(n * n, &mut rng);
+            let w = random_vec(n, &mut rng);
+            let mut claim = F64::from(0u64);
+            for b in 0..n {
+                for c in 0..n {
+                    claim += add[b * n + c] * (w[b] + w[c]) + mult[b * n + c] * (w[b] * w[c]);
+                }
+            }
+            let oracle = GkrOracle::new(&add, &mult, &w);
+            let mut prover = GkrProver::new(add.clone(), mult.clone(), w.clone());
+            let result = verify_with(&mut prover, claim, 2 * k, &oracle).unwrap();
+            assert_eq!(
+                oracle.w_values(&result.challenges),
+                prover.claimed_w_values()
+            );
+        }
+    }
+
+    #[test]
+    fn oracle_rejects_wrong_polynomial() {
+        let mut rng = StdRng::seed_from_u64(0x403);
+        let v = 4;
+        let a = random_vec(1 << v, &mut rng);
+        let mut other = a.clone();
+        other[3] += F64::from(1u64);
+        let claim = a.iter().sum();
+
+        let oracle = MultilinearOracle::new(&other);
+        assert_eq!(
+            verify_with(&mut MultilinearProver::new(a.clone()), claim, v, &oracle).unwrap_err(),
+            SumcheckError::FinalEvaluation
+        );
+
+        // Closures are oracles too; `check` works on a plain verifier result.
+        let mut t = ReplayTranscript::new(0x404);
+        sumcheck(&mut MultilinearProver::new(a.clone()), v, &mut t, |_, _| {});
+        let result = sumcheck_verify(claim, 1, v, &mut t.replay(), |_, _| Ok(())).unwrap();
+        let closure = |r: &[F64]| mle_eval(&a, r);
+        assert_eq!(closure.check(&result), Ok(()));
+        assert_eq!(oracle.check(&result), Err(SumcheckError::FinalEvaluation));
+    }
+
+    #[test]
+    fn short_tables_are_zero_padded() {
+        let mut rng = StdRng::seed_from_u64(0x405);
+        let p = random_vec(5, &mut rng);
+        let point = random_vec(3, &mut rng);
+        let mut padded = p.clone();
+        padded.resize(8, F64::from(0u64));
+        assert_eq!(
+            MultilinearOracle::new(&p).evaluate(&point),
+            mle_eval(&padded, &point)
+        );
+    }
+}
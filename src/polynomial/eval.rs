@@ -22,10 +22,13 @@ pub fn eval_horner<F: SumcheckField>(coeffs: &[F], x: F) -> F {
     result
 }
 
-/// Evaluate a polynomial from its evaluations at `{0, 1, ..., d}` at
-/// an arbitrary point `x` via barycentric Lagrange interpolation.
+/// Evaluate a polynomial from its evaluations at the nodes
+/// `{0, 1, ..., d}` at an arbitrary point `x` via barycentric Lagrange
+/// interpolation.
 ///
-/// `evals = [p(0), p(1), ..., p(d)]`.
+/// `evals = [p(0), p(1), ..., p(d)]`, where node `i` is
+/// [`F::eval_node(i)`](SumcheckField::eval_node) (the integer `i` unless
+/// the field overrides it).
 ///
 /// Cost: O(d) with precomputed [`BarycentricWeights`], O(d²) without.
 /// For repeated evaluations at the same degree, precompute weights once.
@@ -49,8 +52,10 @@ pub fn eval_from_evals<F: SumcheckField>(evals: &[F], x: F) -> F {
 /// Compute once per degree, reuse across rounds. The verifier calls this
 /// once and evaluates O(d) per round instead of O(d²).
 ///
-/// Weight `w_i = 1 / Π_{j≠i} (i − j)` for `i, j ∈ {0, ..., d}`.
-/// For consecutive integer nodes these are `(-1)^{d-i} / (i! · (d-i)!)`.
+/// Weight `w_i = 1 / Π_{j≠i} (x_i − x_j)` for nodes
+/// `x_i = F::eval_node(i)`, `i, j ∈ {0, ..., d}`. For consecutive integer
+/// nodes these are `(-1)^{d-i} / (i! · (d-i)!)`.
+#[derive(Clone, Debug)]
 pub struct BarycentricWeights<F: SumcheckField> {
     /// Precomputed `w_i` for each node `i ∈ {0, ..., d}`.
     weights: Vec<F>,
@@ -60,23 +65,9 @@ impl<F: SumcheckField> BarycentricWeights<F> {
     /// Precompute weights for interpolation at `{0, 1, ..., degree}`.
     pub fn new(degree: usize) -> Self {
         let d = degree + 1; // number of nodes
-        let mut weights = Vec::with_capacity(d);
-        for i in 0..d {
-            let mut w = F::ONE;
-            for j in 0..d {
-                if j != i {
-                    let diff = i as i64 - j as i64;
-                    if diff > 0 {
-                        w *= F::from_u64(diff as u64);
-                    } else {
-                        w *= -F::from_u64((-diff) as u64);
-                    }
-                }
-            }
-            // w_i = 1 / Π_{j≠i} (i - j)
-            weights.push(w.inverse().unwrap_or(F::ZERO));
+        Self {
+            weights: (0..d).map(|i| barycentric_weight(i, d)).collect(),
         }
-        Self { weights }
     }
 
     /// Number of interpolation nodes (degree + 1).
@@ -84,13 +75,19 @@ impl<F: SumcheckField> BarycentricWeights<F> {
         self.weights.len()
     }
 
+    /// The weights `w_i`, one per node.
+    #[cfg(feature = "r1cs")]
+    pub(crate) fn weights(&self) -> &[F] {
+        &self.weights
+    }
+
     /// Evaluate the interpolated polynomial at `x`.
     ///
     /// `evals` must have length `num_nodes()`.
     ///
     /// Uses the "first form" of the barycentric formula:
-    /// `p(x) = Σ_i w_i · L(x) / (x - i) · f(i)`
-    /// where `L(x) = Π_j (x - j)`.
+    /// `p(x) = Σ_i w_i · L(x) / (x - x_i) · f(x_i)`
+    /// where `L(x) = Π_j (x - x_j)`.
     ///
     /// Cost: O(d) multiplications + O(d) additions.
     pub fn eval(&self, evals: &[F], x: F) -> F {
@@ -99,14 +96,13 @@ impl<F: SumcheckField> BarycentricWeights<F> {
 
         // Check if x is one of the nodes (avoid division by zero).
         for (i, &eval) in evals.iter().enumerate() {
-            let node = F::from_u64(i as u64);
-            if x == node {
+            if x == F::eval_node(i) {
                 return eval;
             }
         }
 
-        // Compute (x - 0)(x - 1)...(x - d+1) via prefix/suffix products.
-        let x_minus: Vec<F> = (0..d).map(|j| x - F::from_u64(j as u64)).collect();
+        // Compute (x - x_0)(x - x_1)...(x - x_{d-1}) via prefix/suffix products.
+        let x_minus: Vec<F> = (0..d).map(|j| x - F::eval_node(j)).collect();
 
         let mut prefix = vec![F::ONE; d + 1];
         for i in 0..d {
@@ -119,7 +115,7 @@ impl<F: SumcheckField> BarycentricWeights<F> {
 
         let mut result = F::ZERO;
         for i in 0..d {
-            // numerator = Π_{j≠i} (x - j) = prefix[i] · suffix[i+1]
+            // numerator = Π_{j≠i} (x - x_j) = prefix[i] · suffix[i+1]
             let numerator = prefix[i] * suffix[i + 1];
             result += evals[i] * numerator * self.weights[i];
         }
@@ -127,6 +123,86 @@ impl<F: SumcheckField> BarycentricWeights<F> {
     }
 }
 
+/// `w_i = 1 / Π_{j≠i} (x_i − x_j)` over the first `n` nodes.
+fn barycentric_weight<F: SumcheckField>(i: usize, n: usize) -> F {
+    let x_i = F::eval_node(i);
+    let mut w = F::ONE;
+    for j in (0..n).filter(|&j| j != i) {
+        w *= x_i - F::eval_node(j);
+    }
+    w.inverse().unwrap_or(F::ZERO)
+}
+
+/// [`BarycentricWeights`] in fixed-size storage for at most `N` nodes.
+///
+/// Construction and evaluation never touch the heap: weights, nodes and
+/// the evaluation scratch space are `[F; N]` arrays. Used by
+/// [`FixedVerifier`](crate::fixed_verifier::FixedVerifier).
+#[derive(Clone, Copy, Debug)]
+pub struct FixedBarycentricWeights<F: SumcheckField, const N: usize> {
+    /// `x_i = F::eval_node(i)`; only the first `len` entries are used.
+    nodes: [F; N],
+    /// `w_i`; only the first `len` entries are used.
+    weights: [F; N],
+    len: usize,
+}
+
+impl<F: SumcheckField, const N: usize> FixedBarycentricWeights<F, N> {
+    /// Precompute weights for interpolation at `{0, 1, ..., degree}`.
+    ///
+    /// # Panics
+    ///
+    /// If `degree + 1 > N`.
+    pub fn new(degree: usize) -> Self {
+        let len = degree + 1;
+        assert!(len <= N, "{len} nodes exceed capacity {N}");
+        let mut nodes = [F::ZERO; N];
+        let mut weights = [F::ZERO; N];
+        for i in 0..len {
+            nodes[i] = F::eval_node(i);
+            weights[i] = barycentric_weight(i, len);
+        }
+        Self {
+            nodes,
+            weights,
+            len,
+        }
+    }
+
+    /// Number of interpolation nodes (degree + 1).
+    pub fn num_nodes(&self) -> usize {
+        self.len
+    }
+
+    /// Evaluate the interpolated polynomial at `x`; same result as
+    /// [`BarycentricWeights::eval`].
+    ///
+    /// `evals` must have length `num_nodes()`. Cost: O(d) multiplications,
+    /// no allocation.
+    pub fn eval(&self, evals: &[F], x: F) -> F {
+        let d = self.len;
+        debug_assert_eq!(evals.len(), d);
+        let nodes = &self.nodes[..d];
+
+        if let Some(i) = nodes.iter().position(|&x_i| x_i == x) {
+            return evals[i];
+        }
+
+        // suffix[i] = Π_{j>i} (x − x_j); the prefix product runs forward.
+        let mut suffix = [F::ONE; N];
+        for i in (0..d.saturating_sub(1)).rev() {
+            suffix[i] = suffix[i + 1] * (x - nodes[i + 1]);
+        }
+        let mut prefix = F::ONE;
+        let mut result = F::ZERO;
+        for i in 0..d {
+            result += evals[i] * prefix * suffix[i] * self.weights[i];
+            prefix *= x - nodes[i];
+        }
+        result
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -210,5 +286,20 @@ mod tests {
             assert_eq!(v3, eval_horner(&coeffs, F64::from(3u64)));
             assert_eq!(v5, eval_horner(&coeffs, F64::from(5u64)));
         }
+
+        #[test]
+        fn fixed_weights_match_heap_weights() {
+            let evals: Vec<F64> = (0..5u64).map(|i| F64::from(i * i * i + 7)).collect();
+            for degree in 0..5 {
+                let heap = BarycentricWeights::new(degree);
+                let fixed = FixedBarycentricWeights::<F64, 5>::new(degree);
+                assert_eq!(fixed.num_nodes(), heap.num_nodes());
+                for x in [0u64, 1, 3, 4, 9, 1000] {
+                    let x = F64::from(x);
+                    let evals = &evals[..=degree];
+                    assert_eq!(fixed.eval(evals, x), heap.eval(evals, x));
+                }
+            }
+        }
     }
 }
@@ -9,6 +9,8 @@
 //!   an arbitrary point via barycentric Lagrange interpolation. O(d).
 //! - [`BarycentricWeights`]: precompute weights once per degree, reuse
 //!   across rounds for O(d) evaluation instead of O(d²).
+//! - [`FixedBarycentricWeights`]: the same in `[F; N]` arrays, with no
+//!   heap allocation.
 //!
 //! # Sequential Lagrange polynomial
 //!
@@ -16,6 +18,18 @@
 //!   incrementally as you iterate over the hypercube. Composes with
 //!   [`Ascending`](crate::hypercube::Ascending) for cache-friendly streaming.
 //!
+//! # Univariate skip
+//!
+//! - [`SkipDomain`]: multiplicative subgroup of size `2^k` replacing the
+//!   first `k` Boolean variables (Gruen's univariate skip), with Lagrange
+//!   basis, interpolation and the domain-sum identity used by the verifier.
+//!
+//! # Structured weights
+//!
+//! - [`StructuredWeight`]: `Σ α_i·eq(z_i, x) + Σ β_j·pow(y_j, x)` kept as
+//!   its terms, for the WHIR weighted sumcheck; evaluates at the final point
+//!   in `O(v)` per term.
+//!
 //! # Dense polynomial arithmetic
 //!
 //! Zero-allocation operations on coefficient slices:
@@ -23,8 +37,13 @@
 
 mod dense;
 mod eval;
+pub(crate) mod multilinear;
 mod sequential_lagrange;
+mod skip_domain;
+pub(crate) mod weight;
 
 pub use dense::{add_scaled, eval_at, mul_into};
-pub use eval::{eval_from_evals, eval_horner, BarycentricWeights};
+pub use eval::{eval_from_evals, eval_horner, BarycentricWeights, FixedBarycentricWeights};
 pub use sequential_lagrange::SequentialLagrange;
+pub use skip_domain::SkipDomain;
+pub use weight::StructuredWeight;
@@ -0,0 +1,15 @@
+//! Multilinear helpers shared by the composed protocols.
+
+use crate::field::SumcheckField;
+use alloc::vec::Vec;
+
+/// Evaluate the multilinear extension of `evals` (MSB order) at `point`.
+///
+/// `point[0]` binds the most significant bit of the table index.
+pub(crate) fn mle_eval<F: SumcheckField>(evals: &[F], point: &[F]) -> F {
+    let mut table: Vec<F> = evals.to_vec();
+    for &r in point {
+        crate::inner_product_sumcheck::fold(&mut table, r);
+    }
+    table[0]
+}
@@ -0,0 +1,274 @@
+//! Multiplicative evaluation domain for the univariate skip (Gruen).
+
+extern crate alloc;
+use crate::field::SumcheckField;
+use crate::polynomial::mul_into;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A multiplicative subgroup `H = {1, ω, ..., ω^{N-1}}` of size `N = 2^k`
+/// standing in for the first `k` Boolean variables.
+///
+/// Hypercube index `i ∈ {0,1}^k` (MSB order, so `i` is the integer formed
+/// by the first `k` variables) is identified with `ω^i`. A table `f` over
+/// `{0,1}^v` then defines, for every tail `x' ∈ {0,1}^{v-k}`, the
+/// univariate `f̂(Y, x') = Σ_i L_i(Y) · f(i, x')` of degree `< N`, where
+/// `L_i` is the Lagrange basis of `H`.
+///
+/// The skip round sends the coefficients of `h(Y) = Σ_{x'} ĝ(Y, x')` and
+/// the verifier checks `Σ_{y ∈ H} h(y) = claim` via
+/// `Σ_{y ∈ H} y^j = N·[N | j]`.
+#[derive(Clone, Debug)]
+pub struct SkipDomain<F: SumcheckField> {
+    k: usize,
+    omega: F,
+    /// `ω^{-j}` for `j ∈ {0, ..., N-1}`.
+    omega_inv_pows: Vec<F>,
+    n_inv: F,
+}
+
+impl<F: SumcheckField> SkipDomain<F> {
+    /// Domain of size `2^k` generated by `omega`, a primitive `2^k`-th root of unity.
+    ///
+    /// # Panics
+    ///
+    /// If `k == 0`, or `omega` does not have order exactly `2^k`.
+    pub fn new(k: usize, omega: F) -> Self {
+        assert!(k > 0, "univariate skip needs at least one variable");
+        let n = 1usize << k;
+        let mut half_pow = omega;
+        for _ in 1..k {
+            half_pow *= half_pow;
+        }
+        // half_pow = ω^{N/2}: must be −1 for ω to be primitive.
+        assert!(
+            half_pow + F::ONE == F::ZERO,
+            "omega must be a primitive 2^k-th root of unity"
+        );
+        let omega_inv = omega.inverse().expect("omega is non-zero");
+        let mut omega_inv_pows = Vec::with_capacity(n);
+        let mut p = F::ONE;
+        for _ in 0..n {
+            omega_inv_pows.push(p);
+            p *= omega_inv;
+        }
+        Self {
+            k,
+            omega,
+            omega_inv_pows,
+            n_inv: F::from_u64(n as u64)
+                .inverse()
+                .expect("domain size must be invertible"),
+        }
+    }
+
+    /// Number of skipped Boolean variables `k`.
+    pub fn num_vars(&self) -> usize {
+        self.k
+    }
+
+    /// Domain size `N = 2^k`.
+    pub fn size(&self) -> usize {
+        1 << self.k
+    }
+
+    /// The generator `ω`.
+    pub fn generator(&self) -> F {
+        self.omega
+    }
+
+    /// Degree of the skip-round polynomial for a product of `factors`
+    /// multilinear factors: `factors · (N − 1)`.
+    pub fn round_degree(&self, factors: usize) -> usize {
+        factors * (self.size() - 1)
+    }
+
+    /// Lagrange basis `[L_0(r), ..., L_{N-1}(r)]` of `H` at `r`.
+    ///
+    /// `L_i(r) = ω^i · (r^N − 1) / (N · (r − ω^i))`, or the indicator of
+    /// `r = ω^i` when `r ∈ H`.
+    pub fn lagrange_at(&self, r: F) -> Vec<F> {
+        let n = self.size();
+        let mut r_n = r;
+        for _ in 0..self.k {
+            r_n *= r_n;
+        }
+        let z = r_n - F::ONE;
+
+        let mut basis = vec![F::ZERO; n];
+        let mut node = F::ONE;
+        if z == F::ZERO {
+            for b in basis.iter_mut() {
+                if node == r {
+                    *b = F::ONE;
+                    break;
+                }
+                node *= self.omega;
+            }
+            return basis;
+        }
+        let scale = z * self.n_inv;
+        for b in basis.iter_mut() {
+            *b = node * scale * (r - node).inverse().expect("r is not a node");
+            node *= self.omega;
+        }
+        basis
+    }
+
+    /// Coefficients of the degree-`< N` interpolant of `values` over `H`
+    /// (inverse DFT): `c_j = N^{-1} · Σ_i values[i] · ω^{-ij}`.
+    ///
+    /// Cost: O(N²).
+    pub fn interpolate(&self, values: &[F]) -> Vec<F> {
+        let n = self.size();
+        assert_eq!(values.len(), n, "values must have one entry per node");
+        (0..n)
+            .map(|j| {
+                let s: F = values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &v)| v * self.omega_inv_pows[(i * j) % n])
+                    .sum();
+                s * self.n_inv
+            })
+            .collect()
+    }
+
+    /// `Σ_{y ∈ H} h(y)` for `h` given by its coefficients: `N · Σ_{N | j} c_j`.
+    pub fn sum_over_domain(&self, coeffs: &[F]) -> F {
+        let n = self.size();
+        let s: F = coeffs.iter().step_by(n).copied().sum();
+        s * F::from_u64(n as u64)
+    }
+
+    /// The constant coefficient `c_0` implied by `Σ_{y ∈ H} h(y) = claim`
+    /// and the higher coefficients `higher = [c_1, c_2, ...]`.
+    pub fn constant_term(&self, claim: F, higher: &[F]) -> F {
+        let n = self.size();
+        let wraps: F = higher.iter().skip(n - 1).step_by(n).copied().sum();
+        claim * self.n_inv - wraps
+    }
+
+    /// Fold the first `k` variables of an MSB table into the point `r`:
+    /// `out[x'] = Σ_i L_i(r) · values[i·M + x']` with `M = len / N`.
+    pub fn fold(&self, values: &[F], r: F) -> Vec<F> {
+        self.fold_with_basis(values, &self.lagrange_at(r))
+    }
+
+    /// Coefficients of `h(Y) = Σ_{x'} â(Y, x') · b̂(Y, x')` for two MSB
+    /// tables of equal length `N·M` (degree `2N − 2`).
+    ///
+    /// Cost: O(N² · M).
+    pub(crate) fn product_coeffs(&self, a: &[F], b: &[F]) -> Vec<F> {
+        let n = self.size();
+        assert_eq!(a.len(), b.len(), "a and b must have equal length");
+        assert_eq!(a.len() % n, 0, "table length must be a multiple of 2^k");
+        let m = a.len() / n;
+        let mut h = vec![F::ZERO; 2 * n - 1];
+        let mut prod = vec![F::ZERO; 2 * n - 1];
+        let mut col_a = vec![F::ZERO; n];
+        let mut col_b = vec![F::ZERO; n];
+        for x in 0..m {
+            for i in 0..n {
+                col_a[i] = a[i * m + x];
+                col_b[i] = b[i * m + x];
+            }
+            mul_into(
+                &mut prod,
+                &self.interpolate(&col_a),
+                &self.interpolate(&col_b),
+            );
+            for (hj, &pj) in h.iter_mut().zip(&prod) {
+                *hj += pj;
+            }
+        }
+        h
+    }
+
+    pub(crate) fn fold_with_basis(&self, values: &[F], basis: &[F]) -> Vec<F> {
+        let n = self.size();
+        assert_eq!(
+            values.len() % n,
+            0,
+            "table length must be a multiple of 2^k"
+        );
+        let m = values.len() / n;
+        let mut out = vec![F::ZERO; m];
+        for (chunk, &l) in values.chunks_exact(m).zip(basis) {
+            for (o, &v) in out.iter_mut().zip(chunk) {
+                *o += l * v;
+            }
+        }
+        out
+    }
+}
+
+#[cfg(feature = "arkworks")]
+impl<F: ark_ff::FftField> SkipDomain<F> {
+    /// Domain of size `2^k` from the field's two-adic root of unity.
+    ///
+    /// # Panics
+    ///
+    /// If the field has no subgroup of order `2^k`.
+    pub fn from_two_adic(k: usize) -> Self {
+        let omega = F::get_root_of_unity(1u64 << k).expect("field two-adicity is smaller than k");
+        Self::new(k, omega)
+    }
+}
+
+#[cfg(all(test, feature = "arkworks"))]
+mod tests {
+    use super::*;
+    use crate::polynomial::eval_horner;
+    use crate::tests::F64;
+    use ark_ff::UniformRand;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn interpolate_matches_values_on_domain() {
+        let mut rng = StdRng::seed_from_u64(0x800);
+        for k in 1..=4 {
+            let domain = SkipDomain::<F64>::from_two_adic(k);
+            let values: Vec<F64> = (0..domain.size()).map(|_| F64::rand(&mut rng)).collect();
+            let coeffs = domain.interpolate(&values);
+            let mut node = F64::from(1u64);
+            for &v in &values {
+                assert_eq!(eval_horner(&coeffs, node), v, "k={k}");
+                node *= domain.generator();
+            }
+            let total: F64 = values.iter().copied().sum();
+            assert_eq!(domain.sum_over_domain(&coeffs), total, "k={k}");
+        }
+    }
+
+    #[test]
+    fn lagrange_basis_interpolates() {
+        let mut rng = StdRng::seed_from_u64(0x801);
+        let domain = SkipDomain::<F64>::from_two_adic(3);
+        let values: Vec<F64> = (0..8).map(|_| F64::rand(&mut rng)).collect();
+        let coeffs = domain.interpolate(&values);
+        let r = F64::rand(&mut rng);
+        let via_basis: F64 = domain
+            .lagrange_at(r)
+            .iter()
+            .zip(&values)
+            .map(|(&l, &v)| l * v)
+            .sum();
+        assert_eq!(via_basis, eval_horner(&coeffs, r));
+
+        // On a node, the basis is an indicator.
+        let w3 = domain.generator() * domain.generator() * domain.generator();
+        let basis = domain.lagrange_at(w3);
+        assert_eq!(basis[3], F64::from(1u64));
+        assert_eq!(basis.iter().copied().sum::<F64>(), F64::from(1u64));
+    }
+
+    #[test]
+    fn constant_term_recovers_c0() {
+        let mut rng = StdRng::seed_from_u64(0x802);
+        let domain = SkipDomain::<F64>::from_two_adic(2);
+        let coeffs: Vec<F64> = (0..7).map(|_| F64::rand(&mut rng)).collect();
+        let claim = domain.sum_over_domain(&coeffs);
+        assert_eq!(domain.constant_term(claim, &coeffs[1..]), coeffs[0]);
+    }
+}
@@ -0,0 +1,164 @@
+//! Structured weight polynomials for WHIR-style weighted sumchecks.
+
+extern crate alloc;
+use crate::field::SumcheckField;
+use crate::hypercube::eq_poly_non_binary;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A weight `w(x) = Σ_i α_i·eq(z_i, x) + Σ_j β_j·pow(y_j, x)` over
+/// `{0,1}^v`, kept as its terms instead of a `2^v` table.
+///
+/// `pow(y, x) = y^{x_0·2^{v-1} + ... + x_{v-1}}` (MSB order), so
+/// `Σ_x f(x)·pow(y, x)` is the univariate evaluation `f̂(y)` of the
+/// coefficient vector `f`. Its multilinear extension factors per variable:
+///
+/// ```text
+/// pow(y, x) = Π_k (1 − x_k + x_k·y^{2^{v-1-k}})
+/// ```
+///
+/// Both kinds of term are products of per-variable linear factors, which
+/// is what lets the prover ([`WeightedProver`](crate::provers::weighted::WeightedProver))
+/// split and fold them symbolically and the verifier evaluate `w(r)` in
+/// `O(v)` per term ([`evaluate`](Self::evaluate)).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StructuredWeight<F> {
+    /// `(α_i, z_i)` with `z_i ∈ F^v`.
+    pub eq_terms: Vec<(F, Vec<F>)>,
+    /// `(β_j, y_j)`.
+    pub pow_terms: Vec<(F, F)>,
+}
+
+impl<F: SumcheckField> StructuredWeight<F> {
+    /// An empty weight (`w = 0`).
+    pub fn new() -> Self {
+        Self {
+            eq_terms: Vec::new(),
+            pow_terms: Vec::new(),
+        }
+    }
+
+    /// Add `α · eq(z, x)`.
+    pub fn add_eq(&mut self, alpha: F, z: Vec<F>) -> &mut Self {
+        self.eq_terms.push((alpha, z));
+        self
+    }
+
+    /// Add `β · pow(y, x)`.
+    pub fn add_pow(&mut self, beta: F, y: F) -> &mut Self {
+        self.pow_terms.push((beta, y));
+        self
+    }
+
+    /// Evaluate `w(r)` at a point `r ∈ F^v` (MSB order) — the verifier's
+    /// side of the final check `final_claim == f(r) · w(r)`.
+    ///
+    /// Cost: `O(v)` per term.
+    pub fn evaluate(&self, point: &[F]) -> F {
+        let mut acc = F::ZERO;
+        for (alpha, z) in &self.eq_terms {
+            assert_eq!(z.len(), point.len(), "eq point has wrong arity");
+            acc += *alpha * eq_poly_non_binary(z, point);
+        }
+        for &(beta, y) in &self.pow_terms {
+            let term: F = pow_factors(y, point.len())
+                .iter()
+                .zip(point)
+                .map(|(&(c0, c1), &r)| c0 + r * (c1 - c0))
+                .fold(F::ONE, |acc, t| acc * t);
+            acc += beta * term;
+        }
+        acc
+    }
+
+    /// The `2^v` table of `w` (MSB layout). Reference only; the structured
+    /// prover never builds it.
+    pub fn to_evals(&self, num_vars: usize) -> Vec<F> {
+        let mut out = vec![F::ZERO; 1 << num_vars];
+        for (coeff, factors) in self.factored_terms(num_vars) {
+            for (o, t) in out.iter_mut().zip(product_table(&factors)) {
+                *o += coeff * t;
+            }
+        }
+        out
+    }
+
+    /// Every term as `(coefficient, [(c0_k, c1_k)])` with
+    /// `term(x) = coefficient · Π_k (c0_k if x_k = 0 else c1_k)`.
+    pub(crate) fn factored_terms(&self, num_vars: usize) -> Vec<(F, Vec<(F, F)>)> {
+        let eq = self.eq_terms.iter().map(|(alpha, z)| {
+            assert_eq!(z.len(), num_vars, "eq point has wrong arity");
+            (*alpha, z.iter().map(|&zk| (F::ONE - zk, zk)).collect())
+        });
+        let pow = self
+            .pow_terms
+            .iter()
+            .map(|&(beta, y)| (beta, pow_factors(y, num_vars)));
+        eq.chain(pow).collect()
+    }
+}
+
+/// Per-variable factors `(1, y^{2^{v-1-k}})` of `pow(y, ·)` (MSB order).
+fn pow_factors<F: SumcheckField>(y: F, num_vars: usize) -> Vec<(F, F)> {
+    let mut factors = vec![(F::ONE, F::ONE); num_vars];
+    let mut y_pow = y;
+    for f in factors.iter_mut().rev() {
+        f.1 = y_pow;
+        y_pow *= y_pow;
+    }
+    factors
+}
+
+/// Table of `Π_k (c0_k if x_k = 0 else c1_k)` over `{0,1}^{factors.len()}`
+/// (MSB layout). With `(1 − z_k, z_k)` factors this is `eq(z, ·)`.
+pub(crate) fn product_table<F: SumcheckField>(factors: &[(F, F)]) -> Vec<F> {
+    let v = factors.len();
+    let mut table = vec![F::ZERO; 1 << v];
+    table[0] = F::ONE;
+    for (j, &(c0, c1)) in factors.iter().enumerate() {
+        let stride = 1usize << (v - 1 - j);
+        for b in 0..1usize << j {
+            let base = 2 * stride * b;
+            let parent = table[base];
+            table[base] = parent * c0;
+            table[base + stride] = parent * c1;
+        }
+    }
+    table
+}
+
+#[cfg(all(test, feature = "arkworks"))]
+mod tests {
+    use super::*;
+    use crate::polynomial::eval_horner;
+    use crate::polynomial::multilinear::mle_eval;
+    use crate::tests::F64;
+    use ark_ff::UniformRand;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn pow_term_is_univariate_evaluation() {
+        let mut rng = StdRng::seed_from_u64(0xB00);
+        let v = 5;
+        let f: Vec<F64> = (0..1 << v).map(|_| F64::rand(&mut rng)).collect();
+        let y = F64::rand(&mut rng);
+        let mut w = StructuredWeight::new();
+        w.add_pow(F64::from(1u64), y);
+        let weighted: F64 = f.iter().zip(w.to_evals(v)).map(|(&a, b)| a * b).sum();
+        assert_eq!(weighted, eval_horner(&f, y));
+    }
+
+    #[test]
+    fn evaluate_matches_table_extension() {
+        let mut rng = StdRng::seed_from_u64(0xB01);
+        let v = 4;
+        let mut w = StructuredWeight::new();
+        for _ in 0..3 {
+            let z = (0..v).map(|_| F64::rand(&mut rng)).collect();
+            w.add_eq(F64::rand(&mut rng), z);
+        }
+        w.add_pow(F64::rand(&mut rng), F64::rand(&mut rng));
+        let r: Vec<F64> = (0..v).map(|_| F64::rand(&mut rng)).collect();
+        assert_eq!(w.evaluate(&r), mle_eval(&w.to_evals(v), &r));
+    }
+}
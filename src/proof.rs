@@ -5,6 +5,9 @@
 extern crate alloc;
 
 use crate::field::SumcheckField;
+use crate::session::VerifierSession;
+use crate::transcript::ProverTranscript;
+use crate::verifier::DegreeSchedule;
 use alloc::vec::Vec;
 use core::fmt;
 
@@ -33,12 +36,95 @@ pub struct SumcheckProof<F: SumcheckField> {
     pub final_value: F,
 }
 
+/// Wire-only form of a [`SumcheckProof`]: the EvalsInfty round values of
+/// all rounds in one flat buffer, without challenges or final value.
+///
+/// Round `j` occupies the next `max(d_j, 1)` values, so the degree
+/// schedule the verifier already knows fixes the layout. A Fiat–Shamir
+/// verifier re-derives the challenges and the final claim from these
+/// values ([`to_proof`](Self::to_proof)); composed protocols usually get
+/// the final value from the next layer anyway.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompactProof<F: SumcheckField> {
+    /// Round values `h_0 ‖ h_1 ‖ ... ‖ h_{v-1}`.
+    pub wire: Vec<F>,
+}
+
+impl<F: SumcheckField> CompactProof<F> {
+    /// Keep only `proof`'s round values.
+    pub fn from_proof(proof: &SumcheckProof<F>) -> Self {
+        Self {
+            wire: proof.round_polys.concat(),
+        }
+    }
+
+    /// Number of wire values for `num_rounds` rounds of degrees `degrees`.
+    pub fn wire_len<D: DegreeSchedule + ?Sized>(degrees: &D, num_rounds: usize) -> usize {
+        (0..num_rounds).map(|j| degrees.degree(j).max(1)).sum()
+    }
+
+    /// Rebuild the full proof, re-deriving the challenges through
+    /// `transcript`.
+    ///
+    /// Each round's values are absorbed before its challenge is squeezed,
+    /// as in [`verify_proof`](crate::verifier::verify_proof), so
+    /// `transcript` must start in the prover's initial state. The final
+    /// value is the reduced claim; the caller must still check it against
+    /// `g(r_1, ..., r_v)`.
+    ///
+    /// Errors:
+    /// - [`SumcheckError::DegreeMismatch`] at round `j` (`got` the number
+    ///   of values left) if the buffer ends inside round `j`;
+    /// - [`SumcheckError::TrailingValues`] if values are left over after
+    ///   the last round.
+    ///
+    /// ```ignore
+    /// let compact = CompactProof::from_proof(&proof);
+    /// let proof = compact.to_proof(claimed_sum, &|_| 2, v, &mut Sponge::new(domain))?;
+    /// ```
+    pub fn to_proof<T, D>(
+        &self,
+        claimed_sum: F,
+        degrees: &D,
+        num_rounds: usize,
+        transcript: &mut T,
+    ) -> Result<SumcheckProof<F>, SumcheckError>
+    where
+        T: ProverTranscript<F>,
+        D: DegreeSchedule + ?Sized,
+    {
+        let mut session = VerifierSession::with_degrees(claimed_sum, degrees, num_rounds);
+        let mut round_polys = Vec::with_capacity(num_rounds);
+        let mut rest = &self.wire[..];
+        while let Some(len) = session.message_len() {
+            let (message, tail) = rest.split_at(len.min(rest.len()));
+            let _needed = session.receive_message(message)?;
+            for &v in message {
+                transcript.send(v);
+            }
+            session.receive_challenge(transcript.challenge());
+            round_polys.push(message.to_vec());
+            rest = tail;
+        }
+        if !rest.is_empty() {
+            return Err(SumcheckError::TrailingValues { count: rest.len() });
+        }
+        let result = session.finish();
+        Ok(SumcheckProof {
+            round_polys,
+            challenges: result.challenges,
+            final_value: result.final_claim,
+        })
+    }
+}
+
 /// Sumcheck verification error.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SumcheckError {
     /// Round `j` consistency check failed: `g_j(0) + g_j(1) != claim`.
     ConsistencyCheck { round: usize },
-    /// Round polynomial has wrong degree.
+    /// Round `round` carried `got` wire values instead of `expected`
+    /// (`max(d, 1)` for a degree-`d` round).
     DegreeMismatch {
         round: usize,
         expected: usize,
@@ -50,6 +136,14 @@ pub enum SumcheckError {
     TranscriptError { round: usize },
     /// Per-round hook failed (e.g., proof-of-work verification).
     HookError { round: usize },
+    /// Stored challenge for round `j` differs from the re-derived one.
+    ChallengeMismatch { round: usize },
+    /// The soundness bound gives fewer than the required bits of security.
+    InsufficientSecurity { required: u32, achieved: u32 },
+    /// The prover's value for claim `index` differs from the verifier's.
+    ClaimMismatch { index: usize },
+    /// `count` wire values were left over after the last round.
+    TrailingValues { count: usize },
 }
 
 impl fmt::Display for SumcheckError {
@@ -64,7 +158,7 @@ impl fmt::Display for SumcheckError {
                 got,
             } => write!(
                 f,
-                "round {round}: degree mismatch: expected <= {expected}, got {got}"
+                "round {round}: degree mismatch: expected {expected} values, got {got}"
             ),
             SumcheckError::FinalEvaluation => {
                 write!(f, "final evaluation mismatch")
@@ -75,6 +169,124 @@ impl fmt::Display for SumcheckError {
             SumcheckError::HookError { round } => {
                 write!(f, "round {round}: hook error")
             }
+            SumcheckError::ChallengeMismatch { round } => {
+                write!(f, "round {round}: challenge mismatch")
+            }
+            SumcheckError::InsufficientSecurity { required, achieved } => write!(
+                f,
+                "insufficient security: {achieved} bits, required {required}"
+            ),
+            SumcheckError::ClaimMismatch { index } => {
+                write!(f, "claim {index}: claimed value mismatch")
+            }
+            SumcheckError::TrailingValues { count } => {
+                write!(f, "{count} values left over after the last round")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SumcheckError {}
+
+/// Detailed verification error, generic over the field `F` and the
+/// transcript error `E`.
+///
+/// Returned by the `*_detailed` verifiers in [`crate::verifier`]. Carries
+/// both sides of the failing check and preserves the transcript's own
+/// error; [`SumcheckError::from`] drops the values, keeping the kind and
+/// round.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VerifierError<F, E> {
+    /// Round `round` carried `got` wire values instead of `expected`
+    /// (`max(d, 1)` for a degree-`d` round).
+    DegreeMismatch {
+        round: usize,
+        expected: usize,
+        got: usize,
+    },
+    /// The reduced claim (`expected`) differs from the claimed final value.
+    FinalEvaluation { expected: F, actual: F },
+    /// The stored challenge for round `round` (`actual`, `None` if
+    /// missing) differs from the re-derived one (`expected`, `None` past
+    /// the last round).
+    ChallengeMismatch {
+        round: usize,
+        expected: Option<F>,
+        actual: Option<F>,
+    },
+    /// The transcript failed to produce a prover message in round `round`.
+    Transcript { round: usize, source: E },
+    /// The per-round hook rejected round `round`.
+    Hook { round: usize, source: SumcheckError },
+}
+
+impl<F, E> From<VerifierError<F, E>> for SumcheckError {
+    fn from(err: VerifierError<F, E>) -> Self {
+        match err {
+            VerifierError::DegreeMismatch {
+                round,
+                expected,
+                got,
+            } => SumcheckError::DegreeMismatch {
+                round,
+                expected,
+                got,
+            },
+            VerifierError::FinalEvaluation { .. } => SumcheckError::FinalEvaluation,
+            VerifierError::ChallengeMismatch { round, .. } => {
+                SumcheckError::ChallengeMismatch { round }
+            }
+            VerifierError::Transcript { round, .. } => SumcheckError::TranscriptError { round },
+            VerifierError::Hook { source, .. } => source,
+        }
+    }
+}
+
+impl<F: fmt::Debug, E: fmt::Debug> fmt::Display for VerifierError<F, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifierError::DegreeMismatch {
+                round,
+                expected,
+                got,
+            } => write!(
+                f,
+                "round {round}: degree mismatch: expected {expected} values, got {got}"
+            ),
+            VerifierError::FinalEvaluation { expected, actual } => write!(
+                f,
+                "final evaluation mismatch: expected {expected:?}, got {actual:?}"
+            ),
+            VerifierError::ChallengeMismatch {
+                round,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "round {round}: challenge mismatch: expected {expected:?}, got {actual:?}"
+            ),
+            VerifierError::Transcript { round, source } => {
+                write!(f, "round {round}: transcript error: {source:?}")
+            }
+            VerifierError::Hook { round, source } => {
+                write!(f, "round {round}: hook error: {source}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F, E> std::error::Error for VerifierError<F, E>
+where
+    F: fmt::Debug,
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            VerifierError::Transcript { source, .. } => Some(source),
+            VerifierError::Hook { source, .. } => Some(source),
+            _ => None,
         }
     }
 }
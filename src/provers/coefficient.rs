@@ -9,7 +9,7 @@
 use crate::field::SumcheckField;
 
 use crate::coefficient_sumcheck::RoundPolyEvaluator;
-use crate::sumcheck_prover::SumcheckProver;
+use crate::sumcheck_prover::{impl_table_len, SumcheckProver};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -22,6 +22,8 @@ pub struct CoefficientProver<'a, F: SumcheckField, E: RoundPolyEvaluator<F>> {
     n_tw: usize,
     n_pw: usize,
     deg: usize,
+    /// Rounds computed so far.
+    rounds_elapsed: usize,
 }
 
 impl<'a, F: SumcheckField, E: RoundPolyEvaluator<F>> CoefficientProver<'a, F, E> {
@@ -36,6 +38,7 @@ impl<'a, F: SumcheckField, E: RoundPolyEvaluator<F>> CoefficientProver<'a, F, E>
             n_tw,
             n_pw,
             deg,
+            rounds_elapsed: 0,
         }
     }
 
@@ -269,13 +272,23 @@ where
         self.deg
     }
 
+    fn round_degree(&self, round: usize) -> usize {
+        self.evaluator.round_degree(round)
+    }
+
+    impl_table_len!(|p| p
+        .pairwise
+        .first()
+        .map_or_else(|| p.tablewise.first().map_or(0, Vec::len), Vec::len));
+
     fn round(&mut self, challenge: Option<F>) -> Vec<F> {
         if let Some(c) = challenge {
             self.reduce(c);
         }
 
         let coeffs = self.evaluate_coefficients();
-        let d = self.deg;
+        let d = self.evaluator.round_degree(self.rounds_elapsed);
+        self.rounds_elapsed += 1;
 
         // EvalsInfty wire format:
         //   d == 0: [h(0)]
@@ -288,7 +301,7 @@ where
         evals.push(eval_poly_at(&coeffs, F::ZERO)); // h(0) = coeffs[0]
         evals.push(coeffs[d]); // h(∞) = leading coefficient
         for i in 2..d {
-            evals.push(eval_poly_at(&coeffs, F::from_u64(i as u64))); // h(i)
+            evals.push(eval_poly_at(&coeffs, F::eval_node(i))); // h(i)
         }
         evals
     }
@@ -412,4 +425,64 @@ mod tests {
             assert_eq!(ml_rp[0], cp_rp[0], "round {i}: h(0) mismatch");
         }
     }
+
+    /// `f(x)·g(x_0)`: degree 2 in the first variable, 1 in the rest.
+    struct FirstVarProduct;
+    impl RoundPolyEvaluator<F64> for FirstVarProduct {
+        fn degree(&self) -> usize {
+            2
+        }
+        fn round_degree(&self, round: usize) -> usize {
+            if round == 0 {
+                2
+            } else {
+                1
+            }
+        }
+        fn accumulate_pair(&self, coeffs: &mut [F64], _tw: &[(&[F64], &[F64])], pw: &[(F64, F64)]) {
+            let ((f0, f1), (g0, g1)) = (pw[0], pw[1]);
+            coeffs[0] += f0 * g0;
+            coeffs[1] += f0 * (g1 - g0) + (f1 - f0) * g0;
+            coeffs[2] += (f1 - f0) * (g1 - g0);
+        }
+        fn parallelize(&self) -> bool {
+            false
+        }
+    }
+
+    /// Per-round degrees from the evaluator reach the wire and verify
+    /// under `sumcheck_verify_with_degrees`.
+    #[test]
+    fn msb_round_degree_schedule() {
+        use crate::tests::ReplayTranscript;
+        use crate::verifier::sumcheck_verify_with_degrees;
+
+        let mut rng = StdRng::seed_from_u64(0x320);
+        let v = 4;
+        let n = 1 << v;
+        let f: Vec<F64> = (0..n).map(|_| F64::rand(&mut rng)).collect();
+        let (g_lo, g_hi) = (F64::rand(&mut rng), F64::rand(&mut rng));
+        let g: Vec<F64> = (0..n)
+            .map(|i| if i < n / 2 { g_lo } else { g_hi })
+            .collect();
+        let claim: F64 = f.iter().zip(&g).map(|(&a, &b)| a * b).sum();
+
+        let evaluator = FirstVarProduct;
+        let mut prover = CoefficientProver::new(&evaluator, vec![], vec![f, g]);
+        assert_eq!(prover.round_degree(0), 2);
+        assert_eq!(prover.round_degree(1), 1);
+        assert_eq!(prover.table_len(), Some(n));
+        let mut t = ReplayTranscript::new(0x321);
+        let proof = sumcheck(&mut prover, v, &mut t, |_, _| {});
+        let lens: Vec<usize> = proof.round_polys.iter().map(Vec::len).collect();
+        assert_eq!(lens, [2, 1, 1, 1]);
+        assert_eq!(prover.table_len(), Some(1));
+
+        let mut vt = t.replay();
+        let degrees = |j: usize| evaluator.round_degree(j);
+        let result =
+            sumcheck_verify_with_degrees(claim, &degrees, v, &mut vt, |_, _| Ok(())).unwrap();
+        assert_eq!(result.challenges, proof.challenges);
+        assert_eq!(result.final_claim, proof.final_value);
+    }
 }
@@ -0,0 +1,349 @@
+//! Cross-field provers: base-field witness, extension-field challenges.
+//!
+//! Witnesses often live in a small base field `BF` (e.g. Goldilocks) while
+//! soundness requires challenges from an extension `EF` (e.g. Goldilocks³).
+//! Lifting the witness to `EF` up front costs `[EF : BF]`× memory and
+//! multiplies round-0 work by the extension multiplication cost.
+//!
+//! [`CrossFieldProver`] avoids both: round 0 is computed over the `BF`
+//! witness, and the first challenge `r_1 ∈ EF` is applied by a lifting fold
+//! that writes a half-size `EF` table. From round 1 on, the wrapped prover is
+//! an ordinary `EF` prover. The runner and verifier only ever see `EF`.
+//!
+//! ```text
+//! round 0:  g_1 over BF tables           (lifted to EF on the wire)
+//! fold r_1: t'[k] = t[k] + r_1·(t[k + L/2] − t[k])   BF → EF, L/2 entries
+//! round 1+: the EF prover
+//! ```
+//!
+//! Each prover shape provides a `cross_field` constructor:
+//!
+//! ```ignore
+//! let mut prover = MultilinearProver::<F64Ext3>::cross_field(base_evals);
+//! let proof = sumcheck(&mut prover, num_vars, &mut transcript, noop_hook);
+//! ```
+
+use crate::field::{ExtensionOf, SumcheckField};
+use crate::sumcheck_prover::SumcheckProver;
+
+use alloc::vec::Vec;
+
+/// A base-field prover state that lifts into an `EF` prover on its first
+/// challenge.
+///
+/// Implemented for the `BF` instances of the canonical provers (and for
+/// [`EqFactoredBaseProver`](crate::provers::eq_factored::EqFactoredBaseProver),
+/// whose eq point is already in `EF`).
+pub trait BaseFieldProver<EF: SumcheckField> {
+    /// The `EF` prover that takes over after the first fold.
+    type Lifted: SumcheckProver<EF>;
+
+    /// Degree of the round polynomials (same as the lifted prover's).
+    fn degree(&self) -> usize;
+
+    /// Round 0 computed over the base-field witness, lifted to `EF`.
+    fn first_round(&mut self) -> Vec<EF>;
+
+    /// Fold the first variable with `challenge` and lift to `EF`.
+    fn lift_fold(self, challenge: EF) -> Self::Lifted;
+
+    /// Entries in the base-field table (see [`SumcheckProver::table_len`]).
+    fn table_len(&self) -> Option<usize> {
+        None
+    }
+
+    /// The claimed value when no rounds are run (0-variate witness).
+    fn final_value(&self) -> EF;
+}
+
+enum State<B, L> {
+    Base(B),
+    Lifted(L),
+    /// Transient state while `Base` is consumed by `lift_fold`.
+    Lifting,
+}
+
+/// [`SumcheckProver`] over `EF` wrapping a base-field prover `B`.
+///
+/// See [module docs](self) for details.
+pub struct CrossFieldProver<EF: SumcheckField, B: BaseFieldProver<EF>> {
+    state: State<B, B::Lifted>,
+}
+
+impl<EF: SumcheckField, B: BaseFieldProver<EF>> CrossFieldProver<EF, B> {
+    /// Wrap a base-field prover.
+    pub fn new(base: B) -> Self {
+        Self {
+            state: State::Base(base),
+        }
+    }
+
+    /// The `EF` prover, once the first challenge has been applied.
+    ///
+    /// Use it for post-sumcheck inspection, e.g.
+    /// [`final_factors`](crate::provers::eq_factored::EqFactoredProver::final_factors).
+    pub fn lifted(&self) -> Option<&B::Lifted> {
+        match &self.state {
+            State::Lifted(lifted) => Some(lifted),
+            _ => None,
+        }
+    }
+
+    fn lift(&mut self, challenge: EF) -> &mut B::Lifted {
+        if let State::Base(base) = core::mem::replace(&mut self.state, State::Lifting) {
+            self.state = State::Lifted(base.lift_fold(challenge));
+        }
+        match &mut self.state {
+            State::Lifted(lifted) => lifted,
+            _ => unreachable!("cross-field prover was not lifted"),
+        }
+    }
+}
+
+impl<EF: SumcheckField, B: BaseFieldProver<EF>> SumcheckProver<EF> for CrossFieldProver<EF, B> {
+    fn degree(&self) -> usize {
+        match &self.state {
+            State::Base(base) => base.degree(),
+            State::Lifted(lifted) => lifted.degree(),
+            State::Lifting => unreachable!("cross-field prover was not lifted"),
+        }
+    }
+
+    fn round(&mut self, challenge: Option<EF>) -> Vec<EF> {
+        match (&mut self.state, challenge) {
+            (State::Base(base), None) => base.first_round(),
+            // The first challenge is consumed by the lifting fold; the
+            // lifted prover then computes its first round unfolded.
+            (State::Base(_), Some(r)) => self.lift(r).round(None),
+            (State::Lifted(lifted), challenge) => lifted.round(challenge),
+            (State::Lifting, _) => unreachable!("cross-field prover was not lifted"),
+        }
+    }
+
+    fn table_len(&self) -> Option<usize> {
+        match &self.state {
+            State::Base(base) => base.table_len(),
+            State::Lifted(lifted) => lifted.table_len(),
+            State::Lifting => unreachable!("cross-field prover was not lifted"),
+        }
+    }
+
+    fn finalize(&mut self, last_challenge: EF) {
+        match &mut self.state {
+            State::Base(_) => {
+                self.lift(last_challenge);
+            }
+            State::Lifted(lifted) => lifted.finalize(last_challenge),
+            State::Lifting => unreachable!("cross-field prover was not lifted"),
+        }
+    }
+
+    fn final_value(&self) -> EF {
+        match &self.state {
+            State::Base(base) => base.final_value(),
+            State::Lifted(lifted) => lifted.final_value(),
+            State::Lifting => unreachable!("cross-field prover was not lifted"),
+        }
+    }
+}
+
+/// Half-split (MSB) fold of a base-field table into `EF`:
+/// `out[k] = v[k] + challenge · (v[k + L/2] − v[k])`, with the difference
+/// taken in `BF`.
+///
+/// Matches the implicit zero padding of the in-place folds: for non-pow2
+/// `L` the tail collapses to `v[k] · (1 − challenge)`.
+pub fn lift_fold<BF: SumcheckField, EF: ExtensionOf<BF>>(values: &[BF], challenge: EF) -> Vec<EF> {
+    if values.len() <= 1 {
+        return values.iter().map(|&v| EF::from_base(v)).collect();
+    }
+    let half = values.len().next_power_of_two() >> 1;
+    let (low, high) = values.split_at(half);
+    let mut out = Vec::with_capacity(half);
+    out.extend(
+        low.iter()
+            .zip(high)
+            .map(|(&l, &h)| EF::from_base(l) + challenge * EF::from_base(h - l)),
+    );
+    let one_minus = EF::ONE - challenge;
+    out.extend(
+        low[high.len()..]
+            .iter()
+            .map(|&l| EF::from_base(l) * one_minus),
+    );
+    out
+}
+
+#[cfg(all(test, feature = "arkworks"))]
+mod tests {
+    use super::*;
+    use crate::provers::eq_factored::EqFactoredProver;
+    use crate::provers::gkr::GkrProver;
+    use crate::provers::inner_product::InnerProductProver;
+    use crate::provers::multilinear::MultilinearProver;
+    use crate::runner::sumcheck;
+    use crate::tests::{F64Ext3, ReplayTranscript, F64};
+    use crate::verifier::sumcheck_verify;
+    use ark_ff::{Field, UniformRand};
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    fn base_vec(n: usize, rng: &mut StdRng) -> Vec<F64> {
+        (0..n).map(|_| F64::rand(rng)).collect()
+    }
+
+    fn lift(v: &[F64]) -> Vec<F64Ext3> {
+        v.iter()
+            .map(|&x| F64Ext3::from_base_prime_field(x))
+            .collect()
+    }
+
+    /// A cross-field prover produces the same transcript as the `EF`
+    /// prover on the lifted witness, and verifies.
+    fn assert_matches_lifted(
+        cross: &mut impl SumcheckProver<F64Ext3>,
+        mut lifted: impl SumcheckProver<F64Ext3>,
+        claim: F64Ext3,
+        num_rounds: usize,
+    ) {
+        assert_eq!(cross.table_len(), lifted.table_len());
+        let mut t = ReplayTranscript::new(0x900);
+        let proof = sumcheck(cross, num_rounds, &mut t, |_, _| {});
+        let mut t_ref = ReplayTranscript::new(0x900);
+        let expected = sumcheck(&mut lifted, num_rounds, &mut t_ref, |_, _| {});
+        assert_eq!(proof.round_polys, expected.round_polys);
+        assert_eq!(proof.final_value, expected.final_value);
+        assert_eq!(cross.table_len(), lifted.table_len());
+
+        let degree = cross.degree();
+        let mut vt = t.replay();
+        let result = sumcheck_verify(claim, degree, num_rounds, &mut vt, |_, _| Ok(())).unwrap();
+        assert_eq!(result.final_claim, proof.final_value);
+    }
+
+    #[test]
+    fn lift_fold_matches_ef_fold() {
+        let mut rng = StdRng::seed_from_u64(0x901);
+        for n in [1, 2, 5, 8, 13] {
+            let v = base_vec(n, &mut rng);
+            let r = F64Ext3::rand(&mut rng);
+            let mut expected = lift(&v);
+            crate::inner_product_sumcheck::fold(&mut expected, r);
+            assert_eq!(lift_fold(&v, r), expected, "n={n}");
+        }
+    }
+
+    /// Extension fields embed into themselves, so `EF` witnesses go
+    /// through the same path as base-field ones.
+    #[test]
+    fn from_base_embeds_prime_field_and_self() {
+        let mut rng = StdRng::seed_from_u64(0x906);
+        let x = F64::rand(&mut rng);
+        assert_eq!(
+            <F64Ext3 as ExtensionOf<F64>>::from_base(x),
+            F64Ext3::from_base_prime_field(x)
+        );
+        let y = F64Ext3::rand(&mut rng);
+        assert_eq!(<F64Ext3 as ExtensionOf<F64Ext3>>::from_base(y), y);
+        let v: Vec<F64Ext3> = (0..8).map(|_| F64Ext3::rand(&mut rng)).collect();
+        let r = F64Ext3::rand(&mut rng);
+        let mut expected = v.clone();
+        crate::inner_product_sumcheck::fold(&mut expected, r);
+        assert_eq!(lift_fold(&v, r), expected);
+    }
+
+    /// Intermediate tower levels embed as ring homomorphisms.
+    #[test]
+    fn from_base_embeds_tower_subfield() {
+        use crate::tests::{F64Fp2, F64Fp4};
+        let mut rng = StdRng::seed_from_u64(0x907);
+        let embed = <F64Fp4 as ExtensionOf<F64Fp2>>::from_base;
+        for _ in 0..8 {
+            let (a, b) = (F64Fp2::rand(&mut rng), F64Fp2::rand(&mut rng));
+            assert_eq!(embed(a) * embed(b), embed(a * b));
+            assert_eq!(embed(a) + embed(b), embed(a + b));
+        }
+        let v: Vec<F64Fp2> = (0..8).map(|_| F64Fp2::rand(&mut rng)).collect();
+        let r = F64Fp4::rand(&mut rng);
+        let mut expected: Vec<F64Fp4> = v.iter().map(|&x| embed(x)).collect();
+        crate::inner_product_sumcheck::fold(&mut expected, r);
+        assert_eq!(lift_fold(&v, r), expected);
+    }
+
+    #[test]
+    fn multilinear_cross_field() {
+        let mut rng = StdRng::seed_from_u64(0x902);
+        for v in 0..=5 {
+            let evals = base_vec(1 << v, &mut rng);
+            let claim = F64Ext3::from_base_prime_field(evals.iter().sum());
+            assert_matches_lifted(
+                &mut MultilinearProver::<F64Ext3>::cross_field(evals.clone()),
+                MultilinearProver::new(lift(&evals)),
+                claim,
+                v,
+            );
+        }
+    }
+
+    #[test]
+    fn inner_product_cross_field() {
+        let mut rng = StdRng::seed_from_u64(0x903);
+        for v in 0..=5 {
+            let a = base_vec(1 << v, &mut rng);
+            let b = base_vec(1 << v, &mut rng);
+            let claim: F64 = a.iter().zip(&b).map(|(&x, &y)| x * y).sum();
+            assert_matches_lifted(
+                &mut InnerProductProver::<F64Ext3>::cross_field(a.clone(), b.clone()),
+                InnerProductProver::new(lift(&a), lift(&b)),
+                F64Ext3::from_base_prime_field(claim),
+                v,
+            );
+        }
+    }
+
+    #[test]
+    fn eq_factored_cross_field() {
+        let mut rng = StdRng::seed_from_u64(0x904);
+        for v in 0..=6 {
+            let w: Vec<F64Ext3> = (0..v).map(|_| F64Ext3::rand(&mut rng)).collect();
+            let p = base_vec(1 << v, &mut rng);
+            let claim: F64Ext3 = crate::provers::eq_factored::build_eq_table(&w)
+                .iter()
+                .zip(lift(&p))
+                .map(|(&e, x)| e * x)
+                .sum();
+            assert_matches_lifted(
+                &mut EqFactoredProver::cross_field(w.clone(), p.clone()),
+                EqFactoredProver::new(w, lift(&p)),
+                claim,
+                v,
+            );
+        }
+    }
+
+    #[test]
+    fn gkr_cross_field() {
+        let mut rng = StdRng::seed_from_u64(0x905);
+        for k in 1..=3 {
+            let n = 1 << k;
+            let add = base_vec(n * n, &mut rng);
+            let mult = base_vec(n * n, &mut rng);
+            let w = base_vec(n, &mut rng);
+            let mut claim = F64::from(0u64);
+            for b in 0..n {
+                for c in 0..n {
+                    claim += add[b * n + c] * (w[b] + w[c]) + mult[b * n + c] * (w[b] * w[c]);
+                }
+            }
+            let mut cross = GkrProver::<F64Ext3>::cross_field(add.clone(), mult.clone(), w.clone());
+            assert_matches_lifted(
+                &mut cross,
+                GkrProver::new(lift(&add), lift(&mult), lift(&w)),
+                F64Ext3::from_base_prime_field(claim),
+                2 * k,
+            );
+            let (w_b, w_c) = cross.lifted().unwrap().claimed_w_values();
+            assert_ne!(w_b, F64Ext3::from(0u64));
+            assert_ne!(w_c, F64Ext3::from(0u64));
+        }
+    }
+}
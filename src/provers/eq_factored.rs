@@ -39,9 +39,11 @@
 //! EvalsInfty for degree 2: `[q(0), q(∞)]`. The verifier derives
 //! `q(1) = claim - q(0)` and reconstructs the round polynomial.
 
-use crate::field::SumcheckField;
+use crate::field::{ExtensionOf, SumcheckField};
 use crate::inner_product_sumcheck as ip;
-use crate::sumcheck_prover::SumcheckProver;
+use crate::polynomial::SkipDomain;
+use crate::provers::cross_field::{lift_fold, BaseFieldProver, CrossFieldProver};
+use crate::sumcheck_prover::{impl_table_len, SumcheckProver};
 
 use alloc::{vec, vec::Vec};
 
@@ -80,6 +82,12 @@ pub struct EqFactoredProver<F: SumcheckField> {
     v: usize,
     /// Number of completed [`round`](SumcheckProver::round) calls.
     rounds_elapsed: usize,
+    /// Pending univariate-skip round: the domain and `eq(w_head, ·)` over
+    /// the skipped variables. Cleared once its challenge is applied.
+    skip: Option<(SkipDomain<F>, Vec<F>)>,
+    /// `ê(r_skip) = Σ_i L_i(r_skip)·eq(w_head, i)` after a univariate skip,
+    /// `1` otherwise. Scales every later round polynomial.
+    eq_scale: F,
 }
 
 impl<F: SumcheckField> EqFactoredProver<F> {
@@ -97,31 +105,112 @@ impl<F: SumcheckField> EqFactoredProver<F> {
             v,
             n
         );
+        let mut prover = Self::eq_only(&w);
+        prover.p = p_evals;
+        prover.p.resize(n, F::ZERO);
+        prover
+    }
+
+    /// The split eq half-tables for `w`, with an empty `p` for the caller
+    /// to fill in.
+    fn eq_only(w: &[F]) -> Self {
+        let v = w.len();
         let v_l = v / 2;
         let (w_l, w_r) = w.split_at(v_l);
-        let eq_l = build_eq_table(w_l);
-        let eq_r = build_eq_table(w_r);
-        let mut p = p_evals;
-        p.resize(n, F::ZERO);
         Self {
-            p,
-            eq_l,
-            eq_r,
+            p: Vec::new(),
+            eq_l: build_eq_table(w_l),
+            eq_r: build_eq_table(w_r),
             v_l,
             v,
             rounds_elapsed: 0,
+            skip: None,
+            eq_scale: F::ONE,
         }
     }
 
+    /// Cross-field prover: `p` in `BF`, eq point and challenges in `F`.
+    ///
+    /// Round 0 contracts the `BF` table of `p` against the `F` eq
+    /// half-tables; the first challenge lifts the half-folded `p` to `F`.
+    pub fn cross_field<BF: SumcheckField>(
+        w: Vec<F>,
+        p_evals: Vec<BF>,
+    ) -> CrossFieldProver<F, EqFactoredBaseProver<BF, F>>
+    where
+        F: ExtensionOf<BF>,
+    {
+        CrossFieldProver::new(EqFactoredBaseProver::new(w, p_evals))
+    }
+
+    /// Prover whose first round is a univariate skip over `domain`.
+    ///
+    /// The first `k = domain.num_vars()` variables are replaced by one
+    /// round over the domain. That round sends the coefficients
+    /// `[c_1, ..., c_D]` of `h(Y) = ê(Y) · Σ_{x'} eq(w_tail, x') · p̂(Y, x')`,
+    /// `D = 2·(2^k − 1)`, where `ê` interpolates `eq(w_head, ·)` over the
+    /// domain. The remaining `v − k` rounds are standard. Run `v − k + 1`
+    /// rounds in total; afterwards
+    /// [`final_factors`](Self::final_factors) returns `p̂(r)` and `ê(r_skip)·eq(w_tail, r_tail)`.
+    pub fn with_univariate_skip(w: Vec<F>, p_evals: Vec<F>, domain: SkipDomain<F>) -> Self {
+        let k = domain.num_vars();
+        assert!(k <= w.len(), "cannot skip more variables than w has");
+        let (w_head, w_tail) = w.split_at(k);
+        let mut prover = Self::eq_only(w_tail);
+        let mut p = p_evals;
+        assert!(
+            p.len() <= 1 << w.len(),
+            "p_evals length {} exceeds 2^{}",
+            p.len(),
+            w.len()
+        );
+        p.resize(1 << w.len(), F::ZERO);
+        prover.p = p;
+        prover.skip = Some((domain, build_eq_table(w_head)));
+        prover
+    }
+
     /// After full sumcheck: `(p(r), eq(w, r))`.
     pub fn final_factors(&self) -> (F, F) {
         if self.p.len() == 1 {
-            (self.p[0], self.eq_l[0] * self.eq_r[0])
+            (self.p[0], self.eq_scale * self.eq_l[0] * self.eq_r[0])
         } else {
             (F::ZERO, F::ZERO)
         }
     }
 
+    /// Univariate-skip round: `[c_1, ..., c_D]` of `h(Y) = ê(Y)·P̂(Y)` with
+    /// `P(i) = Σ_{x'} eq(w_tail, x')·p(i, x')` contracted through the
+    /// split-value half-tables.
+    fn round_poly_skip(&self, domain: &SkipDomain<F>, eq_head: &[F]) -> Vec<F> {
+        let n = domain.size();
+        let m = self.p.len() / n;
+        let m_r = self.eq_r.len();
+        let columns: Vec<F> = self
+            .p
+            .chunks_exact(m)
+            .map(|col| {
+                col.chunks_exact(m_r)
+                    .zip(&self.eq_l)
+                    .map(|(block, &el)| {
+                        let inner: F = block.iter().zip(&self.eq_r).map(|(&v, &er)| v * er).sum();
+                        el * inner
+                    })
+                    .sum()
+            })
+            .collect();
+        domain.product_coeffs(eq_head, &columns)[1..].to_vec()
+    }
+
+    /// Fold the skipped variables into the skip challenge `r`.
+    fn apply_skip(&mut self, r: F) {
+        if let Some((domain, eq_head)) = self.skip.take() {
+            let basis = domain.lagrange_at(r);
+            self.p = domain.fold_with_basis(&self.p, &basis);
+            self.eq_scale = basis.iter().zip(&eq_head).map(|(&l, &e)| l * e).sum();
+        }
+    }
+
     /// Round polynomial during the left-half phase (`rounds_elapsed < v_L`).
     ///
     /// Let `j = rounds_elapsed` and split the remaining variables as
@@ -137,37 +226,7 @@ impl<F: SumcheckField> EqFactoredProver<F> {
     /// halves of `p`. The kernel contracts the `b` dimension against
     /// `eq_R` once per `a`, then accumulates the outer sum.
     fn round_poly_left(&self) -> Vec<F> {
-        let eq_l_half = self.eq_l.len() >> 1;
-        let (eq_l_lo, eq_l_hi) = self.eq_l.split_at(eq_l_half);
-
-        let p_half = self.p.len() >> 1;
-        let (p_lo, p_hi) = self.p.split_at(p_half);
-
-        let m = self.eq_r.len();
-        debug_assert_eq!(eq_l_half * m, p_half);
-
-        let mut q0 = F::ZERO;
-        let mut q_inf = F::ZERO;
-        for a in 0..eq_l_half {
-            let p_lo_slice = &p_lo[a * m..(a + 1) * m];
-            let p_hi_slice = &p_hi[a * m..(a + 1) * m];
-
-            let mut inner_0 = F::ZERO;
-            let mut inner_delta = F::ZERO;
-            for b in 0..m {
-                let er = self.eq_r[b];
-                let pl = p_lo_slice[b];
-                let ph = p_hi_slice[b];
-                inner_0 += er * pl;
-                inner_delta += er * (ph - pl);
-            }
-
-            let el_lo = eq_l_lo[a];
-            let el_hi = eq_l_hi[a];
-            q0 += el_lo * inner_0;
-            q_inf += (el_hi - el_lo) * inner_delta;
-        }
-
+        let (q0, q_inf) = split_round_left(&self.p, &self.eq_l, &self.eq_r);
         vec![q0, q_inf]
     }
 
@@ -176,37 +235,78 @@ impl<F: SumcheckField> EqFactoredProver<F> {
     /// the remaining work is a standard inner-product round polynomial on
     /// `eq_R` and `p`, scaled by that scalar.
     fn round_poly_right(&self) -> Vec<F> {
-        let n = self.p.len();
         debug_assert_eq!(self.eq_l.len(), 1);
-        debug_assert_eq!(self.eq_r.len(), n);
-
+        let (q0, q_inf) = split_round_right(&self.p, &self.eq_r);
         let scalar = self.eq_l[0];
-        if n <= 1 {
-            let v = if n == 1 {
-                scalar * self.eq_r[0] * self.p[0]
-            } else {
-                F::ZERO
-            };
-            return vec![v, F::ZERO];
-        }
+        vec![q0 * scalar, q_inf * scalar]
+    }
+}
 
-        let half = n >> 1;
-        let (eq_r_lo, eq_r_hi) = self.eq_r.split_at(half);
-        let (p_lo, p_hi) = self.p.split_at(half);
-
-        let mut q0 = F::ZERO;
-        let mut q_inf = F::ZERO;
-        for i in 0..half {
-            let el = eq_r_lo[i];
-            let eh = eq_r_hi[i];
-            let pl = p_lo[i];
-            let ph = p_hi[i];
-            q0 += el * pl;
-            q_inf += (eh - el) * (ph - pl);
+/// Left-phase kernel: `(q(0), q(∞))` of `Σ_{a,b} L(X, a)·R(b)·p(X, a, b)`
+/// for a weight `L ⊗ R` given by its two half-tables (MSB layout).
+///
+/// The `b` dimension is contracted against `R` once per `a`, then the outer
+/// sum over `a` is accumulated; the weight is never materialized.
+pub(crate) fn split_round_left<F: SumcheckField>(p: &[F], l: &[F], r: &[F]) -> (F, F) {
+    let l_half = l.len() >> 1;
+    let (l_lo, l_hi) = l.split_at(l_half);
+
+    let p_half = p.len() >> 1;
+    let (p_lo, p_hi) = p.split_at(p_half);
+
+    let m = r.len();
+    debug_assert_eq!(l_half * m, p_half);
+
+    let mut q0 = F::ZERO;
+    let mut q_inf = F::ZERO;
+    for a in 0..l_half {
+        let p_lo_slice = &p_lo[a * m..(a + 1) * m];
+        let p_hi_slice = &p_hi[a * m..(a + 1) * m];
+
+        let mut inner_0 = F::ZERO;
+        let mut inner_delta = F::ZERO;
+        for b in 0..m {
+            let er = r[b];
+            let pl = p_lo_slice[b];
+            let ph = p_hi_slice[b];
+            inner_0 += er * pl;
+            inner_delta += er * (ph - pl);
         }
 
-        vec![q0 * scalar, q_inf * scalar]
+        let el_lo = l_lo[a];
+        let el_hi = l_hi[a];
+        q0 += el_lo * inner_0;
+        q_inf += (el_hi - el_lo) * inner_delta;
     }
+
+    (q0, q_inf)
+}
+
+/// Right-phase kernel: `(q(0), q(∞))` of `Σ_b R(X, b)·p(X, b)` once the
+/// left half-table has folded to a scalar (which the caller applies).
+pub(crate) fn split_round_right<F: SumcheckField>(p: &[F], r: &[F]) -> (F, F) {
+    let n = p.len();
+    debug_assert_eq!(r.len(), n);
+    if n <= 1 {
+        let v = if n == 1 { r[0] * p[0] } else { F::ZERO };
+        return (v, F::ZERO);
+    }
+
+    let half = n >> 1;
+    let (r_lo, r_hi) = r.split_at(half);
+    let (p_lo, p_hi) = p.split_at(half);
+
+    let mut q0 = F::ZERO;
+    let mut q_inf = F::ZERO;
+    for i in 0..half {
+        let el = r_lo[i];
+        let eh = r_hi[i];
+        let pl = p_lo[i];
+        let ph = p_hi[i];
+        q0 += el * pl;
+        q_inf += (eh - el) * (ph - pl);
+    }
+    (q0, q_inf)
 }
 
 /// Build the multilinear-extension table of `eq(w, ·)` over `{0,1}^v` in
@@ -246,10 +346,24 @@ where
         2
     }
 
+    fn round_degree(&self, round: usize) -> usize {
+        match &self.skip {
+            Some((domain, _)) if round == 0 => domain.round_degree(2),
+            _ => 2,
+        }
+    }
+
     fn round(&mut self, challenge: Option<F>) -> Vec<F> {
-        // Apply the previous round's challenge to the correct half of eq
-        // (plus p, which folds every round).
-        if let Some(r) = challenge {
+        // Univariate skip: the first round covers the skipped variables;
+        // the second applies the skip challenge instead of a Boolean fold.
+        if let Some((domain, eq_head)) = &self.skip {
+            match challenge {
+                None => return self.round_poly_skip(domain, eq_head),
+                Some(r) => self.apply_skip(r),
+            }
+        } else if let Some(r) = challenge {
+            // Apply the previous round's challenge to the correct half of eq
+            // (plus p, which folds every round).
             let prev_var = self.rounds_elapsed - 1;
             ip::fold(&mut self.p, r);
             if prev_var < self.v_l {
@@ -260,16 +374,23 @@ where
         }
 
         let j = self.rounds_elapsed;
-        let round_poly = if j < self.v_l {
+        let mut round_poly = if j < self.v_l {
             self.round_poly_left()
         } else {
             self.round_poly_right()
         };
+        for v in &mut round_poly {
+            *v *= self.eq_scale;
+        }
         self.rounds_elapsed += 1;
         round_poly
     }
 
     fn finalize(&mut self, last_challenge: F) {
+        if self.skip.is_some() {
+            self.apply_skip(last_challenge);
+            return;
+        }
         let last_var = self.v - 1;
         ip::fold(&mut self.p, last_challenge);
         if last_var < self.v_l {
@@ -279,15 +400,117 @@ where
         }
     }
 
+    impl_table_len!(p);
+
     fn final_value(&self) -> F {
         if self.p.len() == 1 {
-            self.p[0] * self.eq_l[0] * self.eq_r[0]
+            self.p[0] * self.eq_scale * self.eq_l[0] * self.eq_r[0]
         } else {
             F::ZERO
         }
     }
 }
 
+/// Base-field state of [`EqFactoredProver::cross_field`]: `p` over `BF`,
+/// the eq point over `EF`.
+///
+/// Round 0 takes the `p` differences in `BF` and multiplies them into the
+/// `EF` eq half-tables; `p` is never stored in `EF` at full length.
+pub struct EqFactoredBaseProver<BF: SumcheckField, EF: SumcheckField> {
+    /// `p` evaluations (MSB layout), padded to `2^v`.
+    p: Vec<BF>,
+    /// Eq half-tables; its own `p` is filled in by the lifting fold.
+    eq: EqFactoredProver<EF>,
+}
+
+impl<BF: SumcheckField, EF: ExtensionOf<BF>> EqFactoredBaseProver<BF, EF> {
+    /// Construct the base-field state for `∑_x eq(w, x) · p(x)`.
+    ///
+    /// `p_evals.len()` must be `≤ 2^{w.len()}`; shorter inputs are
+    /// zero-padded to `2^{w.len()}`.
+    pub fn new(w: Vec<EF>, p_evals: Vec<BF>) -> Self {
+        let n = 1usize << w.len();
+        assert!(
+            p_evals.len() <= n,
+            "p_evals length {} exceeds 2^{} = {}",
+            p_evals.len(),
+            w.len(),
+            n
+        );
+        let mut p = p_evals;
+        p.resize(n, BF::ZERO);
+        Self {
+            p,
+            eq: EqFactoredProver::eq_only(&w),
+        }
+    }
+}
+
+impl<BF: SumcheckField, EF: ExtensionOf<BF>> BaseFieldProver<EF> for EqFactoredBaseProver<BF, EF> {
+    type Lifted = EqFactoredProver<EF>;
+
+    fn degree(&self) -> usize {
+        2
+    }
+
+    /// Same kernels as [`EqFactoredProver`]'s first round, with
+    /// `p_hi − p_lo` taken in `BF`.
+    fn first_round(&mut self) -> Vec<EF> {
+        let eq = &self.eq;
+        if self.p.len() <= 1 {
+            return vec![EF::from_base(self.p[0]), EF::ZERO];
+        }
+        let (p_lo, p_hi) = self.p.split_at(self.p.len() >> 1);
+        let (eq_lo, eq_hi, m) = if eq.v_l > 0 {
+            let (lo, hi) = eq.eq_l.split_at(eq.eq_l.len() >> 1);
+            (lo, hi, eq.eq_r.len())
+        } else {
+            // v = 1: the only variable is in the right half.
+            let (lo, hi) = eq.eq_r.split_at(1);
+            (lo, hi, 1)
+        };
+
+        let mut q0 = EF::ZERO;
+        let mut q_inf = EF::ZERO;
+        for a in 0..eq_lo.len() {
+            let mut inner_0 = EF::ZERO;
+            let mut inner_delta = EF::ZERO;
+            for b in 0..m {
+                let er = if eq.v_l > 0 { eq.eq_r[b] } else { EF::ONE };
+                let pl = p_lo[a * m + b];
+                let ph = p_hi[a * m + b];
+                inner_0 += er * EF::from_base(pl);
+                inner_delta += er * EF::from_base(ph - pl);
+            }
+            q0 += eq_lo[a] * inner_0;
+            q_inf += (eq_hi[a] - eq_lo[a]) * inner_delta;
+        }
+        vec![q0, q_inf]
+    }
+
+    fn lift_fold(self, challenge: EF) -> EqFactoredProver<EF> {
+        let mut prover = self.eq;
+        prover.p = lift_fold(&self.p, challenge);
+        if prover.v_l > 0 {
+            ip::fold(&mut prover.eq_l, challenge);
+        } else {
+            ip::fold(&mut prover.eq_r, challenge);
+        }
+        prover.rounds_elapsed = 1;
+        prover
+    }
+
+    impl_table_len!(p);
+
+    fn final_value(&self) -> EF {
+        if self.p.len() == 1 {
+            EF::from_base(self.p[0])
+        } else {
+            EF::ZERO
+        }
+    }
+}
+
 #[cfg(all(test, feature = "arkworks"))]
 mod tests {
     use super::*;
@@ -444,4 +667,66 @@ mod tests {
             assert_eq!(eq_wr, expected_eq_wr, "v={v}: eq(w,r) mismatch");
         }
     }
+
+    /// Univariate skip: verifies under `sumcheck_verify_with_skip` and the
+    /// final value factors as `p̂(r) · ê(r_skip) · eq(w_tail, r_tail)`.
+    #[test]
+    fn univariate_skip_roundtrip() {
+        use crate::hypercube::eq_poly_non_binary;
+        use crate::polynomial::multilinear::mle_eval;
+        use crate::polynomial::SkipDomain;
+        use crate::tests::ReplayTranscript;
+        use crate::verifier::sumcheck_verify_with_skip;
+
+        let mut rng = StdRng::seed_from_u64(0x820);
+        for v in 1..=7 {
+            for k in 1..=v.min(3) {
+                let w: Vec<F64> = (0..v).map(|_| F64::rand(&mut rng)).collect();
+                let p: Vec<F64> = (0..1 << v).map(|_| F64::rand(&mut rng)).collect();
+                let claim: F64 = build_eq_table(&w)
+                    .iter()
+                    .zip(&p)
+                    .map(|(&e, &x)| e * x)
+                    .sum();
+                let domain = SkipDomain::<F64>::from_two_adic(k);
+                let num_rounds = v - k + 1;
+
+                let mut prover =
+                    EqFactoredProver::with_univariate_skip(w.clone(), p.clone(), domain.clone());
+                assert_eq!(prover.round_degree(0), domain.round_degree(2));
+                let mut t = ReplayTranscript::new(0x821);
+                let proof = sumcheck(&mut prover, num_rounds, &mut t, |_, _| {});
+                assert_eq!(proof.round_polys[0].len(), domain.round_degree(2));
+
+                let mut vt = t.replay();
+                let result = sumcheck_verify_with_skip(
+                    claim,
+                    &domain,
+                    domain.round_degree(2),
+                    2,
+                    num_rounds,
+                    &mut vt,
+                    |_, _| Ok(()),
+                )
+                .unwrap_or_else(|e| panic!("v={v}, k={k}: {e}"));
+                assert_eq!(result.final_claim, proof.final_value, "v={v}, k={k}");
+
+                let r_skip = proof.challenges[0];
+                let r_tail = &proof.challenges[1..];
+                let e_skip: F64 = domain
+                    .lagrange_at(r_skip)
+                    .iter()
+                    .zip(build_eq_table(&w[..k]))
+                    .map(|(&l, e)| l * e)
+                    .sum();
+                let p_r = mle_eval(&domain.fold(&p, r_skip), r_tail);
+                let expected = p_r * e_skip * eq_poly_non_binary(&w[k..], r_tail);
+                assert_eq!(proof.final_value, expected, "v={v}, k={k}");
+                assert_eq!(
+                    prover.final_factors(),
+                    (p_r, e_skip * eq_poly_non_binary(&w[k..], r_tail))
+                );
+            }
+        }
+    }
 }
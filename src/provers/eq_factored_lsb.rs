@@ -0,0 +1,261 @@
+//! LSB (pair-split) eq-factored sumcheck prover: `g(x) = eq(w, x) · p(x)`, degree 2.
+//!
+//! Folds the *least-significant* variable each round: pairs `(p[2k], p[2k+1])`.
+//! Round `j` binds index bit `j`, which is paired with `w[j]`:
+//!
+//! ```text
+//! eq(w, x) = Π_j eq(w[j], bit_j(x))
+//! ```
+//!
+//! so after `v` rounds with challenges `r`, the final value is
+//! `eq(w, r) · p(r)` with `r` in round order.
+//!
+//! # Space: Split-Value Optimization, right half first
+//!
+//! As in [`EqFactoredProver`](super::eq_factored::EqFactoredProver), `eq` is
+//! stored as two half-tables. The low-order bits (the right half of the
+//! index, `v_lo = ⌊v/2⌋` bits) are bound first, folding `eq_lo`; then the
+//! high-order bits fold `eq_hi`. After the last round
+//! `eq(w, r) = eq_lo[0] · eq_hi[0]`.
+//!
+//! Use this prover for sequential (LSB) traces. For in-memory or
+//! random-access workloads, prefer
+//! [`EqFactoredProver`](super::eq_factored::EqFactoredProver) (MSB layout).
+
+use crate::field::SumcheckField;
+use crate::provers::eq_factored::build_eq_table;
+use crate::provers::inner_product_lsb::fold_lsb;
+use crate::sumcheck_prover::{impl_table_len, SumcheckProver};
+
+use alloc::{vec, vec::Vec};
+
+/// LSB eq-factored sumcheck prover for `∑_x eq(w, x) · p(x)` (degree 2,
+/// pair-split layout).
+///
+/// ```ignore
+/// let mut prover = EqFactoredProverLSB::new(w, p_evals);
+/// let proof = sumcheck(&mut prover, num_vars, &mut transcript, noop_hook);
+/// let (p_r, eq_wr) = prover.final_factors();
+/// ```
+pub struct EqFactoredProverLSB<F: SumcheckField> {
+    /// `p` evaluations (LSB layout), padded to `2^v` on construction.
+    /// Folded in every round.
+    p: Vec<F>,
+    /// `eq(w[..v_lo], ·)` over the low-order bits (LSB layout). Folded
+    /// during the first `v_lo` rounds.
+    eq_lo: Vec<F>,
+    /// `eq(w[v_lo..], ·)` over the high-order bits (LSB layout). Folded
+    /// once `eq_lo` is a scalar.
+    eq_hi: Vec<F>,
+    /// Number of low-order variables `v_lo = ⌊v/2⌋`.
+    v_lo: usize,
+    /// Total number of variables.
+    v: usize,
+    /// Number of completed [`round`](SumcheckProver::round) calls.
+    rounds_elapsed: usize,
+}
+
+/// `eq(w, ·)` over `{0,1}^{w.len()}` in LSB layout: `w[j]` pairs with bit `j`.
+fn build_eq_table_lsb<F: SumcheckField>(w: &[F]) -> Vec<F> {
+    let reversed: Vec<F> = w.iter().rev().copied().collect();
+    build_eq_table(&reversed)
+}
+
+impl<F: SumcheckField> EqFactoredProverLSB<F> {
+    /// Construct a prover for `∑_x eq(w, x) · p(x)`.
+    ///
+    /// `p_evals.len()` must be `≤ 2^{w.len()}`; shorter inputs are
+    /// zero-padded to `2^{w.len()}`.
+    pub fn new(w: Vec<F>, p_evals: Vec<F>) -> Self {
+        let v = w.len();
+        let n = 1usize << v;
+        assert!(
+            p_evals.len() <= n,
+            "p_evals length {} exceeds 2^{} = {}",
+            p_evals.len(),
+            v,
+            n
+        );
+        let v_lo = v / 2;
+        let (w_lo, w_hi) = w.split_at(v_lo);
+        let mut p = p_evals;
+        p.resize(n, F::ZERO);
+        Self {
+            p,
+            eq_lo: build_eq_table_lsb(w_lo),
+            eq_hi: build_eq_table_lsb(w_hi),
+            v_lo,
+            v,
+            rounds_elapsed: 0,
+        }
+    }
+
+    /// After full sumcheck: `(p(r), eq(w, r))`.
+    pub fn final_factors(&self) -> (F, F) {
+        if self.p.len() == 1 {
+            (self.p[0], self.eq_lo[0] * self.eq_hi[0])
+        } else {
+            (F::ZERO, F::ZERO)
+        }
+    }
+
+    /// Round polynomial while binding low-order bits
+    /// (`rounds_elapsed < v_lo`).
+    ///
+    /// With `p` viewed as `2^{|hi|}` contiguous blocks of `|eq_lo|` entries,
+    ///
+    /// ```text
+    /// q(X) = Σ_hi eq_hi(hi) · Σ_a eq_lo(X, a) · p(X, a, hi),
+    /// ```
+    ///
+    /// so each block is contracted against the `eq_lo` pairs, then scaled by
+    /// its `eq_hi` weight.
+    fn round_poly_lo(&self) -> Vec<F> {
+        let m = self.eq_lo.len();
+        debug_assert_eq!(m * self.eq_hi.len(), self.p.len());
+
+        let mut q0 = F::ZERO;
+        let mut q_inf = F::ZERO;
+        for (block, &eh) in self.p.chunks_exact(m).zip(&self.eq_hi) {
+            let mut inner_0 = F::ZERO;
+            let mut inner_inf = F::ZERO;
+            for (p_pair, e_pair) in block.chunks_exact(2).zip(self.eq_lo.chunks_exact(2)) {
+                inner_0 += e_pair[0] * p_pair[0];
+                inner_inf += (e_pair[1] - e_pair[0]) * (p_pair[1] - p_pair[0]);
+            }
+            q0 += eh * inner_0;
+            q_inf += eh * inner_inf;
+        }
+        vec![q0, q_inf]
+    }
+
+    /// Round polynomial while binding high-order bits
+    /// (`rounds_elapsed ≥ v_lo`): a pair-split inner product of `eq_hi` and
+    /// `p`, scaled by the folded `eq_lo` scalar.
+    fn round_poly_hi(&self) -> Vec<F> {
+        let n = self.p.len();
+        debug_assert_eq!(self.eq_lo.len(), 1);
+        debug_assert_eq!(self.eq_hi.len(), n);
+
+        let scalar = self.eq_lo[0];
+        if n <= 1 {
+            let v = if n == 1 {
+                scalar * self.eq_hi[0] * self.p[0]
+            } else {
+                F::ZERO
+            };
+            return vec![v, F::ZERO];
+        }
+
+        let mut q0 = F::ZERO;
+        let mut q_inf = F::ZERO;
+        for (p_pair, e_pair) in self.p.chunks_exact(2).zip(self.eq_hi.chunks_exact(2)) {
+            q0 += e_pair[0] * p_pair[0];
+            q_inf += (e_pair[1] - e_pair[0]) * (p_pair[1] - p_pair[0]);
+        }
+        vec![q0 * scalar, q_inf * scalar]
+    }
+
+    fn fold(&mut self, r: F, var: usize) {
+        fold_lsb(&mut self.p, r);
+        if var < self.v_lo {
+            fold_lsb(&mut self.eq_lo, r);
+        } else {
+            fold_lsb(&mut self.eq_hi, r);
+        }
+    }
+}
+
+impl<F> SumcheckProver<F> for EqFactoredProverLSB<F>
+where
+    F: SumcheckField,
+{
+    fn degree(&self) -> usize {
+        2
+    }
+
+    fn round(&mut self, challenge: Option<F>) -> Vec<F> {
+        if let Some(r) = challenge {
+            self.fold(r, self.rounds_elapsed - 1);
+        }
+
+        let round_poly = if self.rounds_elapsed < self.v_lo {
+            self.round_poly_lo()
+        } else {
+            self.round_poly_hi()
+        };
+        self.rounds_elapsed += 1;
+        round_poly
+    }
+
+    fn finalize(&mut self, last_challenge: F) {
+        self.fold(last_challenge, self.v - 1);
+    }
+
+    impl_table_len!(p);
+
+    fn final_value(&self) -> F {
+        if self.p.len() == 1 {
+            self.p[0] * self.eq_lo[0] * self.eq_hi[0]
+        } else {
+            F::ZERO
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hypercube::eq_poly_non_binary;
+    use crate::provers::eq_factored::EqFactoredProver;
+    use crate::runner::sumcheck;
+    use crate::tests::{ReplayTranscript, F64};
+    use crate::verifier::sumcheck_verify;
+    use ark_ff::UniformRand;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    fn bit_reverse<T: Copy>(v: &[T]) -> Vec<T> {
+        let bits = v.len().trailing_zeros();
+        if bits == 0 {
+            return v.to_vec();
+        }
+        (0..v.len())
+            .map(|i| v[i.reverse_bits() >> (usize::BITS - bits)])
+            .collect()
+    }
+
+    /// The LSB prover on `p` matches the MSB prover on bit-reversed `p`:
+    /// both bind `w[j]` in round `j`.
+    #[test]
+    fn lsb_matches_msb_on_bit_reversed_table() {
+        let mut rng = StdRng::seed_from_u64(0xA10);
+        for v in 1..=7 {
+            let w: Vec<F64> = (0..v).map(|_| F64::rand(&mut rng)).collect();
+            let p: Vec<F64> = (0..1 << v).map(|_| F64::rand(&mut rng)).collect();
+
+            let mut lsb = EqFactoredProverLSB::new(w.clone(), p.clone());
+            let mut t = ReplayTranscript::new(0xA11);
+            let lsb_proof = sumcheck(&mut lsb, v, &mut t, |_, _| {});
+
+            let mut msb = EqFactoredProver::new(w.clone(), bit_reverse(&p));
+            let mut t_msb = ReplayTranscript::new(0xA11);
+            let msb_proof = sumcheck(&mut msb, v, &mut t_msb, |_, _| {});
+
+            assert_eq!(lsb_proof.round_polys, msb_proof.round_polys, "v={v}");
+            assert_eq!(lsb.final_factors(), msb.final_factors(), "v={v}");
+
+            let claim: F64 = build_eq_table_lsb(&w)
+                .iter()
+                .zip(&p)
+                .map(|(&e, &x)| e * x)
+                .sum();
+            let mut vt = t.replay();
+            let result = sumcheck_verify(claim, 2, v, &mut vt, |_, _| Ok(())).unwrap();
+            assert_eq!(result.final_claim, lsb_proof.final_value, "v={v}");
+            assert_eq!(
+                lsb.final_factors().1,
+                eq_poly_non_binary(&w, &lsb_proof.challenges)
+            );
+        }
+    }
+}
@@ -0,0 +1,346 @@
+//! Eq-weighted product sumcheck prover: `g(x) = eq(w, x) · a(x) · b(x)`, degree 3.
+//!
+//! Implements [`SumcheckProver`] for
+//!
+//! ```text
+//! ∑_{x ∈ {0,1}^v} eq(w, x) · a(x) · b(x) = H
+//! ```
+//!
+//! with `a`, `b` multilinear. This is the per-layer sumcheck of GKR-style
+//! product trees (see [`crate::grand_product`]), where `a` and `b` are the
+//! left and right children of each node.
+//!
+//! # Space
+//!
+//! Like [`EqFactoredProver`](super::eq_factored::EqFactoredProver), `eq` is
+//! stored as the two split-value half-tables `eq(w_L, ·)` and `eq(w_R, ·)`
+//! (BDDT25 Algorithm 5). Left-half rounds fold `eq_L`, right-half rounds
+//! fold `eq_R`; `eq(w, r) = eq_L[0] · eq_R[0]` after the last round.
+//!
+//! # Wire format
+//!
+//! EvalsInfty for degree 3: `[q(0), q(∞), q(2)]`.
+
+use crate::field::SumcheckField;
+use crate::inner_product_sumcheck as ip;
+use crate::provers::eq_factored::build_eq_table;
+use crate::sumcheck_prover::{impl_table_len, SumcheckProver};
+
+use alloc::{vec, vec::Vec};
+
+/// Eq-weighted product sumcheck prover for `∑_x eq(w, x) · a(x) · b(x)` (degree 3).
+///
+/// See [module docs](self) for details.
+///
+/// ```ignore
+/// use effsc::provers::eq_product::EqProductProver;
+/// use effsc::runner::sumcheck;
+///
+/// let mut prover = EqProductProver::new(w, a, b);
+/// let proof = sumcheck(&mut prover, num_vars, &mut transcript, noop_hook);
+/// // final_value() = eq(w, r) · a(r) · b(r)
+/// let (evals, eq_wr) = prover.final_factors(); // evals = [a(r), b(r)]
+/// ```
+pub struct EqProductProver<F: SumcheckField> {
+    /// Multilinear factor tables (MSB layout), padded to `2^v`.
+    tables: Vec<Vec<F>>,
+    /// Product terms `(coefficient, lhs table, rhs table)`.
+    terms: Vec<(F, usize, usize)>,
+    /// `eq(w_L, ·)` over `{0,1}^{v_L}` (MSB layout).
+    eq_l: Vec<F>,
+    /// `eq(w_R, ·)` over `{0,1}^{v_R}` (MSB layout).
+    eq_r: Vec<F>,
+    /// Number of left-half variables `v_L = v / 2`.
+    v_l: usize,
+    /// Total number of variables.
+    v: usize,
+    /// Number of completed [`round`](SumcheckProver::round) calls.
+    rounds_elapsed: usize,
+}
+
+impl<F: SumcheckField> EqProductProver<F> {
+    /// Construct a prover for `∑_x eq(w, x) · a(x) · b(x)`.
+    ///
+    /// `a` and `b` must have length `≤ 2^{w.len()}`; shorter inputs are
+    /// zero-padded.
+    pub fn new(w: Vec<F>, a: Vec<F>, b: Vec<F>) -> Self {
+        Self::from_terms(w, vec![a, b], vec![(F::ONE, 0, 1)])
+    }
+
+    /// Construct from factor tables and `(coefficient, lhs, rhs)` product terms.
+    pub(crate) fn from_terms(
+        w: Vec<F>,
+        mut tables: Vec<Vec<F>>,
+        terms: Vec<(F, usize, usize)>,
+    ) -> Self {
+        let v = w.len();
+        let n = 1usize << v;
+        for table in &mut tables {
+            assert!(
+                table.len() <= n,
+                "table length {} exceeds 2^{} = {}",
+                table.len(),
+                v,
+                n
+            );
+            table.resize(n, F::ZERO);
+        }
+        for &(_, lhs, rhs) in &terms {
+            assert!(
+                lhs < tables.len() && rhs < tables.len(),
+                "term index out of range"
+            );
+        }
+        let v_l = v / 2;
+        let (w_l, w_r) = w.split_at(v_l);
+        Self {
+            tables,
+            terms,
+            eq_l: build_eq_table(w_l),
+            eq_r: build_eq_table(w_r),
+            v_l,
+            v,
+            rounds_elapsed: 0,
+        }
+    }
+
+    /// After full sumcheck: each factor table evaluated at `r`, and `eq(w, r)`.
+    pub fn final_factors(&self) -> (Vec<F>, F) {
+        if self.tables.iter().all(|t| t.len() == 1) {
+            (
+                self.tables.iter().map(|t| t[0]).collect(),
+                self.eq_l[0] * self.eq_r[0],
+            )
+        } else {
+            (vec![F::ZERO; self.tables.len()], F::ZERO)
+        }
+    }
+
+    /// `Σ_k c_k · a_k · b_k` at one pair `(lo, hi)` of table indices,
+    /// evaluated at `X = 0`, at the node `X = node2`
+    /// ([`eval_node(2)`](SumcheckField::eval_node)) and as the `X²`
+    /// coefficient.
+    #[inline]
+    fn pair_evals(&self, lo: usize, hi: usize, node2: F) -> (F, F, F) {
+        let mut g0 = F::ZERO;
+        let mut g2 = F::ZERO;
+        let mut g_inf = F::ZERO;
+        for &(c, lhs, rhs) in &self.terms {
+            let (al, ah) = (self.tables[lhs][lo], self.tables[lhs][hi]);
+            let (bl, bh) = (self.tables[rhs][lo], self.tables[rhs][hi]);
+            let da = ah - al;
+            let db = bh - bl;
+            g0 += c * al * bl;
+            g2 += c * (al + node2 * da) * (bl + node2 * db);
+            g_inf += c * da * db;
+        }
+        (g0, g2, g_inf)
+    }
+
+    /// Round polynomial during the left-half phase (`rounds_elapsed < v_L`).
+    ///
+    /// Same nested-sum kernel as
+    /// [`EqFactoredProver`](super::eq_factored::EqFactoredProver): for each
+    /// `a`, the product factor is contracted against `eq_R` over `b`, then
+    /// multiplied by the (linear in `x_j`) `eq_L(x_j, a)`.
+    fn round_poly_left(&self) -> Vec<F> {
+        let eq_l_half = self.eq_l.len() >> 1;
+        let (eq_l_lo, eq_l_hi) = self.eq_l.split_at(eq_l_half);
+        let half = self.tables[0].len() >> 1;
+        let m = self.eq_r.len();
+        debug_assert_eq!(eq_l_half * m, half);
+        let node2 = F::eval_node(2);
+
+        let mut q0 = F::ZERO;
+        let mut q2 = F::ZERO;
+        let mut q_inf = F::ZERO;
+        for a in 0..eq_l_half {
+            let mut inner_0 = F::ZERO;
+            let mut inner_2 = F::ZERO;
+            let mut inner_inf = F::ZERO;
+            for (b, &er) in self.eq_r.iter().enumerate() {
+                let lo = a * m + b;
+                let (g0, g2, g_inf) = self.pair_evals(lo, lo + half, node2);
+                inner_0 += er * g0;
+                inner_2 += er * g2;
+                inner_inf += er * g_inf;
+            }
+
+            let el_lo = eq_l_lo[a];
+            let el_delta = eq_l_hi[a] - el_lo;
+            q0 += el_lo * inner_0;
+            q2 += (el_lo + node2 * el_delta) * inner_2;
+            q_inf += el_delta * inner_inf;
+        }
+
+        vec![q0, q_inf, q2]
+    }
+
+    /// Round polynomial during the right-half phase (`rounds_elapsed ≥ v_L`),
+    /// scaled by the folded `eq_L` scalar.
+    fn round_poly_right(&self) -> Vec<F> {
+        let n = self.eq_r.len();
+        debug_assert_eq!(self.eq_l.len(), 1);
+
+        let scalar = self.eq_l[0];
+        if n <= 1 {
+            let (g0, _, _) = self.pair_evals(0, 0, F::ZERO);
+            return vec![scalar * self.eq_r[0] * g0, F::ZERO, F::ZERO];
+        }
+
+        let half = n >> 1;
+        let (eq_r_lo, eq_r_hi) = self.eq_r.split_at(half);
+        let node2 = F::eval_node(2);
+
+        let mut q0 = F::ZERO;
+        let mut q2 = F::ZERO;
+        let mut q_inf = F::ZERO;
+        for (i, (&el, &eh)) in eq_r_lo.iter().zip(eq_r_hi).enumerate() {
+            let (g0, g2, g_inf) = self.pair_evals(i, i + half, node2);
+            let delta = eh - el;
+            q0 += el * g0;
+            q2 += (el + node2 * delta) * g2;
+            q_inf += delta * g_inf;
+        }
+
+        vec![q0 * scalar, q_inf * scalar, q2 * scalar]
+    }
+
+    fn fold_all(&mut self, r: F, var: usize) {
+        for table in &mut self.tables {
+            ip::fold(table, r);
+        }
+        if var < self.v_l {
+            ip::fold(&mut self.eq_l, r);
+        } else {
+            ip::fold(&mut self.eq_r, r);
+        }
+    }
+}
+
+impl<F> SumcheckProver<F> for EqProductProver<F>
+where
+    F: SumcheckField,
+{
+    fn degree(&self) -> usize {
+        3
+    }
+
+    fn round(&mut self, challenge: Option<F>) -> Vec<F> {
+        if let Some(r) = challenge {
+            self.fold_all(r, self.rounds_elapsed - 1);
+        }
+
+        let round_poly = if self.rounds_elapsed < self.v_l {
+            self.round_poly_left()
+        } else {
+            self.round_poly_right()
+        };
+        self.rounds_elapsed += 1;
+        round_poly
+    }
+
+    fn finalize(&mut self, last_challenge: F) {
+        self.fold_all(last_challenge, self.v - 1);
+    }
+
+    impl_table_len!(|p| p.tables.first().map_or(0, Vec::len));
+
+    fn final_value(&self) -> F {
+        let (evals, eq_wr) = self.final_factors();
+        let g: F = self
+            .terms
+            .iter()
+            .map(|&(c, lhs, rhs)| c * evals[lhs] * evals[rhs])
+            .sum();
+        eq_wr * g
+    }
+}
+
+#[cfg(all(test, feature = "arkworks"))]
+mod tests {
+    use super::*;
+    use crate::coefficient_sumcheck::RoundPolyEvaluator;
+    use crate::provers::coefficient::CoefficientProver;
+    use crate::runner::sumcheck;
+    use crate::tests::{ReplayTranscript, F64};
+    use crate::transcript::SanityTranscript;
+    use crate::verifier::sumcheck_verify;
+    use ark_ff::UniformRand;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    /// `eq · a · b` as a product of three pairwise tables.
+    struct TripleProduct;
+    impl RoundPolyEvaluator<F64> for TripleProduct {
+        fn degree(&self) -> usize {
+            3
+        }
+        fn accumulate_pair(&self, coeffs: &mut [F64], _tw: &[(&[F64], &[F64])], pw: &[(F64, F64)]) {
+            let lin = |(lo, hi): (F64, F64)| [lo, hi - lo];
+            let (e, a, b) = (lin(pw[0]), lin(pw[1]), lin(pw[2]));
+            let mut ea = [F64::from(0u64); 3];
+            crate::polynomial::mul_into(&mut ea, &e, &a);
+            let mut eab = [F64::from(0u64); 4];
+            crate::polynomial::mul_into(&mut eab, &ea, &b);
+            for (c, x) in coeffs.iter_mut().zip(eab) {
+                *c += x;
+            }
+        }
+    }
+
+    #[test]
+    fn eq_product_matches_coefficient_prover() {
+        for &v in &[1usize, 2, 3, 4, 5, 6] {
+            let mut rng = StdRng::seed_from_u64(0xE10 ^ v as u64);
+            let n = 1usize << v;
+            let w: Vec<F64> = (0..v).map(|_| F64::rand(&mut rng)).collect();
+            let a: Vec<F64> = (0..n).map(|_| F64::rand(&mut rng)).collect();
+            let b: Vec<F64> = (0..n).map(|_| F64::rand(&mut rng)).collect();
+
+            let mut prover = EqProductProver::new(w.clone(), a.clone(), b.clone());
+            let mut trng1 = StdRng::seed_from_u64(0xFACE ^ v as u64);
+            let mut t1 = SanityTranscript::new(&mut trng1);
+            let proof = sumcheck(&mut prover, v, &mut t1, |_, _| {});
+
+            let evaluator = TripleProduct;
+            let mut reference =
+                CoefficientProver::new(&evaluator, vec![], vec![build_eq_table(&w), a, b]);
+            let mut trng2 = StdRng::seed_from_u64(0xFACE ^ v as u64);
+            let mut t2 = SanityTranscript::new(&mut trng2);
+            let expected = sumcheck(&mut reference, v, &mut t2, |_, _| {});
+
+            assert_eq!(proof.round_polys, expected.round_polys, "v={v}");
+            assert_eq!(proof.final_value, expected.final_value, "v={v}");
+        }
+    }
+
+    #[test]
+    fn eq_product_verifies() {
+        let mut rng = StdRng::seed_from_u64(0xE11);
+        let v = 5;
+        let n = 1usize << v;
+        let w: Vec<F64> = (0..v).map(|_| F64::rand(&mut rng)).collect();
+        let a: Vec<F64> = (0..n).map(|_| F64::rand(&mut rng)).collect();
+        let b: Vec<F64> = (0..n).map(|_| F64::rand(&mut rng)).collect();
+        let eq = build_eq_table(&w);
+        let claimed_sum: F64 = eq
+            .iter()
+            .zip(a.iter().zip(&b))
+            .map(|(&e, (&x, &y))| e * x * y)
+            .sum();
+
+        let mut prover = EqProductProver::new(w, a, b);
+        let mut t = ReplayTranscript::new(0xBEEF);
+        let proof = sumcheck(&mut prover, v, &mut t, |_, _| {});
+        for rp in &proof.round_polys {
+            assert_eq!(rp.len(), 3, "EvalsInfty degree-3 wire length");
+        }
+
+        let mut vt = t.replay();
+        let result = sumcheck_verify(claimed_sum, 3, v, &mut vt, |_, _| Ok(())).unwrap();
+        assert_eq!(result.final_claim, proof.final_value);
+
+        let (evals, eq_wr) = prover.final_factors();
+        assert_eq!(proof.final_value, eq_wr * evals[0] * evals[1]);
+    }
+}
@@ -29,9 +29,10 @@
 //! let (w_b, w_c) = prover.claimed_w_values();
 //! ```
 
-use crate::field::SumcheckField;
+use crate::field::{ExtensionOf, SumcheckField};
 use crate::inner_product_sumcheck as ip;
-use crate::sumcheck_prover::SumcheckProver;
+use crate::provers::cross_field::{lift_fold, BaseFieldProver, CrossFieldProver};
+use crate::sumcheck_prover::{impl_table_len, SumcheckProver};
 
 use alloc::{vec, vec::Vec};
 
@@ -82,6 +83,22 @@ impl<F: SumcheckField> GkrProver<F> {
         }
     }
 
+    /// Cross-field prover: gate predicates and witness in `BF`, challenges
+    /// in `F`.
+    ///
+    /// Round 0 is computed over `BF`; the first challenge lifts the four
+    /// half-folded tables to `F`.
+    pub fn cross_field<BF: SumcheckField>(
+        add_evals: Vec<BF>,
+        mult_evals: Vec<BF>,
+        w_evals: Vec<BF>,
+    ) -> CrossFieldProver<F, GkrProver<BF>>
+    where
+        F: ExtensionOf<BF>,
+    {
+        CrossFieldProver::new(GkrProver::new(add_evals, mult_evals, w_evals))
+    }
+
     /// After full sumcheck: the claimed witness evaluations `(W(b*), W(c*))`.
     ///
     /// These are the inputs to the reduce-to-one sub-protocol (Thaler §4.5.2).
@@ -112,10 +129,11 @@ where
         }
 
         // EvalsInfty wire format: emit [q(0), q(∞)] for degree-2 round poly.
-        // Strategy: compute (q(0), q(1), q(2)) as before, then convert via
-        //   q(∞) = (q(0) + q(2) − 2·q(1)) / 2
-        // This preserves tail-case semantics identical to the prior
-        // implementation and avoids per-round algebraic rederivation.
+        // Each factor is linear in the bound variable, `t(X) = t_lo + X·Δt`,
+        // and at most one of `W(b)`, `W(c)` depends on it, so the leading
+        // coefficient is computed directly (no division by 2, which keeps
+        // the prover valid in characteristic 2):
+        //   Δadd·(Δw_b + Δw_c) + Δmult·(Δw_b·w_c + w_b·Δw_c) + mult·Δw_b·Δw_c.
         let n = self.add_evals.len();
         if n <= 1 {
             let v = if n == 1 {
@@ -137,48 +155,29 @@ where
         let paired = add_hi.len();
 
         let mut q0 = F::ZERO;
-        let mut q1 = F::ZERO;
-        let mut q2 = F::ZERO;
+        let mut q_inf = F::ZERO;
 
         for i in 0..paired {
-            let al = add_lo[i];
-            let ah = add_hi[i];
-            let ml = mult_lo[i];
-            let mh = mult_hi[i];
-            let wbl = wb_lo[i];
-            let wbh = wb_hi[i];
-            let wcl = wc_lo[i];
-            let wch = wc_hi[i];
-
-            q0 += al * (wbl + wcl) + ml * (wbl * wcl);
-            q1 += ah * (wbh + wch) + mh * (wbh * wch);
-
-            let a2 = ah + ah - al;
-            let m2 = mh + mh - ml;
-            let wb2 = wbh + wbh - wbl;
-            let wc2 = wch + wch - wcl;
-            q2 += a2 * (wb2 + wc2) + m2 * (wb2 * wc2);
+            let (a, da) = (add_lo[i], add_hi[i] - add_lo[i]);
+            let (m, dm) = (mult_lo[i], mult_hi[i] - mult_lo[i]);
+            let (wb, dwb) = (wb_lo[i], wb_hi[i] - wb_lo[i]);
+            let (wc, dwc) = (wc_lo[i], wc_hi[i] - wc_lo[i]);
+
+            q0 += a * (wb + wc) + m * (wb * wc);
+            q_inf += da * (dwb + dwc) + dm * (dwb * wc + wb * dwc) + m * (dwb * dwc);
         }
 
-        // Tail: hi is implicitly zero.
+        // Tail: hi is implicitly zero, so every factor is `t_lo·(1 − X)`.
+        // The add term `(1 − X)²·add·(w_b + w_c)` contributes its `X²`
+        // coefficient. The cubic mult term `(1 − X)³·mult·w_b·w_c` only
+        // enters `q(0)`, as under the second-difference conversion
+        // `(q(0) + q(2) − 2·q(1)) / 2` (which maps `(1 − X)³` to zero).
         for i in paired..half.min(n) {
-            let al = add_lo[i];
-            let ml = mult_lo[i];
-            let wbl = wb_lo[i];
-            let wcl = wc_lo[i];
-
-            q0 += al * (wbl + wcl) + ml * (wbl * wcl);
-            // q(1) += 0 (hi is zero)
-            q2 += al * (wbl + wcl) - ml * (wbl * wcl);
+            let add_term = add_lo[i] * (wb_lo[i] + wc_lo[i]);
+            q0 += add_term + mult_lo[i] * (wb_lo[i] * wc_lo[i]);
+            q_inf += add_term;
         }
 
-        // Convert {q(0), q(1), q(2)} → {q(0), q(∞)}.
-        //   q(∞) = (q(0) + q(2) − 2·q(1)) / 2
-        let two_inv = F::from_u64(2)
-            .inverse()
-            .expect("field characteristic must not be 2");
-        let q_inf = (q0 + q2 - q1.double()) * two_inv;
-
         vec![q0, q_inf]
     }
 
@@ -189,6 +188,8 @@ where
         ip::fold(&mut self.w_c, last_challenge);
     }
 
+    impl_table_len!(add_evals);
+
     fn final_value(&self) -> F {
         if self.add_evals.len() == 1 {
             let wb = self.w_b[0];
@@ -200,6 +201,33 @@ where
     }
 }
 
+impl<BF: SumcheckField, EF: ExtensionOf<BF>> BaseFieldProver<EF> for GkrProver<BF> {
+    type Lifted = GkrProver<EF>;
+
+    fn degree(&self) -> usize {
+        2
+    }
+
+    fn first_round(&mut self) -> Vec<EF> {
+        self.round(None).into_iter().map(EF::from_base).collect()
+    }
+
+    fn lift_fold(self, challenge: EF) -> GkrProver<EF> {
+        GkrProver {
+            add_evals: lift_fold(&self.add_evals, challenge),
+            mult_evals: lift_fold(&self.mult_evals, challenge),
+            w_b: lift_fold(&self.w_b, challenge),
+            w_c: lift_fold(&self.w_c, challenge),
+        }
+    }
+
+    impl_table_len!(add_evals);
+
+    fn final_value(&self) -> EF {
+        EF::from_base(SumcheckProver::final_value(self))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
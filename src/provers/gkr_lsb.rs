@@ -0,0 +1,208 @@
+//! LSB (pair-split) GKR round sumcheck prover (degree 2).
+//!
+//! Same polynomial as [`GkrProver`](super::gkr::GkrProver):
+//!
+//! ```text
+//! f_r(b, c) = add_i(r, b, c) · (W(b) + W(c)) + mult_i(r, b, c) · (W(b) · W(c))
+//! ```
+//!
+//! with the same table layout (`idx = b · 2^k + c`), but each round folds
+//! the *least-significant* index bit: pairs `(t[2i], t[2i+1])`. The first
+//! `k` rounds bind the bits of `c`, least-significant first; the last `k`
+//! bind the bits of `b`. With challenges `r_0, ..., r_{2k-1}`:
+//!
+//! ```text
+//! c* = (r_0, ..., r_{k-1}),   b* = (r_k, ..., r_{2k-1})   (bit j ↔ r_j)
+//! ```
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut prover = GkrProverLSB::new(add_evals, mult_evals, w_evals);
+//! let proof = sumcheck(&mut prover, 2 * k, &mut transcript, noop_hook);
+//! let (w_b, w_c) = prover.claimed_w_values();
+//! ```
+
+use crate::field::SumcheckField;
+use crate::provers::inner_product_lsb::fold_lsb;
+use crate::sumcheck_prover::{impl_table_len, SumcheckProver};
+
+use alloc::{vec, vec::Vec};
+
+/// LSB GKR round sumcheck prover (degree 2, pair-split layout).
+///
+/// See [module docs](self) for details.
+pub struct GkrProverLSB<F: SumcheckField> {
+    /// Gate add predicate: `add_i(r, b, c)`, `2^{2k}` entries.
+    add_evals: Vec<F>,
+    /// Gate mult predicate: `mult_i(r, b, c)`, `2^{2k}` entries.
+    mult_evals: Vec<F>,
+    /// Witness `W(b)` broadcast over c: `w_b[b * 2^k + c] = W(b)`.
+    w_b: Vec<F>,
+    /// Witness `W(c)` broadcast over b: `w_c[b * 2^k + c] = W(c)`.
+    w_c: Vec<F>,
+}
+
+impl<F: SumcheckField> GkrProverLSB<F> {
+    /// Construct from gate predicates and witness evaluations.
+    ///
+    /// - `add_evals`: `add_i(r, b, c)` at `b * 2^k + c` for `(b, c) in {0,1}^{2k}`.
+    /// - `mult_evals`: `mult_i(r, b, c)`, same layout.
+    /// - `w_evals`: `W(x)` for all `x in {0,1}^k`.
+    ///
+    /// The gate tables must have length `w_evals.len()^2`.
+    pub fn new(add_evals: Vec<F>, mult_evals: Vec<F>, w_evals: Vec<F>) -> Self {
+        let n = w_evals.len();
+        let n_bc = n * n;
+        assert_eq!(add_evals.len(), n_bc, "add_evals must have len w^2");
+        assert_eq!(mult_evals.len(), n_bc, "mult_evals must have len w^2");
+
+        let mut w_b = Vec::with_capacity(n_bc);
+        let mut w_c = Vec::with_capacity(n_bc);
+        for &wb in &w_evals {
+            w_b.resize(w_b.len() + n, wb);
+            w_c.extend_from_slice(&w_evals);
+        }
+
+        Self {
+            add_evals,
+            mult_evals,
+            w_b,
+            w_c,
+        }
+    }
+
+    /// After full sumcheck: the claimed witness evaluations `(W(b*), W(c*))`.
+    ///
+    /// These are the inputs to the reduce-to-one sub-protocol (Thaler §4.5.2).
+    pub fn claimed_w_values(&self) -> (F, F) {
+        if self.w_b.len() == 1 {
+            (self.w_b[0], self.w_c[0])
+        } else {
+            (F::ZERO, F::ZERO)
+        }
+    }
+}
+
+impl<F> SumcheckProver<F> for GkrProverLSB<F>
+where
+    F: SumcheckField,
+{
+    fn degree(&self) -> usize {
+        2
+    }
+
+    fn round(&mut self, challenge: Option<F>) -> Vec<F> {
+        if let Some(r) = challenge {
+            self.finalize(r);
+        }
+
+        let n = self.add_evals.len();
+        if n <= 1 {
+            return vec![self.final_value(), F::ZERO];
+        }
+
+        // EvalsInfty: [q(0), q(∞)], derived as in `GkrProver::round` over even/odd pairs.
+        let mut q0 = F::ZERO;
+        let mut q_inf = F::ZERO;
+        for i in (0..n).step_by(2) {
+            let (a, da) = (self.add_evals[i], self.add_evals[i + 1] - self.add_evals[i]);
+            let (m, dm) = (
+                self.mult_evals[i],
+                self.mult_evals[i + 1] - self.mult_evals[i],
+            );
+            let (wb, dwb) = (self.w_b[i], self.w_b[i + 1] - self.w_b[i]);
+            let (wc, dwc) = (self.w_c[i], self.w_c[i + 1] - self.w_c[i]);
+
+            q0 += a * (wb + wc) + m * (wb * wc);
+            q_inf += da * (dwb + dwc) + dm * (dwb * wc + wb * dwc) + m * (dwb * dwc);
+        }
+
+        vec![q0, q_inf]
+    }
+
+    fn finalize(&mut self, last_challenge: F) {
+        fold_lsb(&mut self.add_evals, last_challenge);
+        fold_lsb(&mut self.mult_evals, last_challenge);
+        fold_lsb(&mut self.w_b, last_challenge);
+        fold_lsb(&mut self.w_c, last_challenge);
+    }
+
+    impl_table_len!(add_evals);
+
+    fn final_value(&self) -> F {
+        if self.add_evals.len() == 1 {
+            let wb = self.w_b[0];
+            let wc = self.w_c[0];
+            self.add_evals[0] * (wb + wc) + self.mult_evals[0] * (wb * wc)
+        } else {
+            F::ZERO
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::multilinear::mle_eval;
+    use crate::provers::gkr::GkrProver;
+    use crate::runner::sumcheck;
+    use crate::tests::{ReplayTranscript, F64};
+    use crate::verifier::sumcheck_verify;
+    use ark_ff::UniformRand;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    fn bit_reverse<T: Copy>(v: &[T]) -> Vec<T> {
+        let bits = v.len().trailing_zeros();
+        if bits == 0 {
+            return v.to_vec();
+        }
+        (0..v.len())
+            .map(|i| v[i.reverse_bits() >> (usize::BITS - bits)])
+            .collect()
+    }
+
+    /// Bit-reversing `idx = b·2^k + c` gives `rev(c)·2^k + rev(b)`, so the
+    /// MSB prover on bit-reversed tables (with `b` and `c` swapped, which
+    /// `f_r` is symmetric in) sends the same round polynomials.
+    #[test]
+    fn lsb_matches_msb_on_bit_reversed_tables() {
+        let mut rng = StdRng::seed_from_u64(0xA20);
+        for k in 1..=3 {
+            let n = 1 << k;
+            let add: Vec<F64> = (0..n * n).map(|_| F64::rand(&mut rng)).collect();
+            let mult: Vec<F64> = (0..n * n).map(|_| F64::rand(&mut rng)).collect();
+            let w: Vec<F64> = (0..n).map(|_| F64::rand(&mut rng)).collect();
+
+            let mut lsb = GkrProverLSB::new(add.clone(), mult.clone(), w.clone());
+            let mut t = ReplayTranscript::new(0xA21);
+            let lsb_proof = sumcheck(&mut lsb, 2 * k, &mut t, |_, _| {});
+
+            let mut msb = GkrProver::new(bit_reverse(&add), bit_reverse(&mult), bit_reverse(&w));
+            let mut t_msb = ReplayTranscript::new(0xA21);
+            let msb_proof = sumcheck(&mut msb, 2 * k, &mut t_msb, |_, _| {});
+
+            assert_eq!(lsb_proof.round_polys, msb_proof.round_polys, "k={k}");
+            assert_eq!(lsb_proof.final_value, msb_proof.final_value, "k={k}");
+            let (w_b, w_c) = lsb.claimed_w_values();
+            assert_eq!((w_c, w_b), msb.claimed_w_values(), "k={k}");
+
+            // W(b*) and W(c*) at the documented points.
+            let point = |r: &[F64]| -> Vec<F64> { r.iter().rev().copied().collect() };
+            let c_star = point(&lsb_proof.challenges[..k]);
+            let b_star = point(&lsb_proof.challenges[k..]);
+            assert_eq!(w_b, mle_eval(&w, &b_star));
+            assert_eq!(w_c, mle_eval(&w, &c_star));
+
+            let mut claim = F64::from(0u64);
+            for b in 0..n {
+                for c in 0..n {
+                    claim += add[b * n + c] * (w[b] + w[c]) + mult[b * n + c] * (w[b] * w[c]);
+                }
+            }
+            let mut vt = t.replay();
+            let result = sumcheck_verify(claim, 2, 2 * k, &mut vt, |_, _| Ok(())).unwrap();
+            assert_eq!(result.final_claim, lsb_proof.final_value, "k={k}");
+        }
+    }
+}
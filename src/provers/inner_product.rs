@@ -2,9 +2,11 @@
 //!
 //! Implements [`SumcheckProver`] for the quadratic sumcheck `∑_x f(x)·g(x)`.
 
-use crate::field::SumcheckField;
+use crate::field::{ExtensionOf, SumcheckField};
 use crate::inner_product_sumcheck as ip;
-use crate::sumcheck_prover::SumcheckProver;
+use crate::polynomial::SkipDomain;
+use crate::provers::cross_field::{lift_fold, BaseFieldProver, CrossFieldProver};
+use crate::sumcheck_prover::{impl_table_len, SumcheckProver};
 use alloc::{vec, vec::Vec};
 
 /// Inner-product sumcheck prover (degree 2).
@@ -23,16 +25,67 @@ use alloc::{vec, vec::Vec};
 /// let proof = sumcheck(&mut prover, num_rounds, &mut transcript, |_, _| {});
 /// let (f_eval, g_eval) = prover.final_evaluations();
 /// ```
+///
+/// # Univariate skip
+///
+/// [`with_univariate_skip`](Self::with_univariate_skip) replaces the first
+/// `k` rounds with one round over a [`SkipDomain`] of size `N = 2^k`. That
+/// round sends the coefficients `[c_1, ..., c_D]` of
+/// `h(Y) = Σ_{x'} f̂(Y, x')·ĝ(Y, x')`, `D = 2N − 2`; the verifier derives
+/// `c_0` from the domain-sum identity (see
+/// [`sumcheck_verify_with_skip`](crate::verifier::sumcheck_verify_with_skip)).
+/// Run `v − k + 1` rounds in total.
 pub struct InnerProductProver<F: SumcheckField> {
     a: Vec<F>,
     b: Vec<F>,
+    /// Pending univariate-skip round, cleared once its challenge is applied.
+    skip: Option<SkipDomain<F>>,
 }
 
 impl<F: SumcheckField> InnerProductProver<F> {
     /// Time strategy prover: holds both evaluation vectors in memory.
     pub fn new(a: Vec<F>, b: Vec<F>) -> Self {
         assert_eq!(a.len(), b.len(), "a and b must have equal length");
-        Self { a, b }
+        Self { a, b, skip: None }
+    }
+
+    /// Cross-field prover: evaluations in `BF`, challenges in `F`.
+    ///
+    /// Round 0 is computed over `BF`; the first challenge lifts both
+    /// half-folded tables to `F`.
+    pub fn cross_field<BF: SumcheckField>(
+        a: Vec<BF>,
+        b: Vec<BF>,
+    ) -> CrossFieldProver<F, InnerProductProver<BF>>
+    where
+        F: ExtensionOf<BF>,
+    {
+        CrossFieldProver::new(InnerProductProver::new(a, b))
+    }
+
+    /// Prover whose first round is a univariate skip over `domain`.
+    ///
+    /// `a` and `b` must have power-of-two length `≥ domain.size()`.
+    pub fn with_univariate_skip(a: Vec<F>, b: Vec<F>, domain: SkipDomain<F>) -> Self {
+        assert_eq!(a.len(), b.len(), "a and b must have equal length");
+        assert!(
+            a.len().is_power_of_two() && a.len() >= domain.size(),
+            "univariate skip needs a power-of-two table of at least 2^k entries"
+        );
+        Self {
+            a,
+            b,
+            skip: Some(domain),
+        }
+    }
+
+    /// Fold the skipped variables into the skip challenge `r`.
+    fn apply_skip(&mut self, r: F) {
+        if let Some(domain) = self.skip.take() {
+            let basis = domain.lagrange_at(r);
+            self.a = domain.fold_with_basis(&self.a, &basis);
+            self.b = domain.fold_with_basis(&self.b, &basis);
+        }
     }
 
     /// Access the (possibly folded) evaluation vectors.
@@ -58,9 +111,23 @@ where
         2
     }
 
+    fn round_degree(&self, round: usize) -> usize {
+        match &self.skip {
+            Some(domain) if round == 0 => domain.round_degree(2),
+            _ => 2,
+        }
+    }
+
     fn round(&mut self, challenge: Option<F>) -> Vec<F> {
-        // Fold with previous challenge (if any).
-        if let Some(w) = challenge {
+        // Univariate skip: the first round sends [c_1, ..., c_D]; the
+        // second applies the skip challenge instead of a Boolean fold.
+        if let Some(domain) = &self.skip {
+            match challenge {
+                None => return domain.product_coeffs(&self.a, &self.b)[1..].to_vec(),
+                Some(r) => self.apply_skip(r),
+            }
+        } else if let Some(w) = challenge {
+            // Fold with previous challenge (if any).
             ip::fold(&mut self.a, w);
             ip::fold(&mut self.b, w);
         }
@@ -113,10 +180,16 @@ where
     }
 
     fn finalize(&mut self, last_challenge: F) {
+        if self.skip.is_some() {
+            self.apply_skip(last_challenge);
+            return;
+        }
         ip::fold(&mut self.a, last_challenge);
         ip::fold(&mut self.b, last_challenge);
     }
 
+    impl_table_len!(a);
+
     fn final_value(&self) -> F {
         if self.a.len() == 1 {
             self.a[0] * self.b[0]
@@ -126,6 +199,32 @@ where
     }
 }
 
+impl<BF: SumcheckField, EF: ExtensionOf<BF>> BaseFieldProver<EF> for InnerProductProver<BF> {
+    type Lifted = InnerProductProver<EF>;
+
+    fn degree(&self) -> usize {
+        2
+    }
+
+    fn first_round(&mut self) -> Vec<EF> {
+        assert!(
+            self.skip.is_none(),
+            "univariate skip is not supported by the cross-field prover"
+        );
+        self.round(None).into_iter().map(EF::from_base).collect()
+    }
+
+    fn lift_fold(self, challenge: EF) -> InnerProductProver<EF> {
+        InnerProductProver::new(lift_fold(&self.a, challenge), lift_fold(&self.b, challenge))
+    }
+
+    impl_table_len!(a);
+
+    fn final_value(&self) -> EF {
+        EF::from_base(SumcheckProver::final_value(self))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -199,4 +298,84 @@ mod tests {
         // Final value = f(r) * g(r).
         assert_eq!(new_result.final_value, fa * fb);
     }
+
+    /// Univariate skip: the skip prover verifies under
+    /// `sumcheck_verify_with_skip` and its final value is `â·b̂` at the
+    /// skip-then-Boolean point.
+    #[test]
+    fn univariate_skip_roundtrip() {
+        use crate::polynomial::multilinear::mle_eval;
+        use crate::polynomial::SkipDomain;
+        use crate::tests::ReplayTranscript;
+        use crate::verifier::sumcheck_verify_with_skip;
+
+        let mut rng = StdRng::seed_from_u64(0x810);
+        for v in 1..=6 {
+            for k in 1..=v.min(3) {
+                let n = 1 << v;
+                let a: Vec<F64> = (0..n).map(|_| F64::rand(&mut rng)).collect();
+                let b: Vec<F64> = (0..n).map(|_| F64::rand(&mut rng)).collect();
+                let claim: F64 = a.iter().zip(&b).map(|(&x, &y)| x * y).sum();
+                let domain = SkipDomain::<F64>::from_two_adic(k);
+                let num_rounds = v - k + 1;
+
+                let mut prover =
+                    InnerProductProver::with_univariate_skip(a.clone(), b.clone(), domain.clone());
+                assert_eq!(prover.round_degree(0), domain.round_degree(2));
+                let mut t = ReplayTranscript::new(0x811);
+                let proof = sumcheck(&mut prover, num_rounds, &mut t, |_, _| {});
+                assert_eq!(proof.round_polys[0].len(), domain.round_degree(2));
+
+                let mut vt = t.replay();
+                let result = sumcheck_verify_with_skip(
+                    claim,
+                    &domain,
+                    domain.round_degree(2),
+                    2,
+                    num_rounds,
+                    &mut vt,
+                    |_, _| Ok(()),
+                )
+                .unwrap_or_else(|e| panic!("v={v}, k={k}: {e}"));
+                assert_eq!(result.challenges, proof.challenges);
+                assert_eq!(result.final_claim, proof.final_value, "v={v}, k={k}");
+
+                let r_tail = &proof.challenges[1..];
+                let a_r = mle_eval(&domain.fold(&a, proof.challenges[0]), r_tail);
+                let b_r = mle_eval(&domain.fold(&b, proof.challenges[0]), r_tail);
+                assert_eq!(proof.final_value, a_r * b_r, "v={v}, k={k}");
+            }
+        }
+    }
+
+    #[test]
+    fn univariate_skip_rejects_wrong_claim() {
+        use crate::polynomial::SkipDomain;
+        use crate::tests::ReplayTranscript;
+        use crate::verifier::sumcheck_verify_with_skip;
+
+        let mut rng = StdRng::seed_from_u64(0x812);
+        let (v, k) = (5, 2);
+        let a: Vec<F64> = (0..1 << v).map(|_| F64::rand(&mut rng)).collect();
+        let b: Vec<F64> = (0..1 << v).map(|_| F64::rand(&mut rng)).collect();
+        let claim: F64 = a.iter().zip(&b).map(|(&x, &y)| x * y).sum();
+        let domain = SkipDomain::<F64>::from_two_adic(k);
+
+        let mut prover = InnerProductProver::with_univariate_skip(a, b, domain.clone());
+        let mut t = ReplayTranscript::new(0x813);
+        let proof = sumcheck(&mut prover, v - k + 1, &mut t, |_, _| {});
+
+        let mut vt = t.replay();
+        let result = sumcheck_verify_with_skip(
+            claim + F64::from(1u64),
+            &domain,
+            domain.round_degree(2),
+            2,
+            v - k + 1,
+            &mut vt,
+            |_, _| Ok(()),
+        )
+        .unwrap();
+        assert_ne!(result.final_claim, proof.final_value);
+    }
 }
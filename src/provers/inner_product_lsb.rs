@@ -9,7 +9,7 @@
 //! (MSB layout).
 
 use crate::field::SumcheckField;
-use crate::sumcheck_prover::SumcheckProver;
+use crate::sumcheck_prover::{impl_table_len, SumcheckProver};
 use alloc::{vec, vec::Vec};
 
 /// LSB inner-product sumcheck prover (degree 2, pair-split layout).
@@ -75,7 +75,7 @@ fn compute_lsb<F: SumcheckField>(a: &[F], b: &[F]) -> (F, F) {
 }
 
 /// In-place LSB fold: `new[k] = f[2k] + w * (f[2k+1] - f[2k])`.
-fn fold_lsb<F: SumcheckField>(v: &mut Vec<F>, weight: F) {
+pub(crate) fn fold_lsb<F: SumcheckField>(v: &mut Vec<F>, weight: F) {
     if v.len() <= 1 {
         return;
     }
@@ -168,6 +168,8 @@ where
         fold_lsb(&mut self.b, last_challenge);
     }
 
+    impl_table_len!(a);
+
     fn final_value(&self) -> F {
         if self.a.len() == 1 {
             self.a[0] * self.b[0]
@@ -5,9 +5,14 @@
 pub mod coefficient;
 #[cfg(feature = "arkworks")]
 pub mod coefficient_lsb;
+pub mod cross_field;
 pub mod eq_factored;
+pub mod eq_factored_lsb;
+pub mod eq_product;
 pub mod gkr;
+pub mod gkr_lsb;
 pub mod inner_product;
 pub mod inner_product_lsb;
 pub mod multilinear;
 pub mod multilinear_lsb;
+pub mod weighted;
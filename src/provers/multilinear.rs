@@ -3,11 +3,12 @@
 //! Wraps the fused fold+compute kernel from `multilinear_sumcheck.rs`
 //! behind the [`SumcheckProver`] trait.
 
-use crate::field::SumcheckField;
+use crate::field::{ExtensionOf, SumcheckField};
 use crate::multilinear_sumcheck::{
     compute_sumcheck_polynomial, fold, fused_fold_and_compute_polynomial,
 };
-use crate::sumcheck_prover::SumcheckProver;
+use crate::provers::cross_field::{lift_fold, BaseFieldProver, CrossFieldProver};
+use crate::sumcheck_prover::{impl_table_len, SumcheckProver};
 use alloc::{vec, vec::Vec};
 
 /// Multilinear sumcheck prover (degree 1).
@@ -45,6 +46,42 @@ impl<F: SumcheckField> MultilinearProver<F> {
     pub fn evals(&self) -> &[F] {
         &self.evals
     }
+
+    /// Cross-field prover: evaluations in `BF`, challenges in `F`.
+    ///
+    /// Round 0 is computed over `BF`; the first challenge lifts the
+    /// half-folded table to `F`.
+    pub fn cross_field<BF: SumcheckField>(
+        evals: Vec<BF>,
+    ) -> CrossFieldProver<F, MultilinearProver<BF>>
+    where
+        F: ExtensionOf<BF>,
+    {
+        CrossFieldProver::new(MultilinearProver::new(evals))
+    }
+}
+
+impl<BF: SumcheckField, EF: ExtensionOf<BF>> BaseFieldProver<EF> for MultilinearProver<BF> {
+    type Lifted = MultilinearProver<EF>;
+
+    fn degree(&self) -> usize {
+        1
+    }
+
+    fn first_round(&mut self) -> Vec<EF> {
+        let (s0, _s1) = compute_sumcheck_polynomial(&self.evals);
+        vec![EF::from_base(s0)]
+    }
+
+    fn lift_fold(self, challenge: EF) -> MultilinearProver<EF> {
+        MultilinearProver::new(lift_fold(&self.evals, challenge))
+    }
+
+    impl_table_len!(evals);
+
+    fn final_value(&self) -> EF {
+        EF::from_base(SumcheckProver::final_value(self))
+    }
 }
 
 impl<F> SumcheckProver<F> for MultilinearProver<F>
@@ -70,6 +107,8 @@ where
         fold(&mut self.evals, last_challenge);
     }
 
+    impl_table_len!(evals);
+
     fn final_value(&self) -> F {
         if self.evals.len() == 1 {
             self.evals[0]
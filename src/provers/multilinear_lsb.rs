@@ -10,7 +10,7 @@
 //! (MSB layout).
 
 use crate::field::SumcheckField;
-use crate::sumcheck_prover::SumcheckProver;
+use crate::sumcheck_prover::{impl_table_len, SumcheckProver};
 use alloc::{vec, vec::Vec};
 
 #[cfg(feature = "parallel")]
@@ -184,6 +184,8 @@ impl<F: SumcheckField> SumcheckProver<F> for MultilinearProverLSB<F> {
         fold_lsb(&mut self.evals, last_challenge);
     }
 
+    impl_table_len!(evals);
+
     fn final_value(&self) -> F {
         if self.evals.len() == 1 {
             self.evals[0]
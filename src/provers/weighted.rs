@@ -0,0 +1,238 @@
+//! Structured weighted sumcheck prover: `Σ_x f(x) · w(x)`, degree 2.
+//!
+//! WHIR-style protocols prove `Σ_x f(x)·w(x) = H` for a weight that is a
+//! random combination of constraints:
+//!
+//! ```text
+//! w(x) = Σ_i α_i·eq(z_i, x) + Σ_j β_j·pow(y_j, x)
+//! ```
+//!
+//! (see [`StructuredWeight`]). Materializing `w` costs a `2^v` table per
+//! sumcheck; this prover instead keeps every term as a tensor product of
+//! two half-tables, exactly like the split eq of
+//! [`EqFactoredProver`](super::eq_factored::EqFactoredProver), and folds
+//! those symbolically. Weight storage is `O(m · 2^{v/2})` for `m` terms.
+//!
+//! Round work is `O(m · 2^{v-j})` in round `j`: each term contributes a
+//! split-eq round polynomial against the shared `f` table.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut weight = StructuredWeight::new();
+//! weight.add_eq(alpha, z).add_pow(beta, y);
+//! let mut prover = WeightedProver::new(f_evals, &weight);
+//! let proof = sumcheck(&mut prover, num_vars, &mut transcript, noop_hook);
+//! // Verifier: result.final_claim == f(r) · weight.evaluate(&result.challenges)
+//! let (f_r, w_r) = prover.final_factors();
+//! ```
+
+use crate::field::SumcheckField;
+use crate::inner_product_sumcheck as ip;
+use crate::polynomial::weight::product_table;
+use crate::polynomial::StructuredWeight;
+use crate::provers::eq_factored::{split_round_left, split_round_right};
+use crate::sumcheck_prover::{impl_table_len, SumcheckProver};
+
+use alloc::{vec, vec::Vec};
+
+/// One weight term `coeff · L(x_L) · R(x_R)` as two half-tables.
+struct SplitTerm<F> {
+    coeff: F,
+    left: Vec<F>,
+    right: Vec<F>,
+}
+
+/// Structured weighted sumcheck prover for `Σ_x f(x) · w(x)` (degree 2).
+///
+/// See [module docs](self) for details.
+pub struct WeightedProver<F: SumcheckField> {
+    /// `f` evaluations (MSB layout), `2^v` entries. Folded every round.
+    f: Vec<F>,
+    /// Weight terms; the left tables fold during the first `v_L` rounds,
+    /// the right tables afterwards.
+    terms: Vec<SplitTerm<F>>,
+    /// Number of left-half variables `v_L = ⌊v/2⌋`.
+    v_l: usize,
+    /// Total number of variables.
+    v: usize,
+    /// Number of completed [`round`](SumcheckProver::round) calls.
+    rounds_elapsed: usize,
+}
+
+impl<F: SumcheckField> WeightedProver<F> {
+    /// Construct a prover for `Σ_x f(x) · w(x)` over `v = log2(f.len())`
+    /// variables.
+    ///
+    /// `f_evals.len()` must be a power of two, and every eq point in
+    /// `weight` must have `v` coordinates.
+    pub fn new(f_evals: Vec<F>, weight: &StructuredWeight<F>) -> Self {
+        assert!(
+            f_evals.len().is_power_of_two(),
+            "f_evals length must be a power of two"
+        );
+        let v = f_evals.len().trailing_zeros() as usize;
+        let v_l = v / 2;
+        let terms = weight
+            .factored_terms(v)
+            .into_iter()
+            .map(|(coeff, factors)| {
+                let (l, r) = factors.split_at(v_l);
+                SplitTerm {
+                    coeff,
+                    left: product_table(l),
+                    right: product_table(r),
+                }
+            })
+            .collect();
+        Self {
+            f: f_evals,
+            terms,
+            v_l,
+            v,
+            rounds_elapsed: 0,
+        }
+    }
+
+    /// After full sumcheck: `(f(r), w(r))`.
+    pub fn final_factors(&self) -> (F, F) {
+        if self.f.len() == 1 {
+            (self.f[0], self.weight_value())
+        } else {
+            (F::ZERO, F::ZERO)
+        }
+    }
+
+    /// `w(r)` once all tables are scalars.
+    fn weight_value(&self) -> F {
+        self.terms
+            .iter()
+            .map(|t| t.coeff * t.left[0] * t.right[0])
+            .sum()
+    }
+
+    fn fold(&mut self, r: F, var: usize) {
+        ip::fold(&mut self.f, r);
+        for term in &mut self.terms {
+            if var < self.v_l {
+                ip::fold(&mut term.left, r);
+            } else {
+                ip::fold(&mut term.right, r);
+            }
+        }
+    }
+}
+
+impl<F> SumcheckProver<F> for WeightedProver<F>
+where
+    F: SumcheckField,
+{
+    fn degree(&self) -> usize {
+        2
+    }
+
+    fn round(&mut self, challenge: Option<F>) -> Vec<F> {
+        if let Some(r) = challenge {
+            self.fold(r, self.rounds_elapsed - 1);
+        }
+
+        let left_phase = self.rounds_elapsed < self.v_l;
+        let mut q0 = F::ZERO;
+        let mut q_inf = F::ZERO;
+        for term in &self.terms {
+            let (t0, t_inf, scale) = if left_phase {
+                let (t0, t_inf) = split_round_left(&self.f, &term.left, &term.right);
+                (t0, t_inf, term.coeff)
+            } else {
+                let (t0, t_inf) = split_round_right(&self.f, &term.right);
+                (t0, t_inf, term.coeff * term.left[0])
+            };
+            q0 += scale * t0;
+            q_inf += scale * t_inf;
+        }
+        self.rounds_elapsed += 1;
+        vec![q0, q_inf]
+    }
+
+    fn finalize(&mut self, last_challenge: F) {
+        self.fold(last_challenge, self.v - 1);
+    }
+
+    impl_table_len!(f);
+
+    fn final_value(&self) -> F {
+        if self.f.len() == 1 {
+            self.f[0] * self.weight_value()
+        } else {
+            F::ZERO
+        }
+    }
+}
+
+#[cfg(all(test, feature = "arkworks"))]
+mod tests {
+    use super::*;
+    use crate::polynomial::multilinear::mle_eval;
+    use crate::provers::inner_product::InnerProductProver;
+    use crate::runner::sumcheck;
+    use crate::tests::{ReplayTranscript, F64};
+    use crate::verifier::sumcheck_verify;
+    use ark_ff::UniformRand;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    fn random_weight(v: usize, eqs: usize, pows: usize, rng: &mut StdRng) -> StructuredWeight<F64> {
+        let mut w = StructuredWeight::new();
+        for _ in 0..eqs {
+            let z = (0..v).map(|_| F64::rand(rng)).collect();
+            w.add_eq(F64::rand(rng), z);
+        }
+        for _ in 0..pows {
+            w.add_pow(F64::rand(rng), F64::rand(rng));
+        }
+        w
+    }
+
+    /// Same transcript as `InnerProductProver` on the materialized weight.
+    #[test]
+    fn matches_inner_product_on_materialized_weight() {
+        let mut rng = StdRng::seed_from_u64(0xB10);
+        for v in 0..=7 {
+            let f: Vec<F64> = (0..1 << v).map(|_| F64::rand(&mut rng)).collect();
+            let weight = random_weight(v, 3, 2, &mut rng);
+
+            let mut prover = WeightedProver::new(f.clone(), &weight);
+            let mut t = ReplayTranscript::new(0xB11);
+            let proof = sumcheck(&mut prover, v, &mut t, |_, _| {});
+
+            let mut reference = InnerProductProver::new(f.clone(), weight.to_evals(v));
+            let mut t_ref = ReplayTranscript::new(0xB11);
+            let expected = sumcheck(&mut reference, v, &mut t_ref, |_, _| {});
+
+            assert_eq!(proof.round_polys, expected.round_polys, "v={v}");
+            assert_eq!(proof.final_value, expected.final_value, "v={v}");
+        }
+    }
+
+    #[test]
+    fn verifier_checks_with_structured_evaluation() {
+        let mut rng = StdRng::seed_from_u64(0xB12);
+        let v = 6;
+        let f: Vec<F64> = (0..1 << v).map(|_| F64::rand(&mut rng)).collect();
+        let weight = random_weight(v, 4, 1, &mut rng);
+        let claim: F64 = f.iter().zip(weight.to_evals(v)).map(|(&a, b)| a * b).sum();
+
+        let mut prover = WeightedProver::new(f.clone(), &weight);
+        let mut t = ReplayTranscript::new(0xB13);
+        sumcheck(&mut prover, v, &mut t, |_, _| {});
+
+        let mut vt = t.replay();
+        let result = sumcheck_verify(claim, 2, v, &mut vt, |_, _| Ok(())).unwrap();
+        let (f_r, w_r) = prover.final_factors();
+        assert_eq!(f_r, mle_eval(&f, &result.challenges));
+        assert_eq!(w_r, weight.evaluate(&result.challenges));
+        assert_eq!(
+            result.final_claim,
+            f_r * weight.evaluate(&result.challenges)
+        );
+    }
+}
@@ -0,0 +1,398 @@
+//! Reduce-to-one sub-protocols for GKR (Thaler §4.5.2).
+//!
+//! After a [`GkrProver`](crate::provers::gkr::GkrProver) layer sumcheck
+//! the verifier holds two claims about the next layer's witness:
+//!
+//! ```text
+//! W(b*) = v0,    W(c*) = v1
+//! ```
+//!
+//! Two standard ways to merge them into a single claim are provided.
+//!
+//! # Line restriction (Claim 4.6)
+//!
+//! Let `ℓ(t) = b* + t·(c* − b*)` be the line with `ℓ(0) = b*`, `ℓ(1) = c*`.
+//! The prover sends `q(t) = W(ℓ(t))`, a univariate of degree `≤ k`, as its
+//! evaluations at `{0, 1, ..., k}`. The verifier checks `q(0) = v0`,
+//! `q(1) = v1`, squeezes `r` and continues with the single claim
+//! `W(ℓ(r)) = q(r)`.
+//!
+//! ```ignore
+//! // prover
+//! let (point, value) = reduce_to_one_prove(&w_evals, &b, &c, &mut pt);
+//! // verifier
+//! let (point, value) = reduce_to_one_verify(&b, &c, v0, v1, &mut vt)?;
+//! ```
+//!
+//! # Random linear combination
+//!
+//! The verifier squeezes `α, β` and continues with the claim `α·v0 + β·v1`.
+//! No extra prover message is needed; instead the next layer's gate
+//! predicates are combined under the weight `α·eq(b*, z) + β·eq(c*, z)`,
+//! so the next [`GkrProver`](crate::provers::gkr::GkrProver) proves
+//!
+//! ```text
+//! α·v0 + β·v1 = Σ_{b,c} add'(b, c)·(W'(b) + W'(c)) + mult'(b, c)·W'(b)·W'(c)
+//! ```
+//!
+//! with `add'(b, c) = Σ_z (α·eq(b*, z) + β·eq(c*, z))·add(z, b, c)` (and
+//! likewise for `mult'`). See [`RandomCombination`].
+//!
+//! # Transcript binding
+//!
+//! Both helpers assume `v0` and `v1` are already bound to the transcript
+//! (e.g. sent by the caller after the layer sumcheck). The line variant
+//! additionally sends all of `q(0..=k)`.
+//!
+//! All points use the MSB variable order of the canonical provers:
+//! `point[0]` binds the most significant bit of a table index.
+
+use ark_ff::Field;
+
+use crate::field::SumcheckField;
+use crate::hypercube::eq_poly_non_binary;
+use crate::polynomial::eval_from_evals;
+use crate::polynomial::multilinear::mle_eval;
+use crate::proof::SumcheckError;
+use crate::provers::eq_factored::build_eq_table;
+use crate::transcript::{ProverTranscript, VerifierTranscript};
+
+use alloc::{vec, vec::Vec};
+
+// ─── Line restriction ──────────────────────────────────────────────────────
+
+/// The point `ℓ(t) = b + t·(c − b)` on the line through `b` and `c`.
+pub fn line_point<F: Field>(b: &[F], c: &[F], t: F) -> Vec<F> {
+    assert_eq!(b.len(), c.len(), "b and c must have the same length");
+    b.iter()
+        .zip(c)
+        .map(|(&bi, &ci)| bi + t * (ci - bi))
+        .collect()
+}
+
+/// Evaluations of `q(t) = W(ℓ(t))` at the nodes `t ∈ {0, 1, ..., k}`
+/// ([`SumcheckField::eval_node`]).
+///
+/// `w_evals` holds `W` over `{0,1}^k` in MSB order; `b` and `c` are points
+/// in `F^k`. `q` has degree `≤ k`, so `k + 1` evaluations determine it.
+///
+/// Cost: `O(k · 2^k)`.
+pub fn restrict_to_line<F: Field>(w_evals: &[F], b: &[F], c: &[F]) -> Vec<F> {
+    let k = b.len();
+    assert_eq!(w_evals.len(), 1 << k, "w_evals must have len 2^k");
+    (0..=k)
+        .map(|t| mle_eval(w_evals, &line_point(b, c, F::eval_node(t))))
+        .collect()
+}
+
+/// Prover side of the line-restriction reduction.
+///
+/// Sends `q(0), ..., q(k)`, squeezes `r` and returns the new claim
+/// `(ℓ(r), W(ℓ(r)))`.
+pub fn reduce_to_one_prove<F: Field, T: ProverTranscript<F>>(
+    w_evals: &[F],
+    b: &[F],
+    c: &[F],
+    transcript: &mut T,
+) -> (Vec<F>, F) {
+    let q = restrict_to_line(w_evals, b, c);
+    for &v in &q {
+        transcript.send(v);
+    }
+    let r = transcript.challenge();
+    (line_point(b, c, r), eval_from_evals(&q, r))
+}
+
+/// Verifier side of the line-restriction reduction.
+///
+/// Reads `q(0), ..., q(k)` with `k = b.len()`, checks `q(0) = v0` and
+/// `q(1) = v1`, squeezes `r` and returns the new claim `(ℓ(r), q(r))`.
+/// The caller must check that claim against `W`, typically by running
+/// the next layer's sumcheck on it.
+///
+/// The line needs both endpoints on the wire, so `b` must be non-empty;
+/// a message with fewer than two values is a `DegreeMismatch`. Errors are
+/// reported as round 0 of the sub-protocol.
+pub fn reduce_to_one_verify<F: Field, T: VerifierTranscript<F>>(
+    b: &[F],
+    c: &[F],
+    v0: F,
+    v1: F,
+    transcript: &mut T,
+) -> Result<(Vec<F>, F), SumcheckError> {
+    assert_eq!(b.len(), c.len(), "b and c must have the same length");
+    let k = b.len();
+    let mut q = Vec::with_capacity(k + 1);
+    for _ in 0..=k {
+        let v = transcript
+            .receive()
+            .map_err(|_| SumcheckError::TranscriptError { round: 0 })?;
+        q.push(v);
+    }
+    let (q0, q1) = match q[..] {
+        [q0, q1, ..] => (q0, q1),
+        _ => {
+            return Err(SumcheckError::DegreeMismatch {
+                round: 0,
+                expected: 2,
+                got: q.len(),
+            })
+        }
+    };
+    if q0 != v0 || q1 != v1 {
+        return Err(SumcheckError::ConsistencyCheck { round: 0 });
+    }
+    let r = transcript.challenge();
+    Ok((line_point(b, c, r), eval_from_evals(&q, r)))
+}
+
+// ─── Random linear combination ─────────────────────────────────────────────
+
+/// Coefficients `(α, β)` of the random-combination reduction.
+///
+/// Squeeze with [`prover_challenge`](Self::prover_challenge) or
+/// [`verifier_challenge`](Self::verifier_challenge) (both read `α` then
+/// `β`), then combine claims with [`combine`](Self::combine) and gate
+/// predicates with [`combine_predicate`](Self::combine_predicate).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RandomCombination<F> {
+    pub alpha: F,
+    pub beta: F,
+}
+
+impl<F: Field> RandomCombination<F> {
+    /// Squeeze `(α, β)` from a prover transcript.
+    pub fn prover_challenge<T: ProverTranscript<F>>(transcript: &mut T) -> Self {
+        let alpha = transcript.challenge();
+        let beta = transcript.challenge();
+        Self { alpha, beta }
+    }
+
+    /// Squeeze `(α, β)` from a verifier transcript.
+    pub fn verifier_challenge<T: VerifierTranscript<F>>(transcript: &mut T) -> Self {
+        let alpha = transcript.challenge();
+        let beta = transcript.challenge();
+        Self { alpha, beta }
+    }
+
+    /// The combined claim `α·v0 + β·v1`.
+    pub fn combine(&self, v0: F, v1: F) -> F {
+        self.alpha * v0 + self.beta * v1
+    }
+
+    /// The weight `α·eq(b, z) + β·eq(c, z)` at an arbitrary point `z`.
+    ///
+    /// Verifier-side counterpart of [`combine_predicate`](Self::combine_predicate)
+    /// for wiring predicates the verifier evaluates itself.
+    pub fn weight_at(&self, b: &[F], c: &[F], z: &[F]) -> F {
+        self.alpha * eq_poly_non_binary(b, z) + self.beta * eq_poly_non_binary(c, z)
+    }
+
+    /// The weight table `α·eq(b, z) + β·eq(c, z)` over `z ∈ {0,1}^k` (MSB order).
+    pub fn weight_table(&self, b: &[F], c: &[F]) -> Vec<F> {
+        assert_eq!(b.len(), c.len(), "b and c must have the same length");
+        let eq_b = build_eq_table(b);
+        let eq_c = build_eq_table(c);
+        eq_b.iter()
+            .zip(&eq_c)
+            .map(|(&x, &y)| self.alpha * x + self.beta * y)
+            .collect()
+    }
+
+    /// Combine a gate predicate into the next layer's prover table.
+    ///
+    /// `table` holds `pred(z, b, c)` over `{0,1}^{k + 2k'}`, indexed
+    /// `z · 2^{2k'} + b · 2^{k'} + c` with `z ∈ {0,1}^k` the output
+    /// variables. Returns `Σ_z (α·eq(b*, z) + β·eq(c*, z))·pred(z, b, c)`
+    /// over `(b, c)`, ready for [`GkrProver::new`](crate::provers::gkr::GkrProver::new).
+    pub fn combine_predicate(&self, b_star: &[F], c_star: &[F], table: &[F]) -> Vec<F> {
+        let weights = self.weight_table(b_star, c_star);
+        assert_eq!(
+            table.len() % weights.len(),
+            0,
+            "table length must be a multiple of 2^k"
+        );
+        let n_bc = table.len() / weights.len();
+        let mut out = vec![F::ZERO; n_bc];
+        for (chunk, &wz) in table.chunks_exact(n_bc).zip(&weights) {
+            for (o, &t) in out.iter_mut().zip(chunk) {
+                *o += wz * t;
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provers::gkr::GkrProver;
+    use crate::runner::sumcheck;
+    use crate::tests::{ReplayTranscript, F64};
+    use crate::transcript::SanityTranscript;
+    use crate::verifier::sumcheck_verify;
+    use ark_ff::UniformRand;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    fn random_vec(rng: &mut StdRng, n: usize) -> Vec<F64> {
+        (0..n).map(|_| F64::rand(rng)).collect()
+    }
+
+    #[test]
+    fn line_restriction_endpoints_and_degree() {
+        let mut rng = StdRng::seed_from_u64(0x401);
+        let k = 4;
+        let w = random_vec(&mut rng, 1 << k);
+        let b = random_vec(&mut rng, k);
+        let c = random_vec(&mut rng, k);
+
+        let q = restrict_to_line(&w, &b, &c);
+        assert_eq!(q.len(), k + 1);
+        assert_eq!(q[0], mle_eval(&w, &b));
+        assert_eq!(q[1], mle_eval(&w, &c));
+
+        // k + 1 evaluations determine q everywhere on the line.
+        let t = F64::rand(&mut rng);
+        assert_eq!(eval_from_evals(&q, t), mle_eval(&w, &line_point(&b, &c, t)));
+    }
+
+    #[test]
+    fn line_restriction_roundtrip_after_gkr_layer() {
+        let mut rng = StdRng::seed_from_u64(0x402);
+        let k = 3;
+        let n = 1 << k;
+        let add = random_vec(&mut rng, n * n);
+        let mult = random_vec(&mut rng, n * n);
+        let w = random_vec(&mut rng, n);
+
+        let mut prover = GkrProver::new(add, mult, w.clone());
+        let mut trng = StdRng::seed_from_u64(7);
+        let mut t = SanityTranscript::new(&mut trng);
+        let proof = sumcheck(&mut prover, 2 * k, &mut t, |_, _| {});
+        let (v0, v1) = prover.claimed_w_values();
+        let (b, c) = proof.challenges.split_at(k);
+
+        let mut pt = ReplayTranscript::new(0x403);
+        let (p_point, p_value) = reduce_to_one_prove(&w, b, c, &mut pt);
+
+        let mut vt = pt.replay();
+        let (v_point, v_value) = reduce_to_one_verify(b, c, v0, v1, &mut vt).unwrap();
+        assert_eq!(p_point, v_point);
+        assert_eq!(p_value, v_value);
+        assert_eq!(v_value, mle_eval(&w, &v_point));
+    }
+
+    #[test]
+    fn line_restriction_rejects_wrong_claim() {
+        let mut rng = StdRng::seed_from_u64(0x404);
+        let k = 3;
+        let w = random_vec(&mut rng, 1 << k);
+        let b = random_vec(&mut rng, k);
+        let c = random_vec(&mut rng, k);
+        let v0 = mle_eval(&w, &b);
+        let v1 = mle_eval(&w, &c);
+
+        let mut pt = ReplayTranscript::new(0x405);
+        reduce_to_one_prove(&w, &b, &c, &mut pt);
+
+        let mut vt = pt.replay();
+        let bad = reduce_to_one_verify(&b, &c, v0, v1 + F64::from(1u64), &mut vt);
+        assert_eq!(bad, Err(SumcheckError::ConsistencyCheck { round: 0 }));
+
+        let mut short = ReplayTranscript::new(0);
+        let missing = reduce_to_one_verify(&b, &c, v0, v1, &mut short);
+        assert_eq!(missing, Err(SumcheckError::TranscriptError { round: 0 }));
+
+        // A zero-variable line carries a single value, too short to bind
+        // both endpoints.
+        let mut pt = ReplayTranscript::new(0x406);
+        reduce_to_one_prove(&w[..1], &[], &[], &mut pt);
+        let mut vt = pt.replay();
+        let short_line = reduce_to_one_verify(&[], &[], w[0], w[0], &mut vt);
+        assert_eq!(
+            short_line,
+            Err(SumcheckError::DegreeMismatch {
+                round: 0,
+                expected: 2,
+                got: 1
+            })
+        );
+    }
+
+    /// Two GKR layers chained through the random-combination reduction:
+    /// layer i's claims on `W` become layer i+1's sumcheck claim.
+    #[test]
+    fn random_combination_feeds_next_layer() {
+        let mut rng = StdRng::seed_from_u64(0x406);
+        let k = 2; // layer i: W has k variables
+        let k_next = 3; // layer i+1: W' has k_next variables
+        let n = 1 << k;
+        let n_next = 1 << k_next;
+
+        // Layer-(i+1) gate predicates pred(z, b, c), z ∈ {0,1}^k.
+        let add = random_vec(&mut rng, n * n_next * n_next);
+        let mult = random_vec(&mut rng, n * n_next * n_next);
+        let w_next = random_vec(&mut rng, n_next);
+
+        // W(z) = Σ_{b,c} add(z,b,c)·(W'(b)+W'(c)) + mult(z,b,c)·W'(b)·W'(c).
+        let w: Vec<F64> = (0..n)
+            .map(|z| {
+                let mut acc = F64::from(0u64);
+                for b in 0..n_next {
+                    for c in 0..n_next {
+                        let idx = z * n_next * n_next + b * n_next + c;
+                        let (wb, wc) = (w_next[b], w_next[c]);
+                        acc += add[idx] * (wb + wc) + mult[idx] * wb * wc;
+                    }
+                }
+                acc
+            })
+            .collect();
+
+        let b_star = random_vec(&mut rng, k);
+        let c_star = random_vec(&mut rng, k);
+        let v0 = mle_eval(&w, &b_star);
+        let v1 = mle_eval(&w, &c_star);
+
+        // Prover: squeeze (α, β), combine predicates, run the next layer.
+        let mut pt = ReplayTranscript::new(0x407);
+        let rc = RandomCombination::prover_challenge(&mut pt);
+        let add_next = rc.combine_predicate(&b_star, &c_star, &add);
+        let mult_next = rc.combine_predicate(&b_star, &c_star, &mult);
+        let mut prover = GkrProver::new(add_next, mult_next, w_next.clone());
+        let proof = sumcheck(&mut prover, 2 * k_next, &mut pt, |_, _| {});
+        let (wb, wc) = prover.claimed_w_values();
+
+        // Verifier: same (α, β), combined claim, layer sumcheck.
+        let mut vt = pt.replay();
+        let rv = RandomCombination::verifier_challenge(&mut vt);
+        assert_eq!(rc, rv);
+        let claim = rv.combine(v0, v1);
+        let result = sumcheck_verify(claim, 2, 2 * k_next, &mut vt, |_, _| Ok(())).unwrap();
+        assert_eq!(result.challenges, proof.challenges);
+
+        // Oracle check: evaluate the combined predicates at (r_b, r_c)
+        // through the verifier-side weight.
+        let (r_b, r_c) = result.challenges.split_at(k_next);
+        let bits = |i: usize, len: usize| -> Vec<F64> {
+            (0..len)
+                .rev()
+                .map(|j| F64::from(((i >> j) & 1) as u64))
+                .collect()
+        };
+        let mut add_r = F64::from(0u64);
+        let mut mult_r = F64::from(0u64);
+        for z in 0..n {
+            let weight = rv.weight_at(&b_star, &c_star, &bits(z, k));
+            let start = z * n_next * n_next;
+            let end = start + n_next * n_next;
+            let mut point = r_b.to_vec();
+            point.extend_from_slice(r_c);
+            add_r += weight * mle_eval(&add[start..end], &point);
+            mult_r += weight * mle_eval(&mult[start..end], &point);
+        }
+        assert_eq!(wb, mle_eval(&w_next, r_b));
+        assert_eq!(wc, mle_eval(&w_next, r_c));
+        assert_eq!(result.final_claim, add_r * (wb + wc) + mult_r * wb * wc);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::field::SumcheckField;
+use crate::field::{ExtensionOf, SumcheckField};
 use ark_ff::Field;
 use ark_std::vec::Vec;
 use ark_std::{cfg_chunks, cfg_into_iter};
@@ -112,16 +112,13 @@ pub fn pairwise_product_evaluate<F: SumcheckField>(src: &[Vec<F>]) -> (F, F) {
 
 /// Cross-field reduce: fold `BF` evaluations with an `EF` challenge, producing `Vec<EF>`.
 ///
-/// For each adjacent pair `(a, b)` in `src`: `EF::from(a) + challenge * (EF::from(b) - EF::from(a))`.
-pub fn cross_field_reduce<BF: SumcheckField, EF: SumcheckField + From<BF>>(
+/// For each adjacent pair `(a, b)` in `src`: `a + challenge * (b - a)`, with
+/// the difference taken in `BF` before lifting.
+pub fn cross_field_reduce<BF: SumcheckField, EF: ExtensionOf<BF>>(
     src: &[BF],
     challenge: EF,
 ) -> Vec<EF> {
     cfg_chunks!(src, 2)
-        .map(|chunk| {
-            let a = EF::from(chunk[0]);
-            let b = EF::from(chunk[1]);
-            a + challenge * (b - a)
-        })
+        .map(|chunk| EF::from_base(chunk[0]) + challenge * EF::from_base(chunk[1] - chunk[0]))
         .collect()
 }
@@ -8,13 +8,16 @@
 //! Partial execution (`num_rounds < v`) supports composed protocols like
 //! GKR (one sumcheck per layer) and WHIR (partial rounds interleaved with
 //! commit/open).
+//!
+//! The loop drives a [`ProverSession`]; use the session directly when
+//! messages travel over a network or other subprotocols interleave.
 
 extern crate alloc;
 use crate::field::SumcheckField;
 use crate::proof::SumcheckProof;
+use crate::session::{ProverRoundContext, ProverSession};
 use crate::sumcheck_prover::SumcheckProver;
 use crate::transcript::ProverTranscript;
-use alloc::vec::Vec;
 
 /// Run the sum-check protocol for `num_rounds` rounds.
 ///
@@ -32,38 +35,52 @@ pub fn sumcheck<F: SumcheckField, T: ProverTranscript<F>>(
     transcript: &mut T,
     mut hook: impl FnMut(usize, &mut T),
 ) -> SumcheckProof<F> {
-    let mut round_polys: Vec<Vec<F>> = Vec::with_capacity(num_rounds);
-    let mut challenges: Vec<F> = Vec::with_capacity(num_rounds);
-    let mut prev_challenge: Option<F> = None;
-
+    let mut session = ProverSession::new(prover, num_rounds);
     for round in 0..num_rounds {
-        let evals = prover.round(prev_challenge);
-
         // Send evaluations to transcript.
-        for &v in &evals {
+        for &v in session.next_message() {
             transcript.send(v);
         }
-        round_polys.push(evals);
 
         // Per-round hook (e.g., proof-of-work grinding for WHIR).
         hook(round, transcript);
 
         // Squeeze verifier challenge.
-        let r = transcript.challenge();
-        challenges.push(r);
-        prev_challenge = Some(r);
+        session.receive_challenge(transcript.challenge());
     }
 
-    // Apply the final challenge so final_value() is correct.
-    if let Some(r) = prev_challenge {
-        prover.finalize(r);
-    }
-
-    let final_value = prover.final_value();
+    // Applies the final challenge so final_value is correct.
+    session.finish()
+}
 
-    SumcheckProof {
-        round_polys,
-        challenges,
-        final_value,
+/// [`sumcheck`] with a hook that sees the round's context.
+///
+/// `hook` receives a [`ProverRoundContext`] — the round polynomial just
+/// sent, the current claim (tracked from `claimed_sum`) and the prover's
+/// remaining [`table_len`](SumcheckProver::table_len) — along with the
+/// transcript, after the message is written and before the challenge is
+/// read. Useful for grinding, logging and custom absorbs.
+///
+/// ```ignore
+/// let proof = sumcheck_with_context(&mut prover, sum, n, &mut t, |ctx, t| {
+///     log::debug!("round {}: {} entries left", ctx.round, ctx.table_len.unwrap_or(0));
+///     grind(t, ctx.message);
+/// });
+/// ```
+pub fn sumcheck_with_context<F: SumcheckField, T: ProverTranscript<F>>(
+    prover: &mut impl SumcheckProver<F>,
+    claimed_sum: F,
+    num_rounds: usize,
+    transcript: &mut T,
+    mut hook: impl FnMut(&ProverRoundContext<'_, F>, &mut T),
+) -> SumcheckProof<F> {
+    let mut session = ProverSession::new(prover, num_rounds).with_claim(claimed_sum);
+    for _ in 0..num_rounds {
+        for &v in session.next_message() {
+            transcript.send(v);
+        }
+        hook(&session.context().expect("message sent"), transcript);
+        session.receive_challenge(transcript.challenge());
     }
+    session.finish()
 }
@@ -0,0 +1,787 @@
+//! Binary and serde encodings of [`SumcheckProof`], [`CompactProof`] and
+//! [`SumcheckResult`].
+//!
+//! With the `arkworks` feature these types implement `CanonicalSerialize`
+//! and `CanonicalDeserialize` for `F: CanonicalSerialize` /
+//! `CanonicalDeserialize`; with the `serde` feature they implement
+//! `Serialize` and `Deserialize` for `F: Serialize` / `Deserialize` (with
+//! the same fields). Arkworks fields do not implement serde; use
+//! ark-serialize's `CompressedChecked` wrappers (its `serde` feature) on
+//! the canonical encoding instead.
+//!
+//! # Format (version 1)
+//!
+//! `SumcheckProof`:
+//!
+//! | Field | Encoding |
+//! |---|---|
+//! | version | `u8` = [`FORMAT_VERSION`] |
+//! | num_rounds | `u64` (little-endian) |
+//! | width | `u64`: values per round (`max(d, 1)`) when all rounds agree, zero when the widths vary or `num_rounds` is zero |
+//! | widths | only if `width` is zero: `num_rounds` `u64`s, the width of each round |
+//! | round_polys | field elements, round by round |
+//! | challenges | `num_rounds` field elements |
+//! | final_value | one field element |
+//!
+//! A constant-degree proof thus carries a single width; a proof with
+//! per-round degrees (e.g. a univariate-skip first round) lists them.
+//!
+//! `CompactProof`: version, the `u64` number of wire values, then the
+//! values. The per-round widths come from the verifier's degree schedule,
+//! so the shape is checked when the proof is rebuilt
+//! ([`CompactProof::to_proof`]).
+//!
+//! `SumcheckResult`: version, num_rounds, `num_rounds` challenges, then
+//! `final_claim`. Field elements use `F`'s own encoding in the requested
+//! compression mode.
+//!
+//! Decoding rejects an unknown version, an inconsistent `num_rounds` /
+//! `width` pair, empty rounds and truncated input, and with
+//! `Validate::Yes` invalid field elements, so a malformed proof never
+//! reaches the verifier. Encoding fails with `InvalidData` for a proof
+//! with an empty round or a challenge count that differs from its round
+//! count.
+//!
+//! [`ProofSize`] breaks an encoding down by component, e.g. to compare a
+//! full proof with its compact form.
+//!
+//! ```ignore
+//! let mut bytes = Vec::new();
+//! proof.serialize_compressed(&mut bytes)?;
+//! let proof = SumcheckProof::<F>::deserialize_compressed(&bytes[..])?;
+//! let result = verify_proof(&proof, claimed_sum, degree, &mut transcript)?;
+//! ```
+
+use crate::field::SumcheckField;
+use crate::proof::SumcheckProof;
+#[cfg(any(feature = "arkworks", feature = "serde"))]
+use crate::{proof::CompactProof, verifier::SumcheckResult};
+#[cfg(any(feature = "arkworks", feature = "serde"))]
+use alloc::vec::Vec;
+
+/// Current encoding version, written first.
+pub const FORMAT_VERSION: u8 = 1;
+
+/// Encoded size of a proof, in bytes, by component.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProofSize {
+    /// Version byte and length prefixes.
+    pub header: usize,
+    /// Round polynomial values (the wire values).
+    pub round_polys: usize,
+    /// Stored challenges (zero for a [`CompactProof`]).
+    pub challenges: usize,
+    /// Final value (zero for a [`CompactProof`]).
+    pub final_value: usize,
+}
+
+impl ProofSize {
+    /// Size of the whole encoding.
+    pub fn total(&self) -> usize {
+        self.header + self.round_polys + self.challenges + self.final_value
+    }
+}
+
+/// `(num_rounds, width)` of `proof`, where `width` is the common number
+/// of values per round (zero if the rounds differ or there are none), or
+/// why it has no encoding.
+#[cfg_attr(not(any(feature = "arkworks", feature = "serde")), allow(dead_code))]
+fn proof_shape<F: SumcheckField>(proof: &SumcheckProof<F>) -> Result<(usize, usize), &'static str> {
+    let num_rounds = proof.round_polys.len();
+    if proof.round_polys.iter().any(|poly| poly.is_empty()) {
+        return Err("empty round polynomial");
+    }
+    if proof.challenges.len() != num_rounds {
+        return Err("challenge count differs from round count");
+    }
+    let width = proof.round_polys.first().map_or(0, |poly| poly.len());
+    if proof.round_polys.iter().any(|poly| poly.len() != width) {
+        return Ok((num_rounds, 0));
+    }
+    Ok((num_rounds, width))
+}
+
+#[cfg(feature = "arkworks")]
+mod canonical {
+    use super::*;
+    use ark_serialize::{
+        CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid,
+        Validate, Write,
+    };
+
+    /// Version byte plus the two `u64` length prefixes.
+    const PROOF_HEADER_LEN: usize = 1 + 8 + 8;
+    /// Version byte plus one `u64` length.
+    const RESULT_HEADER_LEN: usize = 1 + 8;
+    const COMPACT_HEADER_LEN: usize = 1 + 8;
+
+    fn elems_size<'a, F: CanonicalSerialize + 'a>(
+        elems: impl IntoIterator<Item = &'a F>,
+        compress: Compress,
+    ) -> usize {
+        elems.into_iter().map(|v| v.serialized_size(compress)).sum()
+    }
+
+    impl<F: SumcheckField + CanonicalSerialize> SumcheckProof<F> {
+        /// Size of the canonical encoding, by component.
+        ///
+        /// The header includes the per-round widths of a proof whose
+        /// rounds differ in length.
+        pub fn size(&self, compress: Compress) -> ProofSize {
+            let widths = match proof_shape(self) {
+                Ok((_, 0)) => 8 * self.round_polys.len(),
+                _ => 0,
+            };
+            ProofSize {
+                header: PROOF_HEADER_LEN + widths,
+                round_polys: elems_size(self.round_polys.iter().flatten(), compress),
+                challenges: elems_size(&self.challenges, compress),
+                final_value: self.final_value.serialized_size(compress),
+            }
+        }
+    }
+
+    impl<F: SumcheckField + CanonicalSerialize> CompactProof<F> {
+        /// Size of the canonical encoding, by component.
+        pub fn size(&self, compress: Compress) -> ProofSize {
+            ProofSize {
+                header: COMPACT_HEADER_LEN,
+                round_polys: elems_size(&self.wire, compress),
+                ..ProofSize::default()
+            }
+        }
+    }
+
+    fn read_version<R: Read>(reader: &mut R) -> Result<(), SerializationError> {
+        match u8::deserialize_with_mode(reader, Compress::No, Validate::No)? {
+            FORMAT_VERSION => Ok(()),
+            _ => Err(SerializationError::InvalidData),
+        }
+    }
+
+    fn read_len<R: Read>(reader: &mut R) -> Result<usize, SerializationError> {
+        let len = u64::deserialize_with_mode(reader, Compress::No, Validate::No)?;
+        usize::try_from(len).map_err(|_| SerializationError::InvalidData)
+    }
+
+    /// `n` field elements. The length is untrusted, so the vector grows
+    /// as elements arrive instead of being preallocated.
+    fn read_elems<F: CanonicalDeserialize, R: Read>(
+        reader: &mut R,
+        n: usize,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Vec<F>, SerializationError> {
+        let mut elems = Vec::new();
+        for _ in 0..n {
+            elems.push(F::deserialize_with_mode(&mut *reader, compress, validate)?);
+        }
+        Ok(elems)
+    }
+
+    impl<F: SumcheckField + CanonicalSerialize> CanonicalSerialize for SumcheckProof<F> {
+        fn serialize_with_mode<W: Write>(
+            &self,
+            mut writer: W,
+            compress: Compress,
+        ) -> Result<(), SerializationError> {
+            let (num_rounds, width) =
+                proof_shape(self).map_err(|_| SerializationError::InvalidData)?;
+            FORMAT_VERSION.serialize_with_mode(&mut writer, compress)?;
+            (num_rounds as u64).serialize_with_mode(&mut writer, compress)?;
+            (width as u64).serialize_with_mode(&mut writer, compress)?;
+            if width == 0 {
+                for poly in &self.round_polys {
+                    (poly.len() as u64).serialize_with_mode(&mut writer, compress)?;
+                }
+            }
+            for v in self.round_polys.iter().flatten().chain(&self.challenges) {
+                v.serialize_with_mode(&mut writer, compress)?;
+            }
+            self.final_value.serialize_with_mode(&mut writer, compress)
+        }
+
+        fn serialized_size(&self, compress: Compress) -> usize {
+            self.size(compress).total()
+        }
+    }
+
+    impl<F: SumcheckField + Valid> Valid for SumcheckProof<F> {
+        fn check(&self) -> Result<(), SerializationError> {
+            proof_shape(self).map_err(|_| SerializationError::InvalidData)?;
+            let elems = self.round_polys.iter().flatten().chain(&self.challenges);
+            F::batch_check(elems.chain(core::iter::once(&self.final_value)))
+        }
+    }
+
+    impl<F: SumcheckField + CanonicalDeserialize> CanonicalDeserialize for SumcheckProof<F> {
+        fn deserialize_with_mode<R: Read>(
+            mut reader: R,
+            compress: Compress,
+            validate: Validate,
+        ) -> Result<Self, SerializationError> {
+            read_version(&mut reader)?;
+            let num_rounds = read_len(&mut reader)?;
+            let width = read_len(&mut reader)?;
+            if num_rounds == 0 && width != 0 {
+                return Err(SerializationError::InvalidData);
+            }
+            let mut widths = Vec::new();
+            for _ in 0..num_rounds {
+                widths.push(match width {
+                    0 => read_len(&mut reader)?,
+                    w => w,
+                });
+            }
+            // Listed widths must be non-empty and not all equal (that proof
+            // encodes with a single width).
+            if width == 0
+                && num_rounds != 0
+                && (widths.contains(&0) || widths.windows(2).all(|pair| pair[0] == pair[1]))
+            {
+                return Err(SerializationError::InvalidData);
+            }
+            let mut round_polys = Vec::new();
+            for w in widths {
+                round_polys.push(read_elems(&mut reader, w, compress, validate)?);
+            }
+            let challenges = read_elems(&mut reader, num_rounds, compress, validate)?;
+            let final_value = F::deserialize_with_mode(&mut reader, compress, validate)?;
+            Ok(Self {
+                round_polys,
+                challenges,
+                final_value,
+            })
+        }
+    }
+
+    impl<F: SumcheckField + CanonicalSerialize> CanonicalSerialize for CompactProof<F> {
+        fn serialize_with_mode<W: Write>(
+            &self,
+            mut writer: W,
+            compress: Compress,
+        ) -> Result<(), SerializationError> {
+            FORMAT_VERSION.serialize_with_mode(&mut writer, compress)?;
+            (self.wire.len() as u64).serialize_with_mode(&mut writer, compress)?;
+            for v in &self.wire {
+                v.serialize_with_mode(&mut writer, compress)?;
+            }
+            Ok(())
+        }
+
+        fn serialized_size(&self, compress: Compress) -> usize {
+            self.size(compress).total()
+        }
+    }
+
+    impl<F: SumcheckField + Valid> Valid for CompactProof<F> {
+        fn check(&self) -> Result<(), SerializationError> {
+            F::batch_check(self.wire.iter())
+        }
+    }
+
+    impl<F: SumcheckField + CanonicalDeserialize> CanonicalDeserialize for CompactProof<F> {
+        fn deserialize_with_mode<R: Read>(
+            mut reader: R,
+            compress: Compress,
+            validate: Validate,
+        ) -> Result<Self, SerializationError> {
+            read_version(&mut reader)?;
+            let len = read_len(&mut reader)?;
+            let wire = read_elems(&mut reader, len, compress, validate)?;
+            Ok(Self { wire })
+        }
+    }
+
+    impl<F: SumcheckField + CanonicalSerialize> CanonicalSerialize for SumcheckResult<F> {
+        fn serialize_with_mode<W: Write>(
+            &self,
+            mut writer: W,
+            compress: Compress,
+        ) -> Result<(), SerializationError> {
+            FORMAT_VERSION.serialize_with_mode(&mut writer, compress)?;
+            (self.challenges.len() as u64).serialize_with_mode(&mut writer, compress)?;
+            for v in &self.challenges {
+                v.serialize_with_mode(&mut writer, compress)?;
+            }
+            self.final_claim.serialize_with_mode(&mut writer, compress)
+        }
+
+        fn serialized_size(&self, compress: Compress) -> usize {
+            RESULT_HEADER_LEN
+                + elems_size(&self.challenges, compress)
+                + self.final_claim.serialized_size(compress)
+        }
+    }
+
+    impl<F: SumcheckField + Valid> Valid for SumcheckResult<F> {
+        fn check(&self) -> Result<(), SerializationError> {
+            F::batch_check(
+                self.challenges
+                    .iter()
+                    .chain(core::iter::once(&self.final_claim)),
+            )
+        }
+    }
+
+    impl<F: SumcheckField + CanonicalDeserialize> CanonicalDeserialize for SumcheckResult<F> {
+        fn deserialize_with_mode<R: Read>(
+            mut reader: R,
+            compress: Compress,
+            validate: Validate,
+        ) -> Result<Self, SerializationError> {
+            read_version(&mut reader)?;
+            let num_rounds = read_len(&mut reader)?;
+            let challenges = read_elems(&mut reader, num_rounds, compress, validate)?;
+            let final_claim = F::deserialize_with_mode(&mut reader, compress, validate)?;
+            Ok(Self {
+                challenges,
+                final_claim,
+            })
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::*;
+    use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize)]
+    #[serde(rename = "SumcheckProof")]
+    struct ProofRef<'a, F> {
+        version: u8,
+        num_rounds: u64,
+        width: u64,
+        round_polys: &'a [Vec<F>],
+        challenges: &'a [F],
+        final_value: &'a F,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "SumcheckProof", deny_unknown_fields)]
+    struct ProofOwned<F> {
+        version: u8,
+        num_rounds: u64,
+        width: u64,
+        round_polys: Vec<Vec<F>>,
+        challenges: Vec<F>,
+        final_value: F,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename = "CompactProof")]
+    struct CompactRef<'a, F> {
+        version: u8,
+        wire: &'a [F],
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "CompactProof", deny_unknown_fields)]
+    struct CompactOwned<F> {
+        version: u8,
+        wire: Vec<F>,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename = "SumcheckResult")]
+    struct ResultRef<'a, F> {
+        version: u8,
+        challenges: &'a [F],
+        final_claim: &'a F,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "SumcheckResult", deny_unknown_fields)]
+    struct ResultOwned<F> {
+        version: u8,
+        challenges: Vec<F>,
+        final_claim: F,
+    }
+
+    fn check_version<E: de::Error>(version: u8) -> Result<(), E> {
+        if version == FORMAT_VERSION {
+            Ok(())
+        } else {
+            Err(E::custom(format_args!(
+                "unsupported format version {version}"
+            )))
+        }
+    }
+
+    impl<F: SumcheckField + Serialize> Serialize for SumcheckProof<F> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let (num_rounds, width) = proof_shape(self).map_err(ser::Error::custom)?;
+            ProofRef {
+                version: FORMAT_VERSION,
+                num_rounds: num_rounds as u64,
+                width: width as u64,
+                round_polys: &self.round_polys,
+                challenges: &self.challenges,
+                final_value: &self.final_value,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, F: SumcheckField + Deserialize<'de>> Deserialize<'de> for SumcheckProof<F> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let repr = ProofOwned::<F>::deserialize(deserializer)?;
+            check_version(repr.version)?;
+            let proof = SumcheckProof {
+                round_polys: repr.round_polys,
+                challenges: repr.challenges,
+                final_value: repr.final_value,
+            };
+            let (num_rounds, width) = proof_shape(&proof).map_err(de::Error::custom)?;
+            if (num_rounds as u64, width as u64) != (repr.num_rounds, repr.width) {
+                return Err(de::Error::custom("declared shape differs from contents"));
+            }
+            Ok(proof)
+        }
+    }
+
+    impl<F: SumcheckField + Serialize> Serialize for CompactProof<F> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            CompactRef {
+                version: FORMAT_VERSION,
+                wire: &self.wire,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, F: SumcheckField + Deserialize<'de>> Deserialize<'de> for CompactProof<F> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let repr = CompactOwned::<F>::deserialize(deserializer)?;
+            check_version(repr.version)?;
+            Ok(CompactProof { wire: repr.wire })
+        }
+    }
+
+    impl<F: SumcheckField + Serialize> Serialize for SumcheckResult<F> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            ResultRef {
+                version: FORMAT_VERSION,
+                challenges: &self.challenges,
+                final_claim: &self.final_claim,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, F: SumcheckField + Deserialize<'de>> Deserialize<'de> for SumcheckResult<F> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let repr = ResultOwned::<F>::deserialize(deserializer)?;
+            check_version(repr.version)?;
+            Ok(SumcheckResult {
+                challenges: repr.challenges,
+                final_claim: repr.final_claim,
+            })
+        }
+    }
+}
+
+#[cfg(all(test, feature = "arkworks"))]
+mod tests {
+    use super::*;
+    use crate::proof::SumcheckError;
+    use crate::provers::eq_product::EqProductProver;
+    use crate::provers::inner_product::InnerProductProver;
+    use crate::provers::multilinear::MultilinearProver;
+    use crate::runner::sumcheck;
+    use crate::sumcheck_prover::SumcheckProver;
+    use crate::tests::{ReplayTranscript, F64};
+    use crate::verifier::verify_proof;
+    use ark_ff::UniformRand;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError};
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    fn random_vec(n: usize, rng: &mut StdRng) -> Vec<F64> {
+        (0..n).map(|_| F64::rand(rng)).collect()
+    }
+
+    fn prove(prover: &mut impl SumcheckProver<F64>, v: usize) -> SumcheckProof<F64> {
+        sumcheck(prover, v, &mut ReplayTranscript::new(0x47), |_, _| {})
+    }
+
+    fn to_bytes(proof: &SumcheckProof<F64>) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        proof.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), proof.compressed_size());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<SumcheckProof<F64>, SerializationError> {
+        SumcheckProof::deserialize_compressed(bytes)
+    }
+
+    #[test]
+    fn proofs_roundtrip_and_still_verify() {
+        let mut rng = StdRng::seed_from_u64(0x470);
+        for v in 0..=4 {
+            let a = random_vec(1 << v, &mut rng);
+            let b = random_vec(1 << v, &mut rng);
+            let w = random_vec(v, &mut rng);
+            let proofs = [
+                (prove(&mut MultilinearProver::new(a.clone()), v), 1),
+                (
+                    prove(&mut InnerProductProver::new(a.clone(), b.clone()), v),
+                    2,
+                ),
+                (prove(&mut EqProductProver::new(w, a, b), v), 3),
+            ];
+            for (proof, degree) in proofs {
+                let bytes = to_bytes(&proof);
+                // Header, then (d + 1) elements per round and the final value.
+                let elems = v * (degree + 1) + 1;
+                assert_eq!(bytes.len(), 17 + 8 * elems);
+                let decoded = from_bytes(&bytes).unwrap();
+                assert_eq!(decoded.round_polys, proof.round_polys);
+                assert_eq!(decoded.challenges, proof.challenges);
+                assert_eq!(decoded.final_value, proof.final_value);
+
+                let mut uncompressed = Vec::new();
+                proof.serialize_uncompressed(&mut uncompressed).unwrap();
+                let decoded = SumcheckProof::<F64>::deserialize_uncompressed(&uncompressed[..]);
+                assert_eq!(decoded.unwrap().challenges, proof.challenges);
+            }
+        }
+
+        // A decoded proof verifies like the original.
+        let a = random_vec(8, &mut rng);
+        let claim = a.iter().sum();
+        let proof = from_bytes(&to_bytes(&prove(&mut MultilinearProver::new(a), 3))).unwrap();
+        verify_proof(&proof, claim, 1, &mut ReplayTranscript::new(0x47)).unwrap();
+    }
+
+    #[test]
+    fn malformed_bytes_rejected() {
+        let mut rng = StdRng::seed_from_u64(0x471);
+        let a = random_vec(16, &mut rng);
+        let b = random_vec(16, &mut rng);
+        let bytes = to_bytes(&prove(&mut InnerProductProver::new(a, b), 4));
+
+        let with = |offset: usize, value: u8| {
+            let mut bytes = bytes.clone();
+            bytes[offset] = value;
+            from_bytes(&bytes)
+        };
+        // Unknown version.
+        assert!(matches!(with(0, 2), Err(SerializationError::InvalidData)));
+        // More rounds or values per round than the input holds.
+        assert!(with(1, 5).is_err());
+        assert!(with(9, 3).is_err());
+        // A width without rounds, listed widths with an empty round or
+        // all equal (non-canonical).
+        let header = |num_rounds: u8, width: u8, widths: &[u64]| {
+            let mut header = bytes[..17].to_vec();
+            header[1] = num_rounds;
+            header[9] = width;
+            header.extend(widths.iter().flat_map(|w| w.to_le_bytes()));
+            from_bytes(&header)
+        };
+        for malformed in [
+            header(0, 2, &[]),
+            header(4, 0, &[2, 2, 2, 0]),
+            header(4, 0, &[2, 2, 2, 2]),
+        ] {
+            assert!(matches!(malformed, Err(SerializationError::InvalidData)));
+        }
+        // Truncated.
+        assert!(from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        // A non-canonical field element (the all-ones limb exceeds p).
+        let mut bad = bytes.clone();
+        bad[17..25].fill(0xFF);
+        assert!(from_bytes(&bad).is_err());
+    }
+
+    #[test]
+    fn empty_rounds_have_no_encoding() {
+        let mut rng = StdRng::seed_from_u64(0x472);
+        let a = random_vec(8, &mut rng);
+        let b = random_vec(8, &mut rng);
+        let mut proof = prove(&mut InnerProductProver::new(a, b), 3);
+        proof.round_polys[1].clear();
+        assert!(matches!(
+            proof.serialize_compressed(&mut Vec::new()),
+            Err(SerializationError::InvalidData)
+        ));
+
+        let mut proof = prove(&mut MultilinearProver::new(random_vec(8, &mut rng)), 3);
+        proof.challenges.pop();
+        assert!(proof.serialize_compressed(&mut Vec::new()).is_err());
+    }
+
+    /// A univariate-skip proof has a wider first round; its per-round
+    /// widths are listed in the header.
+    #[test]
+    fn variable_degree_proofs_roundtrip() {
+        use crate::polynomial::SkipDomain;
+
+        let mut rng = StdRng::seed_from_u64(0x474);
+        let (v, k) = (5, 2);
+        let a = random_vec(1 << v, &mut rng);
+        let b = random_vec(1 << v, &mut rng);
+        let domain = SkipDomain::<F64>::from_two_adic(k);
+        let num_rounds = v - k + 1;
+        let proof = prove(
+            &mut InnerProductProver::with_univariate_skip(a, b, domain.clone()),
+            num_rounds,
+        );
+        let widths: Vec<usize> = proof.round_polys.iter().map(Vec::len).collect();
+        assert_eq!(widths, [domain.round_degree(2), 2, 2, 2]);
+
+        let bytes = to_bytes(&proof);
+        assert_eq!(proof.size(Compress::Yes).header, 17 + 8 * num_rounds);
+        assert_eq!(bytes[9..17], [0; 8]);
+        let decoded = from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.round_polys, proof.round_polys);
+        assert_eq!(decoded.challenges, proof.challenges);
+        assert_eq!(decoded.final_value, proof.final_value);
+    }
+
+    #[test]
+    fn compact_proofs_roundtrip_through_a_transcript() {
+        let mut rng = StdRng::seed_from_u64(0x480);
+        let a = random_vec(32, &mut rng);
+        let b = random_vec(32, &mut rng);
+        let claim = a.iter().zip(&b).map(|(x, y)| *x * y).sum();
+        let proof = prove(&mut InnerProductProver::new(a, b), 5);
+
+        let compact = CompactProof::from_proof(&proof);
+        assert_eq!(compact.wire.len(), CompactProof::<F64>::wire_len(&|_| 2, 5));
+        let mut bytes = Vec::new();
+        compact.serialize_compressed(&mut bytes).unwrap();
+        let decoded = CompactProof::<F64>::deserialize_compressed(&bytes[..]).unwrap();
+        assert_eq!(decoded, compact);
+
+        let rebuilt = decoded
+            .to_proof(claim, &|_| 2, 5, &mut ReplayTranscript::new(0x47))
+            .unwrap();
+        assert_eq!(rebuilt.round_polys, proof.round_polys);
+        assert_eq!(rebuilt.challenges, proof.challenges);
+        assert_eq!(rebuilt.final_value, proof.final_value);
+
+        // Only the header and the wire values remain.
+        let full = proof.size(Compress::Yes);
+        let small = compact.size(Compress::Yes);
+        assert_eq!(
+            full,
+            ProofSize {
+                header: 17,
+                round_polys: 8 * 10,
+                challenges: 8 * 5,
+                final_value: 8
+            }
+        );
+        assert_eq!(
+            small,
+            ProofSize {
+                header: 9,
+                round_polys: 8 * 10,
+                ..ProofSize::default()
+            }
+        );
+        assert_eq!(small.total(), bytes.len());
+        assert_eq!(full.total(), to_bytes(&proof).len());
+
+        let mut short = compact.clone();
+        short.wire.pop();
+        assert_eq!(
+            short
+                .to_proof(claim, &|_| 2, 5, &mut ReplayTranscript::new(0x47))
+                .unwrap_err(),
+            SumcheckError::DegreeMismatch {
+                round: 4,
+                expected: 2,
+                got: 1
+            }
+        );
+        let mut long = compact;
+        long.wire.push(F64::from(1u64));
+        assert_eq!(
+            long.to_proof(claim, &|_| 2, 5, &mut ReplayTranscript::new(0x47))
+                .unwrap_err(),
+            SumcheckError::TrailingValues { count: 1 }
+        );
+    }
+
+    #[test]
+    fn results_roundtrip() {
+        let mut rng = StdRng::seed_from_u64(0x473);
+        let result = SumcheckResult {
+            challenges: random_vec(5, &mut rng),
+            final_claim: F64::rand(&mut rng),
+        };
+        let mut bytes = Vec::new();
+        result.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), 9 + 8 * 6);
+        let decoded = SumcheckResult::<F64>::deserialize_compressed(&bytes[..]).unwrap();
+        assert_eq!(decoded.challenges, result.challenges);
+        assert_eq!(decoded.final_claim, result.final_claim);
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+    use crate::binary_field::Gf2_128;
+
+    fn proof(num_rounds: usize, degree: usize) -> SumcheckProof<Gf2_128> {
+        let elem = |i: usize| Gf2_128::new(0x0123_4567_89AB_CDEF_u128 << (i % 64));
+        SumcheckProof {
+            round_polys: (0..num_rounds)
+                .map(|j| (0..degree).map(|k| elem(j * degree + k)).collect())
+                .collect(),
+            challenges: (0..num_rounds).map(|j| elem(100 + j)).collect(),
+            final_value: elem(7),
+        }
+    }
+
+    #[test]
+    fn serde_roundtrip_and_shape_checks() {
+        let proof = proof(3, 2);
+        let json = serde_json::to_string(&proof).unwrap();
+        let decoded: SumcheckProof<Gf2_128> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.round_polys, proof.round_polys);
+        assert_eq!(decoded.challenges, proof.challenges);
+        assert_eq!(decoded.final_value, proof.final_value);
+
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value["width"] = 3.into();
+        assert!(serde_json::from_value::<SumcheckProof<Gf2_128>>(value.clone()).is_err());
+        value["width"] = 2.into();
+        value["version"] = 9.into();
+        assert!(serde_json::from_value::<SumcheckProof<Gf2_128>>(value.clone()).is_err());
+        value["version"] = 1.into();
+        value["challenges"].as_array_mut().unwrap().pop();
+        assert!(serde_json::from_value::<SumcheckProof<Gf2_128>>(value).is_err());
+
+        // Rounds of different widths declare width zero.
+        let mut ragged = proof.clone();
+        ragged.round_polys[0].push(Gf2_128::new(1));
+        let json = serde_json::to_string(&ragged).unwrap();
+        let decoded: SumcheckProof<Gf2_128> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.round_polys, ragged.round_polys);
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["width"], 0);
+        value["width"] = 3.into();
+        assert!(serde_json::from_value::<SumcheckProof<Gf2_128>>(value).is_err());
+        ragged.round_polys[1].clear();
+        assert!(serde_json::to_string(&ragged).is_err());
+
+        let result = SumcheckResult {
+            challenges: proof.challenges.clone(),
+            final_claim: proof.final_value,
+        };
+        let compact = CompactProof::from_proof(&proof);
+        let decoded: CompactProof<Gf2_128> =
+            serde_json::from_str(&serde_json::to_string(&compact).unwrap()).unwrap();
+        assert_eq!(decoded, compact);
+
+        let decoded: SumcheckResult<Gf2_128> =
+            serde_json::from_str(&serde_json::to_string(&result).unwrap()).unwrap();
+        assert_eq!(decoded.challenges, result.challenges);
+    }
+}
@@ -0,0 +1,562 @@
+//! Step-wise (sans-I/O) sumcheck sessions.
+//!
+//! [`sumcheck`](crate::runner::sumcheck) and
+//! [`sumcheck_verify`](crate::verifier::sumcheck_verify) own the whole
+//! round loop and talk to a transcript. [`ProverSession`] and
+//! [`VerifierSession`] expose the same protocol one message at a time and
+//! do no I/O: the caller moves messages and challenges between the
+//! parties, over a network or through a Fiat–Shamir transcript, and can
+//! run other subprotocols between rounds. The runner and the verifiers are
+//! built on these sessions.
+//!
+//! # Protocol
+//!
+//! Each round `j`:
+//!
+//! 1. prover: [`ProverSession::next_message`] → round polynomial `h_j`
+//!    (EvalsInfty, see [`SumcheckProver::round`]);
+//! 2. verifier: [`VerifierSession::receive_message`]`(h_j)` →
+//!    [`ChallengeNeeded`];
+//! 3. both: `receive_challenge(r_j)`, with `r_j` sampled by the verifier
+//!    (interactive) or squeezed from a transcript (Fiat–Shamir).
+//!
+//! After the last round, [`ProverSession::finish`] returns the
+//! [`SumcheckProof`] and [`VerifierSession::finish`] the
+//! [`SumcheckResult`] whose `final_claim` the caller checks against
+//! `g(r_1, ..., r_v)`.
+//!
+//! ```ignore
+//! let mut prover = ProverSession::new(&mut p, v);
+//! let mut verifier = VerifierSession::new(claimed_sum, p.degree(), v);
+//! while !verifier.is_done() {
+//!     let msg = prover.next_message().to_vec(); // → network
+//!     verifier.receive_message(&msg)?;
+//!     let r = F::rand(&mut rng);                 // ← verifier's coins
+//!     verifier.receive_challenge(r);
+//!     prover.receive_challenge(r);
+//! }
+//! let proof = prover.finish();
+//! let result = verifier.finish();
+//! ```
+//!
+//! Calling a method out of turn (a second message before the challenge, a
+//! challenge before the message, anything after the last round) is a
+//! programming error and panics.
+
+extern crate alloc;
+use crate::field::SumcheckField;
+use crate::proof::{SumcheckError, SumcheckProof};
+use crate::sumcheck_prover::SumcheckProver;
+use crate::verifier::{DegreeSchedule, RoundKernel, SumcheckResult};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Prover side of a `num_rounds`-round sumcheck, one message at a time.
+pub struct ProverSession<'a, F: SumcheckField, P: SumcheckProver<F> + ?Sized> {
+    prover: &'a mut P,
+    num_rounds: usize,
+    round_polys: Vec<Vec<F>>,
+    challenges: Vec<F>,
+    /// Current claim, if the claimed sum was given.
+    claim: Option<F>,
+    /// Round update kernels for the degrees seen so far, if tracking the claim.
+    kernels: Vec<RoundKernel<F>>,
+}
+
+impl<'a, F: SumcheckField, P: SumcheckProver<F> + ?Sized> ProverSession<'a, F, P> {
+    /// Start a session running `prover` for `num_rounds` rounds.
+    ///
+    /// As with the runner, `num_rounds < v` leaves the prover part-way
+    /// through its variables for the caller to continue or inspect.
+    pub fn new(prover: &'a mut P, num_rounds: usize) -> Self {
+        Self {
+            prover,
+            num_rounds,
+            round_polys: Vec::with_capacity(num_rounds),
+            challenges: Vec::with_capacity(num_rounds),
+            claim: None,
+            kernels: Vec::new(),
+        }
+    }
+
+    /// Track the current claim, starting from `claimed_sum`, for
+    /// [`claim`](Self::claim) and [`context`](Self::context).
+    ///
+    /// Assumes standard EvalsInfty rounds at
+    /// [`round_degree`](SumcheckProver::round_degree) (no univariate skip).
+    pub fn with_claim(mut self, claimed_sum: F) -> Self {
+        self.claim = Some(claimed_sum);
+        self
+    }
+
+    /// Index of the current round (`num_rounds` once done).
+    pub fn round(&self) -> usize {
+        self.challenges.len()
+    }
+
+    /// Total number of rounds.
+    pub fn num_rounds(&self) -> usize {
+        self.num_rounds
+    }
+
+    /// The claim the current round's message sums to: the claimed sum,
+    /// then `h_j(r_j)` after round `j`. `None` without
+    /// [`with_claim`](Self::with_claim).
+    pub fn claim(&self) -> Option<F> {
+        self.claim
+    }
+
+    /// Read-only view of the current round, between
+    /// [`next_message`](Self::next_message) and
+    /// [`receive_challenge`](Self::receive_challenge); `None` otherwise.
+    pub fn context(&self) -> Option<ProverRoundContext<'_, F>> {
+        let round = self.round();
+        let message = self.round_polys.get(round)?;
+        Some(ProverRoundContext {
+            round,
+            num_rounds: self.num_rounds,
+            degree: self.prover.round_degree(round),
+            message,
+            claim: self.claim,
+            table_len: self.prover.table_len(),
+        })
+    }
+
+    /// Whether all `num_rounds` challenges have been received.
+    pub fn is_done(&self) -> bool {
+        self.challenges.len() == self.num_rounds
+    }
+
+    /// Compute the current round's polynomial (EvalsInfty wire values).
+    ///
+    /// # Panics
+    ///
+    /// If the previous message still awaits its challenge, or all rounds
+    /// are done.
+    pub fn next_message(&mut self) -> &[F] {
+        assert!(!self.is_done(), "all {} rounds are done", self.num_rounds);
+        assert!(
+            self.round_polys.len() == self.challenges.len(),
+            "round {} message already sent; awaiting its challenge",
+            self.round()
+        );
+        let message = self.prover.round(self.challenges.last().copied());
+        debug_assert_eq!(
+            message.len(),
+            self.prover.round_degree(self.round()).max(1),
+            "round {}: message length does not match round_degree",
+            self.round()
+        );
+        self.round_polys.push(message);
+        self.round_polys.last().expect("just pushed")
+    }
+
+    /// Receive the verifier's challenge for the current round.
+    ///
+    /// # Panics
+    ///
+    /// If the current round's message has not been sent.
+    pub fn receive_challenge(&mut self, challenge: F) {
+        assert!(
+            self.round_polys.len() == self.challenges.len() + 1,
+            "challenge received before the round {} message",
+            self.round()
+        );
+        let round = self.round();
+        if let Some(claim) = self.claim {
+            let kernel = RoundKernel::cached(&mut self.kernels, self.prover.round_degree(round));
+            self.claim = Some(kernel.eval(claim, &self.round_polys[round], challenge));
+        }
+        self.challenges.push(challenge);
+    }
+
+    /// Fold in the last challenge and return the proof.
+    ///
+    /// # Panics
+    ///
+    /// If rounds remain.
+    pub fn finish(self) -> SumcheckProof<F> {
+        assert!(
+            self.is_done(),
+            "finished after {} of {} rounds",
+            self.round(),
+            self.num_rounds
+        );
+        if let Some(&r) = self.challenges.last() {
+            self.prover.finalize(r);
+        }
+        SumcheckProof {
+            round_polys: self.round_polys,
+            challenges: self.challenges,
+            final_value: self.prover.final_value(),
+        }
+    }
+}
+
+/// Returned by [`VerifierSession::receive_message`]: the message is
+/// accepted and the verifier must now supply the round's challenge via
+/// [`VerifierSession::receive_challenge`].
+#[must_use = "the round is not complete until its challenge is received"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChallengeNeeded {
+    /// The round awaiting its challenge.
+    pub round: usize,
+}
+
+/// Prover-side view of round `round`, after its message is computed and
+/// before its challenge; passed to
+/// [`sumcheck_with_context`](crate::runner::sumcheck_with_context) hooks.
+#[derive(Clone, Copy, Debug)]
+pub struct ProverRoundContext<'a, F> {
+    /// Current round (0-indexed).
+    pub round: usize,
+    /// Total number of rounds.
+    pub num_rounds: usize,
+    /// Degree of this round's polynomial.
+    pub degree: usize,
+    /// The round polynomial just sent (EvalsInfty wire values).
+    pub message: &'a [F],
+    /// The claim `message` sums to over `{0, 1}`, if tracked
+    /// ([`ProverSession::with_claim`]).
+    pub claim: Option<F>,
+    /// Entries left in the prover's table ([`SumcheckProver::table_len`]).
+    pub table_len: Option<usize>,
+}
+
+/// Verifier-side view of round `round`, after its message is received and
+/// before its challenge; passed to
+/// [`sumcheck_verify_with_context`](crate::verifier::sumcheck_verify_with_context)
+/// hooks.
+#[derive(Clone, Copy, Debug)]
+pub struct VerifierRoundContext<'a, F> {
+    /// Current round (0-indexed).
+    pub round: usize,
+    /// Total number of rounds.
+    pub num_rounds: usize,
+    /// Degree of this round's polynomial.
+    pub degree: usize,
+    /// The round polynomial just received (EvalsInfty wire values).
+    pub message: &'a [F],
+    /// The claim `message` is checked against.
+    pub claim: F,
+}
+
+/// Verifier side of a sumcheck, one message at a time.
+///
+/// Runs the same round checks as
+/// [`sumcheck_verify`](crate::verifier::sumcheck_verify) and additionally
+/// rejects messages of the wrong length, since it sees each round's
+/// message as a whole.
+#[derive(Clone, Debug)]
+pub struct VerifierSession<F: SumcheckField> {
+    /// Per-round degrees, indexed by absolute round.
+    degrees: Vec<usize>,
+    /// One round update kernel per distinct degree in `degrees`.
+    kernels: Vec<RoundKernel<F>>,
+    challenges: Vec<F>,
+    claim: F,
+    /// The current round's wire values, between message and challenge.
+    pending: Option<Vec<F>>,
+}
+
+impl<F: SumcheckField> VerifierSession<F> {
+    /// Verify `num_rounds` rounds of degree `degree` against `claimed_sum`.
+    pub fn new(claimed_sum: F, degree: usize, num_rounds: usize) -> Self {
+        Self::resume(claimed_sum, vec![degree; num_rounds], Vec::new())
+    }
+
+    /// Verify `num_rounds` rounds whose degrees follow `degrees`.
+    pub fn with_degrees<D: DegreeSchedule + ?Sized>(
+        claimed_sum: F,
+        degrees: &D,
+        num_rounds: usize,
+    ) -> Self {
+        let degrees = (0..num_rounds).map(|j| degrees.degree(j)).collect();
+        Self::resume(claimed_sum, degrees, Vec::new())
+    }
+
+    /// Continue from round `challenges.len()` with the reduced `claim`
+    /// (e.g. after a univariate-skip first round).
+    pub(crate) fn resume(claim: F, degrees: Vec<usize>, challenges: Vec<F>) -> Self {
+        debug_assert!(challenges.len() <= degrees.len());
+        let mut kernels = Vec::new();
+        for &d in &degrees[challenges.len()..] {
+            RoundKernel::cached(&mut kernels, d);
+        }
+        Self {
+            degrees,
+            kernels,
+            challenges,
+            claim,
+            pending: None,
+        }
+    }
+
+    /// Index of the current round (`num_rounds` once done).
+    pub fn round(&self) -> usize {
+        self.challenges.len()
+    }
+
+    /// Whether all rounds have been verified.
+    pub fn is_done(&self) -> bool {
+        self.challenges.len() == self.degrees.len()
+    }
+
+    /// The current claim: the claimed sum, then `h_j(r_j)` after round `j`.
+    pub fn claim(&self) -> F {
+        self.claim
+    }
+
+    /// Total number of rounds.
+    pub fn num_rounds(&self) -> usize {
+        self.degrees.len()
+    }
+
+    /// Read-only view of the current round, between
+    /// [`receive_message`](Self::receive_message) and
+    /// [`receive_challenge`](Self::receive_challenge); `None` otherwise.
+    pub fn context(&self) -> Option<VerifierRoundContext<'_, F>> {
+        let round = self.round();
+        let message = self.pending.as_deref()?;
+        Some(VerifierRoundContext {
+            round,
+            num_rounds: self.degrees.len(),
+            degree: self.degrees[round],
+            message,
+            claim: self.claim,
+        })
+    }
+
+    /// Number of wire values in the current round's message
+    /// (`max(d_j, 1)`), or `None` once done.
+    pub fn message_len(&self) -> Option<usize> {
+        self.degrees.get(self.round()).map(|&d| d.max(1))
+    }
+
+    /// Receive the current round's message.
+    ///
+    /// Returns [`SumcheckError::DegreeMismatch`] if it does not hold
+    /// [`message_len`](Self::message_len) values; the session is then
+    /// unchanged.
+    ///
+    /// # Panics
+    ///
+    /// If the previous message still awaits its challenge, or all rounds
+    /// are done.
+    pub fn receive_message(&mut self, message: &[F]) -> Result<ChallengeNeeded, SumcheckError> {
+        let round = self.round();
+        let Some(&degree) = self.degrees.get(round) else {
+            panic!("all {} rounds are done", self.degrees.len());
+        };
+        assert!(
+            self.pending.is_none(),
+            "round {round} message already received; awaiting its challenge"
+        );
+        if message.len() != degree.max(1) {
+            return Err(SumcheckError::DegreeMismatch {
+                round,
+                expected: degree.max(1),
+                got: message.len(),
+            });
+        }
+        self.pending = Some(message.to_vec());
+        Ok(ChallengeNeeded { round })
+    }
+
+    /// Receive the current round's challenge `r_j` and reduce the claim
+    /// to `h_j(r_j)`.
+    ///
+    /// # Panics
+    ///
+    /// If the current round's message has not been received.
+    pub fn receive_challenge(&mut self, challenge: F) {
+        let round = self.round();
+        let Some(wire) = self.pending.take() else {
+            panic!("challenge received before the round {round} message");
+        };
+        let degree = self.degrees[round];
+        let kernel = self
+            .kernels
+            .iter()
+            .find(|k| k.degree() == degree)
+            .expect("kernel built for every round");
+        self.claim = kernel.eval(self.claim, &wire, challenge);
+        self.challenges.push(challenge);
+    }
+
+    /// The challenges and final claim.
+    ///
+    /// # Panics
+    ///
+    /// If rounds remain.
+    pub fn finish(self) -> SumcheckResult<F> {
+        assert!(
+            self.is_done(),
+            "finished after {} of {} rounds",
+            self.round(),
+            self.degrees.len()
+        );
+        SumcheckResult {
+            challenges: self.challenges,
+            final_claim: self.claim,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "arkworks"))]
+mod tests {
+    use super::*;
+    use crate::provers::inner_product::InnerProductProver;
+    use crate::provers::multilinear::MultilinearProver;
+    use crate::runner::sumcheck;
+    use crate::tests::F64;
+    use ark_ff::UniformRand;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    fn random_vec(n: usize, rng: &mut StdRng) -> Vec<F64> {
+        (0..n).map(|_| F64::rand(rng)).collect()
+    }
+
+    #[test]
+    fn interactive_sessions_match_runner() {
+        let mut rng = StdRng::seed_from_u64(0x450);
+        for v in 0..=5 {
+            let a = random_vec(1 << v, &mut rng);
+            let b = random_vec(1 << v, &mut rng);
+            let claim = a.iter().zip(&b).map(|(x, y)| *x * y).sum();
+
+            // Interactive: the verifier's own coins, no transcript.
+            let mut p = InnerProductProver::new(a.clone(), b.clone());
+            let mut prover = ProverSession::new(&mut p, v);
+            let mut verifier = VerifierSession::new(claim, 2, v);
+            while let Some(len) = verifier.message_len() {
+                let message = prover.next_message().to_vec();
+                assert_eq!(message.len(), len);
+                let needed = verifier.receive_message(&message).unwrap();
+                assert_eq!(needed.round, verifier.round());
+                let r = F64::rand(&mut rng);
+                verifier.receive_challenge(r);
+                prover.receive_challenge(r);
+            }
+            assert!(prover.is_done());
+            let proof = prover.finish();
+            let result = verifier.finish();
+            assert_eq!(result.challenges, proof.challenges);
+            assert_eq!(result.final_claim, proof.final_value);
+
+            // Same challenges through the runner give the same proof.
+            let mut t = TapeChallenges(proof.challenges.clone().into_iter());
+            let rerun = sumcheck(&mut InnerProductProver::new(a, b), v, &mut t, |_, _| {});
+            assert_eq!(rerun.round_polys, proof.round_polys);
+            assert_eq!(rerun.final_value, proof.final_value);
+        }
+    }
+
+    /// Prover transcript that ignores messages and replays fixed challenges.
+    struct TapeChallenges(alloc::vec::IntoIter<F64>);
+
+    impl crate::transcript::ProverTranscript<F64> for TapeChallenges {
+        fn send(&mut self, _value: F64) {}
+
+        fn challenge(&mut self) -> F64 {
+            self.0.next().unwrap()
+        }
+    }
+
+    #[test]
+    fn verifier_session_rejects_wrong_shape_and_sum() {
+        let mut rng = StdRng::seed_from_u64(0x451);
+        let a = random_vec(8, &mut rng);
+        let claim: F64 = a.iter().sum();
+        let mut p = MultilinearProver::new(a);
+        let mut prover = ProverSession::new(&mut p, 3);
+        let mut verifier = VerifierSession::new(claim + F64::from(1u64), 1, 3);
+
+        let message = prover.next_message().to_vec();
+        assert_eq!(
+            verifier.receive_message(&[message[0], message[0]]),
+            Err(SumcheckError::DegreeMismatch {
+                round: 0,
+                expected: 1,
+                got: 2,
+            })
+        );
+        // The rejected message left the session waiting for round 0.
+        let _ = verifier.receive_message(&message).unwrap();
+        loop {
+            let r = F64::rand(&mut rng);
+            verifier.receive_challenge(r);
+            prover.receive_challenge(r);
+            if verifier.is_done() {
+                break;
+            }
+            let message = prover.next_message().to_vec();
+            let _ = verifier.receive_message(&message).unwrap();
+        }
+        // A wrong claimed sum surfaces as a final-claim mismatch.
+        assert_ne!(verifier.finish().final_claim, prover.finish().final_value);
+    }
+
+    #[test]
+    fn hooks_see_matching_round_context() {
+        use crate::runner::sumcheck_with_context;
+        use crate::tests::ReplayTranscript;
+        use crate::verifier::sumcheck_verify_with_context;
+
+        let mut rng = StdRng::seed_from_u64(0x460);
+        let v = 4;
+        let a = random_vec(1 << v, &mut rng);
+        let b = random_vec(1 << v, &mut rng);
+        let claim = a.iter().zip(&b).map(|(x, y)| *x * y).sum();
+
+        let mut t = ReplayTranscript::new(0x461);
+        let mut seen = Vec::new();
+        let proof = sumcheck_with_context(
+            &mut InnerProductProver::new(a.clone(), b.clone()),
+            claim,
+            v,
+            &mut t,
+            |ctx, _| {
+                assert_eq!((ctx.num_rounds, ctx.degree), (v, 2));
+                assert_eq!(ctx.table_len, Some(1 << (v - ctx.round)));
+                seen.push((ctx.message.to_vec(), ctx.claim.unwrap()));
+            },
+        );
+        // The plain runner produces the same proof.
+        let plain = sumcheck(
+            &mut InnerProductProver::new(a, b),
+            v,
+            &mut ReplayTranscript::new(0x461),
+            crate::noop_hook,
+        );
+        assert_eq!(proof.round_polys, plain.round_polys);
+        assert_eq!(proof.final_value, plain.final_value);
+        assert_eq!(seen[0].1, claim);
+
+        let mut round = 0;
+        let result = sumcheck_verify_with_context(claim, &|_| 2, v, &mut t.replay(), |ctx, _| {
+            assert_eq!(ctx.round, round);
+            assert_eq!((ctx.message.to_vec(), ctx.claim), seen[round]);
+            round += 1;
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(round, v);
+        assert_eq!(result.final_claim, proof.final_value);
+    }
+
+    #[test]
+    #[should_panic(expected = "awaiting its challenge")]
+    fn prover_session_enforces_turn_order() {
+        let mut p = MultilinearProver::new(vec![F64::from(1u64); 4]);
+        let mut prover = ProverSession::new(&mut p, 2);
+        prover.next_message();
+        prover.next_message();
+    }
+
+    #[test]
+    #[should_panic(expected = "before the round 0 message")]
+    fn verifier_session_enforces_turn_order() {
+        VerifierSession::new(F64::from(0u64), 2, 1).receive_challenge(F64::from(1u64));
+    }
+}
@@ -0,0 +1,308 @@
+//! Soundness accounting for sumcheck (Thaler Proposition 4.1).
+//!
+//! A `v`-round sumcheck whose round polynomials have degrees `d_1, ..., d_v`
+//! accepts a false claim with probability at most `Σ_j d_j / |F|`
+//! (`v·d / |F|` for a constant degree). Batching `k` instances with the
+//! powers `1, γ, ..., γ^{k-1}` of one challenge adds `(k − 1)/|F|`
+//! (Schwartz–Zippel on the batching polynomial in `γ`), so
+//!
+//! ```text
+//! ε ≤ (Σ_j d_j + k − 1) / |F|,      security = ⌊log2 |F|⌋ − ⌈log2(Σ_j d_j + k − 1)⌉
+//! ```
+//!
+//! Both logarithms are rounded to integer bit lengths in the safe
+//! direction, so the reported level never overstates `log2(1/ε)`.
+//!
+//! For Goldilocks without an extension (`log2 |F| ≈ 64`) a degree-3,
+//! 30-round sumcheck has `ε ≈ 2^{-57.5}` (reported as 56 bits): far below
+//! 100 bits. Sample challenges from an extension field to reach the target.
+//!
+//! [`SoundnessBound`] computes the bound from
+//! [`SumcheckField::field_size_bits`]; [`SecurityConfig`] makes the verifier
+//! ([`sumcheck_verify_with_config`](crate::verifier::sumcheck_verify_with_config))
+//! refuse to run below a required number of bits.
+//!
+//! ```ignore
+//! let bound = SoundnessBound::new::<F, _>(&|_| 3, 30, 1);
+//! assert!(bound.security_bits() >= 100.0, "use an extension field");
+//!
+//! let config = SecurityConfig::new(100);
+//! let result = sumcheck_verify_with_config(sum, 3, 30, &config, &mut t, noop_hook_verify)?;
+//! ```
+
+use crate::field::SumcheckField;
+use crate::proof::SumcheckError;
+use crate::verifier::DegreeSchedule;
+
+/// `log2(x)` for `x ≥ 1`, rounded down (`0.0` for `x = 0`).
+///
+/// `no_std`: the integer part comes from the bit length and the fraction
+/// from repeated squaring of the (truncated) 53-bit mantissa.
+pub(crate) fn log2_u128(x: u128) -> f64 {
+    if x == 0 {
+        return 0.0;
+    }
+    let n = 127 - x.leading_zeros();
+    let top = if n > 52 { x >> (n - 52) } else { x << (52 - n) };
+    let mut m = top as f64 / (1u64 << 52) as f64; // in [1, 2)
+    let mut frac = 0.0;
+    let mut bit = 0.5;
+    for _ in 0..52 {
+        m *= m;
+        if m >= 2.0 {
+            m /= 2.0;
+            frac += bit;
+        }
+        bit /= 2.0;
+    }
+    n as f64 + frac
+}
+
+/// The Proposition 4.1 soundness bound `ε ≤ numerator / |F|`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SoundnessBound {
+    /// `log2 |F|`.
+    pub field_bits: f64,
+    /// `Σ_j d_j + k − 1`.
+    pub numerator: u128,
+}
+
+impl SoundnessBound {
+    /// Bound for `num_rounds` rounds of degrees `degrees` over `F`,
+    /// batching `num_instances ≥ 1` instances with powers of one challenge.
+    ///
+    /// # Panics
+    ///
+    /// If `num_instances == 0`.
+    pub fn new<F: SumcheckField, D: DegreeSchedule + ?Sized>(
+        degrees: &D,
+        num_rounds: usize,
+        num_instances: usize,
+    ) -> Self {
+        Self::with_field_bits(F::field_size_bits(), degrees, num_rounds, num_instances)
+    }
+
+    /// As [`new`](Self::new), for a field of `field_bits = log2 |F|` bits.
+    pub fn with_field_bits<D: DegreeSchedule + ?Sized>(
+        field_bits: f64,
+        degrees: &D,
+        num_rounds: usize,
+        num_instances: usize,
+    ) -> Self {
+        assert!(num_instances > 0, "need at least one instance");
+        let total_degree: u128 = (0..num_rounds).map(|j| degrees.degree(j) as u128).sum();
+        Self {
+            field_bits,
+            numerator: total_degree + num_instances as u128 - 1,
+        }
+    }
+
+    /// Security level `⌊log2 |F|⌋ − ⌈log2(numerator)⌉`, in whole bits: a
+    /// lower bound on `log2(1/ε)`.
+    ///
+    /// `f64::INFINITY` when the bound is zero (no rounds, one instance).
+    pub fn security_bits(&self) -> f64 {
+        if self.numerator == 0 {
+            return f64::INFINITY;
+        }
+        // Bit length of `numerator − 1` is `⌈log2(numerator)⌉`.
+        let log2_num = 128 - (self.numerator - 1).leading_zeros();
+        (self.field_bits as u64) as f64 - log2_num as f64
+    }
+
+    /// Upper bound on the soundness error, `2^{-security_bits}` capped at 1.
+    pub fn error(&self) -> f64 {
+        let bits = self.security_bits();
+        if bits <= 0.0 {
+            return 1.0;
+        }
+        if bits.is_infinite() {
+            return 0.0;
+        }
+        let mut e = 1.0f64;
+        for _ in 0..bits as u32 {
+            e /= 2.0;
+        }
+        e
+    }
+}
+
+/// Verifier configuration enforcing a minimum security level.
+///
+/// Passed to
+/// [`sumcheck_verify_with_config`](crate::verifier::sumcheck_verify_with_config),
+/// which returns [`SumcheckError::InsufficientSecurity`] before reading
+/// the transcript if the [`SoundnessBound`] falls short.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SecurityConfig {
+    /// Required security level, in bits.
+    pub min_security_bits: u32,
+    /// Number of instances batched into the sumcheck (default 1).
+    pub num_instances: usize,
+}
+
+impl SecurityConfig {
+    /// Require at least `min_security_bits` bits for a single instance.
+    pub fn new(min_security_bits: u32) -> Self {
+        Self {
+            min_security_bits,
+            num_instances: 1,
+        }
+    }
+
+    /// Account for `num_instances` batched instances.
+    pub fn with_instances(self, num_instances: usize) -> Self {
+        Self {
+            num_instances,
+            ..self
+        }
+    }
+
+    /// Check the bound for `num_rounds` rounds of degrees `degrees` over `F`.
+    ///
+    /// Returns [`SumcheckError::InsufficientSecurity`] with the required
+    /// and achieved (rounded down) bits on failure.
+    pub fn check<F: SumcheckField, D: DegreeSchedule + ?Sized>(
+        &self,
+        degrees: &D,
+        num_rounds: usize,
+    ) -> Result<(), SumcheckError> {
+        let bits =
+            SoundnessBound::new::<F, D>(degrees, num_rounds, self.num_instances).security_bits();
+        if bits >= self.min_security_bits as f64 {
+            Ok(())
+        } else {
+            Err(SumcheckError::InsufficientSecurity {
+                required: self.min_security_bits,
+                achieved: bits.max(0.0) as u32,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary_field::Gf2_128;
+
+    #[test]
+    fn log2_is_exact_on_powers_and_rounds_down() {
+        for n in [0u32, 1, 7, 63, 64, 100, 127] {
+            assert_eq!(log2_u128(1u128 << n), n as f64);
+        }
+        let l3 = log2_u128(3);
+        assert!(l3 < 1.584_962_500_721_157 && l3 > 1.584_962_500_72);
+        // Goldilocks p = 2^64 − 2^32 + 1 is just below 64 bits.
+        let p = 0xFFFF_FFFF_0000_0001u128;
+        assert!(log2_u128(p) < 64.0 && log2_u128(p) > 63.999_999);
+    }
+
+    #[test]
+    fn bound_matches_proposition() {
+        // v·d = 30·3 = 90 over a 128-bit field: 128 − ⌈log2(90)⌉ bits.
+        let bound = SoundnessBound::new::<Gf2_128, _>(&|_| 3, 30, 1);
+        assert_eq!(bound.numerator, 90);
+        assert_eq!(bound.security_bits(), 121.0);
+
+        // Batching 7 instances adds 6 to the numerator.
+        let batched = SoundnessBound::new::<Gf2_128, _>(&[3, 3, 1], 3, 7);
+        assert_eq!(batched.numerator, 7 + 6);
+
+        let trivial = SoundnessBound::with_field_bits(64.0, &|_| 2, 0, 1);
+        assert_eq!(trivial.security_bits(), f64::INFINITY);
+        assert_eq!(trivial.error(), 0.0);
+
+        let half = SoundnessBound::with_field_bits(1.0, &|_| 1, 1, 1);
+        assert_eq!(half.error(), 0.5);
+        // ε = 3/16, rounded up to 1/4.
+        let tiny = SoundnessBound::with_field_bits(4.0, &|_| 3, 1, 1);
+        assert_eq!(tiny.error(), 0.25);
+        let broken = SoundnessBound::with_field_bits(2.0, &|_| 3, 4, 1);
+        assert_eq!(broken.error(), 1.0);
+    }
+
+    #[test]
+    fn security_bits_round_down_at_powers_of_two() {
+        // 32 rounds of degree 2: numerator 64 = 2^6 exactly.
+        let exact = SoundnessBound::with_field_bits(128.0, &|_| 2, 32, 1);
+        assert_eq!(exact.numerator, 64);
+        assert_eq!(exact.security_bits(), 122.0);
+        // One more instance crosses the boundary: ⌈log2(65)⌉ = 7.
+        let over = SoundnessBound::with_field_bits(128.0, &|_| 2, 32, 2);
+        assert_eq!(over.numerator, 65);
+        assert_eq!(over.security_bits(), 121.0);
+        let under = SoundnessBound::with_field_bits(128.0, &[2, 2, 2, 1], 4, 1);
+        assert_eq!(under.numerator, 7);
+        assert_eq!(under.security_bits(), 125.0);
+        // A field just short of 2^64 (Goldilocks) only counts 63 bits.
+        let goldilocks = SoundnessBound::with_field_bits(63.999_999, &|_| 1, 1, 1);
+        assert_eq!(goldilocks.security_bits(), 63.0);
+    }
+
+    #[test]
+    fn config_enforces_minimum() {
+        let config = SecurityConfig::new(100);
+        assert_eq!(config.check::<Gf2_128, _>(&|_| 3, 30), Ok(()));
+        assert_eq!(
+            SecurityConfig::new(124).check::<Gf2_128, _>(&|_| 3, 30),
+            Err(SumcheckError::InsufficientSecurity {
+                required: 124,
+                achieved: 121,
+            })
+        );
+        // Batching enough instances eats into the margin.
+        assert!(config
+            .with_instances(1 << 30)
+            .check::<Gf2_128, _>(&|_| 3, 30)
+            .is_err());
+    }
+
+    #[cfg(feature = "arkworks")]
+    #[test]
+    fn ark_fields_report_their_size() {
+        use crate::tests::{F64Ext3, F64};
+        let base = F64::field_size_bits();
+        assert!(base > 63.999_999 && base < 64.0, "{base}");
+        assert!((F64Ext3::field_size_bits() - 3.0 * base).abs() < 1e-9);
+
+        // Goldilocks alone: 30 rounds of degree 3 stay well below 100 bits.
+        let config = SecurityConfig::new(100);
+        assert!(config.check::<F64, _>(&|_| 3, 30).is_err());
+        assert_eq!(config.check::<F64Ext3, _>(&|_| 3, 30), Ok(()));
+    }
+
+    #[cfg(feature = "arkworks")]
+    #[test]
+    fn verifier_refuses_before_reading_transcript() {
+        use crate::provers::multilinear::MultilinearProver;
+        use crate::runner::sumcheck;
+        use crate::tests::{ReplayTranscript, F64};
+        use crate::verifier::sumcheck_verify_with_config;
+
+        let evals: Vec<F64> = (0..16u64).map(F64::from).collect();
+        let claim = evals.iter().sum();
+        let mut t = ReplayTranscript::new(0x43);
+        sumcheck(&mut MultilinearProver::new(evals), 4, &mut t, |_, _| {});
+
+        let mut replay = t.replay();
+        let err = sumcheck_verify_with_config(
+            claim,
+            1,
+            4,
+            &SecurityConfig::new(100),
+            &mut replay,
+            |_, _| Ok(()),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            SumcheckError::InsufficientSecurity {
+                required: 100,
+                achieved: 61,
+            }
+        );
+        // Nothing was consumed: the same transcript still verifies.
+        let config = SecurityConfig::new(60);
+        sumcheck_verify_with_config(claim, 1, 4, &config, &mut replay, |_, _| Ok(())).unwrap();
+    }
+}
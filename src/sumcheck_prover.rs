@@ -37,9 +37,20 @@ pub trait SumcheckProver<F: SumcheckField> {
     /// Maximum degree of the round polynomial in the current variable.
     fn degree(&self) -> usize;
 
+    /// Degree of the round polynomial in round `round` (0-indexed).
+    ///
+    /// Defaults to [`degree()`](Self::degree) for every round. Provers whose
+    /// polynomial has a different degree per variable override this and
+    /// emit `round_degree(j)` values in round `j`; the verifier side is
+    /// [`sumcheck_verify_with_degrees`](crate::verifier::sumcheck_verify_with_degrees).
+    fn round_degree(&self, round: usize) -> usize {
+        let _ = round;
+        self.degree()
+    }
+
     /// Compute the round polynomial and advance state.
     ///
-    /// Returns `d = degree()` values per round in the **EvalsInfty** wire
+    /// Returns `d = round_degree(j)` values in round `j` in the **EvalsInfty** wire
     /// format:
     ///
     /// - `d == 1`: `[g_j(0)]` — verifier derives `g_j(1) = claim - g_j(0)`.
@@ -48,6 +59,10 @@ pub trait SumcheckProver<F: SumcheckField> {
     ///   The verifier derives `g_j(1) = claim - g_j(0)` from the consistency
     ///   constraint `g_j(0) + g_j(1) = claim`.
     ///
+    /// Here `g_j(i)` for `i ≥ 2` means `g_j(F::eval_node(i))`
+    /// ([`SumcheckField::eval_node`]), the integer `i` unless the field
+    /// overrides it (characteristic 2).
+    ///
     /// One wire element per round is saved versus sending explicit
     /// evaluations at `{0, 1, ..., d}`. The leading-coefficient form is
     /// also typically the cheapest round-polynomial contribution to compute
@@ -65,7 +80,33 @@ pub trait SumcheckProver<F: SumcheckField> {
     /// `final_value()` can return `g(r_1, ..., r_v)`.
     fn finalize(&mut self, last_challenge: F);
 
+    /// Number of entries left in the prover's folded table(s), for
+    /// hooks and logging ([`ProverRoundContext`](crate::session::ProverRoundContext)).
+    ///
+    /// After [`round()`](Self::round) for round `j` this is the size the
+    /// round polynomial was computed from (`2^{v-j}` for a full table).
+    /// Defaults to `None` for provers that do not report it.
+    fn table_len(&self) -> Option<usize> {
+        None
+    }
+
     /// After all rounds and [`finalize()`](Self::finalize): the claimed
     /// value `g(r_1, ..., r_v)`.
     fn final_value(&self) -> F;
 }
+
+/// Implements `table_len` as the length of the prover's table: a field
+/// name (`impl_table_len!(evals)`) or a closure over the prover
+/// (`impl_table_len!(|p| p.tables[0].len())`).
+macro_rules! impl_table_len {
+    ($table:ident) => {
+        $crate::sumcheck_prover::impl_table_len!(|p| p.$table.len());
+    };
+    (|$p:ident| $len:expr) => {
+        fn table_len(&self) -> Option<usize> {
+            let $p = self;
+            Some($len)
+        }
+    };
+}
+pub(crate) use impl_table_len;
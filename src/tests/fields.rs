@@ -1,7 +1,8 @@
 use ark_ff::define_field;
 use ark_ff::fields::models::cubic_extension::{CubicExtConfig, CubicExtField};
 use ark_ff::fields::models::quadratic_extension::{QuadExtConfig, QuadExtField};
-use ark_ff::fields::{Fp128, Fp64, MontBackend, MontConfig};
+use ark_ff::fields::{Fp128, Fp2, Fp2Config, Fp4, Fp4Config, Fp64, MontBackend, MontConfig};
+use ark_ff::{AdditiveGroup, Field};
 
 #[derive(MontConfig)]
 #[modulus = "19"]
@@ -99,6 +100,24 @@ impl CubicExtConfig for F64Ext3Config {
 }
 pub type F64Ext3 = CubicExtField<F64Ext3Config>;
 
+// Two-level tower over Goldilocks: F64Fp2 = F64[u] / (u² - 7), then
+// F64Fp4 = F64Fp2[v] / (v² - u). Frobenius is unused, as above.
+pub struct F64Fp2Config;
+impl Fp2Config for F64Fp2Config {
+    type Fp = F64;
+    const NONRESIDUE: F64 = F64::from_raw(7);
+    const FROBENIUS_COEFF_FP2_C1: &'static [F64] = F64Ext2Config::FROBENIUS_COEFF_C1;
+}
+pub type F64Fp2 = Fp2<F64Fp2Config>;
+
+pub struct F64Fp4Config;
+impl Fp4Config for F64Fp4Config {
+    type Fp2Config = F64Fp2Config;
+    const NONRESIDUE: F64Fp2 = F64Fp2::new(F64::ZERO, F64::ONE);
+    const FROBENIUS_COEFF_FP4_C1: &'static [F64] = &[F64::ONE; 4];
+}
+pub type F64Fp4 = Fp4<F64Fp4Config>;
+
 #[derive(MontConfig)]
 #[modulus = "143244528689204659050391023439224324689"]
 #[generator = "2"]
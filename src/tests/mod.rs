@@ -1,7 +1,9 @@
 #[allow(clippy::assign_op_pattern)]
 mod fields;
 mod streams;
+mod transcript;
 
 pub mod polynomials;
-pub use fields::{BabyBear, F64Ext2, F64Ext3, FpF64, F128, F19, F64, M31};
+pub use fields::{BabyBear, F64Ext2, F64Ext3, F64Fp2, F64Fp4, FpF64, F128, F19, F64, M31};
 pub use streams::BenchStream;
+pub use transcript::{ChainTranscript, ReplayTranscript};
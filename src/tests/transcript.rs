@@ -0,0 +1,105 @@
+use ark_std::rand::{rngs::StdRng, RngCore, SeedableRng};
+
+use crate::field::SumcheckField;
+use crate::transcript::{ProverTranscript, VerifierTranscript};
+
+/// Test transcript that records prover messages and challenges, then
+/// replays them to a verifier.
+///
+/// Prove with the transcript, call [`replay`](Self::replay) (or
+/// [`rewind`](Self::rewind)) and verify with it: the verifier reads back
+/// the same sequence of field elements, so any tampering with `tape`
+/// between the two shows up as a rejected proof.
+#[derive(Clone, Debug)]
+pub struct ReplayTranscript<F> {
+    /// Recorded field elements (prover messages interleaved with challenges).
+    pub tape: Vec<F>,
+    /// Current read position for the verifier.
+    pub cursor: usize,
+    rng: StdRng,
+}
+
+impl<F> ReplayTranscript<F> {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            tape: Vec::new(),
+            cursor: 0,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// A fresh reader over the recorded tape.
+    pub fn replay(&self) -> Self
+    where
+        F: Clone,
+    {
+        Self {
+            tape: self.tape.clone(),
+            cursor: 0,
+            rng: self.rng.clone(),
+        }
+    }
+
+    /// Reset the cursor to replay from the beginning.
+    pub fn rewind(&mut self) {
+        self.cursor = 0;
+    }
+}
+
+impl<F: SumcheckField> ProverTranscript<F> for ReplayTranscript<F> {
+    fn send(&mut self, value: F) {
+        self.tape.push(value);
+    }
+
+    fn challenge(&mut self) -> F {
+        let c = F::from_u64(self.rng.next_u64());
+        self.tape.push(c);
+        c
+    }
+}
+
+impl<F: SumcheckField> VerifierTranscript<F> for ReplayTranscript<F> {
+    type Error = ();
+
+    fn receive(&mut self) -> Result<F, ()> {
+        let v = self.tape.get(self.cursor).copied().ok_or(())?;
+        self.cursor += 1;
+        Ok(v)
+    }
+
+    fn challenge(&mut self) -> F {
+        // An exhausted tape yields zero; the verifier then fails its checks.
+        let v = self.tape.get(self.cursor).copied().unwrap_or(F::ZERO);
+        self.cursor += 1;
+        v
+    }
+}
+
+/// Deterministic transcript whose challenges depend on every absorbed
+/// message (a toy algebraic hash chain, not a secure sponge).
+///
+/// Stands in for a Fiat–Shamir sponge when checking stored proofs: a fresh
+/// `ChainTranscript::new(domain)` re-derives the prover's challenges.
+#[derive(Clone, Debug)]
+pub struct ChainTranscript<F> {
+    pub state: F,
+}
+
+impl<F: SumcheckField> ChainTranscript<F> {
+    pub fn new(domain: u64) -> Self {
+        Self {
+            state: F::from_u64(domain),
+        }
+    }
+}
+
+impl<F: SumcheckField> ProverTranscript<F> for ChainTranscript<F> {
+    fn send(&mut self, value: F) {
+        self.state = self.state * F::from_u64(0x9E37_79B9) + value;
+    }
+
+    fn challenge(&mut self) -> F {
+        self.state = self.state * self.state + F::from_u64(7);
+        self.state
+    }
+}
@@ -0,0 +1,502 @@
+//! Native Fiat–Shamir transcripts over a duplex sponge.
+//!
+//! [`DuplexProverTranscript`] writes every prover message to a byte
+//! string (the proof) and absorbs the same bytes into a sponge;
+//! challenges are squeezed from the sponge. [`DuplexVerifierTranscript`]
+//! reads the messages back from a byte slice, absorbing them the same way,
+//! so both sides squeeze identical challenges. Field elements use their
+//! canonical [`FieldBytes`] encoding, and non-canonical bytes are rejected.
+//!
+//! Challenges are sampled by rejection: the sponge is squeezed until
+//! [`FieldBytes::from_uniform_bytes`] accepts, so they are exactly uniform.
+//!
+//! Sponges ([`DuplexSponge`]):
+//! - [`KeccakSponge`] (feature `keccak`): Keccak-f\[1600\] with rate 136
+//!   and SHA-3 padding, i.e. absorb-then-squeeze is SHA3-256.
+//! - [`Sha256Sponge`] (feature `sha2`) and [`Blake3Sponge`] (feature
+//!   `blake3`): a [`HashChainSponge`] over a 256-bit hash.
+//!
+//! ```ignore
+//! let mut t = DuplexProverTranscript::<KeccakSponge>::new(b"my-protocol");
+//! let proof = sumcheck(&mut prover, v, &mut t, noop_hook);
+//! let bytes = t.into_proof();
+//!
+//! let mut t = DuplexVerifierTranscript::<KeccakSponge>::new(b"my-protocol", &bytes);
+//! let result = sumcheck_verify(claimed_sum, degree, v, &mut t, noop_hook_verify)?;
+//! t.finish()?;
+//! ```
+
+use crate::field::FieldBytes;
+use crate::transcript::{ProverTranscript, VerifierTranscript};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+/// A duplex sponge: absorb bytes, squeeze bytes, interleaved freely.
+///
+/// Squeezed output depends on everything absorbed before it, including
+/// where the absorb/squeeze switches happened.
+pub trait DuplexSponge: Default {
+    /// Absorb `bytes`.
+    fn absorb(&mut self, bytes: &[u8]);
+
+    /// Fill `out` with output.
+    fn squeeze(&mut self, out: &mut [u8]);
+}
+
+/// Fresh sponge with the length-prefixed `domain` absorbed.
+fn domain_sponge<S: DuplexSponge>(domain: &[u8]) -> S {
+    let mut sponge = S::default();
+    sponge.absorb(&(domain.len() as u64).to_le_bytes());
+    sponge.absorb(domain);
+    sponge
+}
+
+/// Squeeze until `F` accepts the bytes.
+fn squeeze_field<F: FieldBytes, S: DuplexSponge>(sponge: &mut S) -> F {
+    let mut bytes = vec![0u8; F::sample_bytes()];
+    loop {
+        sponge.squeeze(&mut bytes);
+        if let Some(value) = F::from_uniform_bytes(&bytes) {
+            return value;
+        }
+    }
+}
+
+/// Prover side: appends each message to the proof bytes and absorbs it.
+#[derive(Clone, Debug)]
+pub struct DuplexProverTranscript<S> {
+    sponge: S,
+    proof: Vec<u8>,
+}
+
+impl<S: DuplexSponge> DuplexProverTranscript<S> {
+    /// Start a transcript for the protocol identified by `domain`.
+    pub fn new(domain: &[u8]) -> Self {
+        Self {
+            sponge: domain_sponge(domain),
+            proof: Vec::new(),
+        }
+    }
+
+    /// The proof bytes written so far.
+    pub fn proof(&self) -> &[u8] {
+        &self.proof
+    }
+
+    /// Consume the transcript, returning the proof bytes.
+    pub fn into_proof(self) -> Vec<u8> {
+        self.proof
+    }
+}
+
+impl<F: FieldBytes, S: DuplexSponge> ProverTranscript<F> for DuplexProverTranscript<S> {
+    fn send(&mut self, value: F) {
+        let start = self.proof.len();
+        value.write_bytes(&mut self.proof);
+        self.sponge.absorb(&self.proof[start..]);
+    }
+
+    fn challenge(&mut self) -> F {
+        squeeze_field(&mut self.sponge)
+    }
+}
+
+/// Why a verifier transcript ([`DuplexVerifierTranscript`],
+/// [`Poseidon2VerifierTranscript`](super::Poseidon2VerifierTranscript))
+/// rejected its proof data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DuplexTranscriptError {
+    /// The proof ends before the next message does.
+    Exhausted,
+    /// The next message is not a canonical field element encoding.
+    NonCanonical,
+    /// Proof data is left over after the last message.
+    TrailingBytes,
+}
+
+impl fmt::Display for DuplexTranscriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DuplexTranscriptError::Exhausted => write!(f, "proof bytes exhausted"),
+            DuplexTranscriptError::NonCanonical => {
+                write!(f, "non-canonical field element encoding")
+            }
+            DuplexTranscriptError::TrailingBytes => write!(f, "trailing proof bytes"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DuplexTranscriptError {}
+
+/// Verifier side: reads each message from the proof bytes and absorbs it.
+#[derive(Clone, Debug)]
+pub struct DuplexVerifierTranscript<'a, S> {
+    sponge: S,
+    proof: &'a [u8],
+}
+
+impl<'a, S: DuplexSponge> DuplexVerifierTranscript<'a, S> {
+    /// Read `proof` for the protocol identified by `domain`.
+    pub fn new(domain: &[u8], proof: &'a [u8]) -> Self {
+        Self {
+            sponge: domain_sponge(domain),
+            proof,
+        }
+    }
+
+    /// The proof bytes not read yet.
+    pub fn remaining(&self) -> &'a [u8] {
+        self.proof
+    }
+
+    /// Check that the whole proof was read.
+    pub fn finish(self) -> Result<(), DuplexTranscriptError> {
+        if self.proof.is_empty() {
+            Ok(())
+        } else {
+            Err(DuplexTranscriptError::TrailingBytes)
+        }
+    }
+}
+
+impl<'a, F: FieldBytes, S: DuplexSponge> VerifierTranscript<F> for DuplexVerifierTranscript<'a, S> {
+    type Error = DuplexTranscriptError;
+
+    fn receive(&mut self) -> Result<F, Self::Error> {
+        let len = F::num_bytes();
+        if self.proof.len() < len {
+            return Err(DuplexTranscriptError::Exhausted);
+        }
+        let (bytes, rest) = self.proof.split_at(len);
+        let value = F::read_bytes(bytes).ok_or(DuplexTranscriptError::NonCanonical)?;
+        self.sponge.absorb(bytes);
+        self.proof = rest;
+        Ok(value)
+    }
+
+    fn challenge(&mut self) -> F {
+        squeeze_field(&mut self.sponge)
+    }
+}
+
+// ─── Keccak ─────────────────────────────────────────────────────────────────
+
+/// Keccak-f\[1600\] duplex sponge with rate 136 bytes (capacity 512 bits).
+///
+/// Each switch from absorbing to squeezing applies SHA-3 padding
+/// (`0x06 … 0x80`) and permutes, so absorbing `m` into a fresh sponge and
+/// squeezing 32 bytes yields `SHA3-256(m)`.
+#[cfg(feature = "keccak")]
+#[derive(Clone, Debug, Default)]
+pub struct KeccakSponge {
+    state: [u64; 25],
+    /// Next rate byte to absorb into or squeeze from.
+    pos: usize,
+    squeezing: bool,
+}
+
+#[cfg(feature = "keccak")]
+impl KeccakSponge {
+    const RATE: usize = 136;
+
+    fn xor_byte(&mut self, i: usize, byte: u8) {
+        self.state[i / 8] ^= (byte as u64) << (8 * (i % 8));
+    }
+
+    fn byte(&self, i: usize) -> u8 {
+        (self.state[i / 8] >> (8 * (i % 8))) as u8
+    }
+
+    fn permute(&mut self) {
+        keccak::f1600(&mut self.state);
+        self.pos = 0;
+    }
+}
+
+#[cfg(feature = "keccak")]
+impl DuplexSponge for KeccakSponge {
+    fn absorb(&mut self, bytes: &[u8]) {
+        if self.squeezing {
+            self.squeezing = false;
+            self.pos = 0;
+        }
+        for &byte in bytes {
+            if self.pos == Self::RATE {
+                self.permute();
+            }
+            self.xor_byte(self.pos, byte);
+            self.pos += 1;
+        }
+    }
+
+    fn squeeze(&mut self, out: &mut [u8]) {
+        if !self.squeezing {
+            if self.pos == Self::RATE {
+                self.permute();
+            }
+            self.xor_byte(self.pos, 0x06);
+            self.xor_byte(Self::RATE - 1, 0x80);
+            self.permute();
+            self.squeezing = true;
+        }
+        for byte in out {
+            if self.pos == Self::RATE {
+                self.permute();
+            }
+            *byte = self.byte(self.pos);
+            self.pos += 1;
+        }
+    }
+}
+
+// ─── Hash chaining ──────────────────────────────────────────────────────────
+
+/// A 256-bit hash function, the building block of [`HashChainSponge`].
+pub trait ChainHash {
+    /// Hash the concatenation of `parts`.
+    fn hash(parts: &[&[u8]]) -> [u8; 32];
+}
+
+#[cfg(feature = "sha2")]
+impl ChainHash for sha2::Sha256 {
+    fn hash(parts: &[&[u8]]) -> [u8; 32] {
+        use sha2::Digest;
+        let mut hasher = Self::new();
+        for part in parts {
+            hasher.update(part);
+        }
+        hasher.finalize().into()
+    }
+}
+
+#[cfg(feature = "blake3")]
+impl ChainHash for blake3::Hasher {
+    fn hash(parts: &[&[u8]]) -> [u8; 32] {
+        let mut hasher = Self::new();
+        for part in parts {
+            hasher.update(part);
+        }
+        *hasher.finalize().as_bytes()
+    }
+}
+
+/// Duplex sponge built from a 256-bit hash `H` by chaining a 32-byte state.
+///
+/// Absorbed bytes are buffered. The first squeeze after absorbing sets
+/// `state ← H(0 ‖ state ‖ len ‖ bytes)`; each 32-byte output block is
+/// `H(1 ‖ state)`, after which `state ← H(2 ‖ state)`. Absorbing discards
+/// the rest of the current output block.
+#[derive(Clone, Debug)]
+pub struct HashChainSponge<H> {
+    state: [u8; 32],
+    pending: Vec<u8>,
+    block: [u8; 32],
+    /// Bytes of `block` already squeezed.
+    used: usize,
+    _hash: PhantomData<fn() -> H>,
+}
+
+impl<H> Default for HashChainSponge<H> {
+    fn default() -> Self {
+        Self {
+            state: [0; 32],
+            pending: Vec::new(),
+            block: [0; 32],
+            used: 32,
+            _hash: PhantomData,
+        }
+    }
+}
+
+impl<H: ChainHash> DuplexSponge for HashChainSponge<H> {
+    fn absorb(&mut self, bytes: &[u8]) {
+        self.pending.extend_from_slice(bytes);
+        self.used = 32;
+    }
+
+    fn squeeze(&mut self, out: &mut [u8]) {
+        if !self.pending.is_empty() {
+            let len = (self.pending.len() as u64).to_le_bytes();
+            self.state = H::hash(&[&[0], &self.state, &len, &self.pending]);
+            self.pending.clear();
+        }
+        for byte in out {
+            if self.used == 32 {
+                self.block = H::hash(&[&[1], &self.state]);
+                self.state = H::hash(&[&[2], &self.state]);
+                self.used = 0;
+            }
+            *byte = self.block[self.used];
+            self.used += 1;
+        }
+    }
+}
+
+/// [`HashChainSponge`] over SHA-256.
+#[cfg(feature = "sha2")]
+pub type Sha256Sponge = HashChainSponge<sha2::Sha256>;
+
+/// [`HashChainSponge`] over BLAKE3.
+#[cfg(feature = "blake3")]
+pub type Blake3Sponge = HashChainSponge<blake3::Hasher>;
+
+#[cfg(all(
+    test,
+    feature = "arkworks",
+    feature = "keccak",
+    feature = "sha2",
+    feature = "blake3"
+))]
+mod tests {
+    use super::*;
+    use crate::binary_field::Gf2_128;
+    use crate::field::SumcheckField;
+    use crate::provers::inner_product::InnerProductProver;
+    use crate::provers::multilinear::MultilinearProver;
+    use crate::runner::sumcheck;
+    use crate::tests::{F64Ext3, FpF64, F64};
+    use crate::verifier::sumcheck_verify;
+    use ark_ff::UniformRand;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    const DOMAIN: &[u8] = b"effsc-duplex-test";
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn keccak_sponge_matches_sha3_256() {
+        let sha3 = |msg: &[u8]| {
+            let mut sponge = KeccakSponge::default();
+            sponge.absorb(msg);
+            let mut out = [0u8; 32];
+            sponge.squeeze(&mut out);
+            hex(&out)
+        };
+        assert_eq!(
+            sha3(b""),
+            "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a"
+        );
+        assert_eq!(
+            sha3(b"abc"),
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"
+        );
+        // Two blocks: the padding goes into a block of its own.
+        assert_eq!(
+            sha3(&[0xa3; 200]),
+            "79f38adec5c20307a98ef76e8324afbfd46cfd81b22e3973c65fa1bd9de31787"
+        );
+    }
+
+    /// Prove `Σ a·b` over `F`, verify from the proof bytes, then tamper.
+    fn roundtrip<F: FieldBytes + UniformRand, S: DuplexSponge>() {
+        let mut rng = StdRng::seed_from_u64(0x49);
+        let a: Vec<F> = (0..16).map(|_| F::rand(&mut rng)).collect();
+        let b: Vec<F> = (0..16).map(|_| F::rand(&mut rng)).collect();
+        let claim: F = a.iter().zip(&b).map(|(x, y)| *x * *y).sum();
+
+        let mut t = DuplexProverTranscript::<S>::new(DOMAIN);
+        let proof = sumcheck(&mut InnerProductProver::new(a, b), 4, &mut t, |_, _| {});
+        let bytes = t.into_proof();
+        assert_eq!(bytes.len(), 4 * 2 * F::num_bytes());
+
+        let mut t = DuplexVerifierTranscript::<S>::new(DOMAIN, &bytes);
+        let result = sumcheck_verify(claim, 2, 4, &mut t, |_, _| Ok(())).unwrap();
+        t.finish().unwrap();
+        assert_eq!(result.challenges, proof.challenges);
+        assert_eq!(result.final_claim, proof.final_value);
+
+        // Another domain, or one flipped bit, changes every later challenge.
+        let mut t = DuplexVerifierTranscript::<S>::new(b"other", &bytes);
+        let other = sumcheck_verify(claim, 2, 4, &mut t, |_, _| Ok(())).unwrap();
+        assert_ne!(other.challenges[0], proof.challenges[0]);
+        let mut tampered = bytes.clone();
+        tampered[F::num_bytes()] ^= 1;
+        let mut t = DuplexVerifierTranscript::<S>::new(DOMAIN, &tampered);
+        let forged = sumcheck_verify(claim, 2, 4, &mut t, |_, _| Ok(())).unwrap();
+        assert_ne!(forged.final_claim, proof.final_value);
+
+        // Truncated and padded proofs.
+        let mut t = DuplexVerifierTranscript::<S>::new(DOMAIN, &bytes[..bytes.len() - 1]);
+        assert!(sumcheck_verify(claim, 2, 4, &mut t, |_, _| Ok(())).is_err());
+        let mut padded = bytes.clone();
+        padded.push(0);
+        let mut t = DuplexVerifierTranscript::<S>::new(DOMAIN, &padded);
+        sumcheck_verify(claim, 2, 4, &mut t, |_, _| Ok(())).unwrap();
+        assert_eq!(t.finish(), Err(DuplexTranscriptError::TrailingBytes));
+    }
+
+    #[test]
+    fn transcripts_roundtrip_over_every_sponge() {
+        roundtrip::<F64, KeccakSponge>();
+        roundtrip::<F64Ext3, KeccakSponge>();
+        roundtrip::<F64, Sha256Sponge>();
+        roundtrip::<F64Ext3, Sha256Sponge>();
+        roundtrip::<F64, Blake3Sponge>();
+        roundtrip::<F64Ext3, Blake3Sponge>();
+    }
+
+    #[test]
+    fn binary_field_transcript() {
+        let evals: Vec<Gf2_128> = (0..16u128).map(|i| Gf2_128::new(i * 0x9E37)).collect();
+        let claim: Gf2_128 = evals.iter().copied().sum();
+        let mut t = DuplexProverTranscript::<Sha256Sponge>::new(DOMAIN);
+        let proof = sumcheck(&mut MultilinearProver::new(evals), 4, &mut t, |_, _| {});
+        let bytes = t.into_proof();
+        assert_eq!(bytes.len(), 4 * 16);
+        let mut t = DuplexVerifierTranscript::<Sha256Sponge>::new(DOMAIN, &bytes);
+        let result = sumcheck_verify(claim, 1, 4, &mut t, |_, _| Ok(())).unwrap();
+        assert_eq!(result.challenges, proof.challenges);
+    }
+
+    #[test]
+    fn encoding_is_the_integer_value() {
+        // F64 (SmallFp) and FpF64 (MontBackend) store different Montgomery
+        // forms but must write the same bytes.
+        let x = 0x0123_4567_89AB_CDEFu64;
+        let (mut small, mut mont) = (Vec::new(), Vec::new());
+        F64::from(x).write_bytes(&mut small);
+        FpF64::from(x).write_bytes(&mut mont);
+        assert_eq!(small, x.to_le_bytes());
+        assert_eq!(mont, small);
+        assert_eq!(F64::read_bytes(&small), Some(F64::from(x)));
+
+        let ext = F64Ext3::new(F64::from(1u64), F64::from(2u64), F64::from(3u64));
+        let mut bytes = Vec::new();
+        ext.write_bytes(&mut bytes);
+        assert_eq!(&bytes[8..16], &2u64.to_le_bytes());
+        assert_eq!(F64Ext3::read_bytes(&bytes), Some(ext));
+        assert_eq!(F64Ext3::read_bytes(&bytes[..23]), None);
+    }
+
+    #[test]
+    fn non_canonical_elements_rejected() {
+        // p = 2^64 − 2^32 + 1 itself is not a canonical encoding.
+        let p = 0xFFFF_FFFF_0000_0001u64.to_le_bytes();
+        let mut t = DuplexVerifierTranscript::<KeccakSponge>::new(DOMAIN, &p);
+        let received: Result<F64, _> = t.receive();
+        assert_eq!(received, Err(DuplexTranscriptError::NonCanonical));
+        let mut t = DuplexVerifierTranscript::<KeccakSponge>::new(DOMAIN, &p[..7]);
+        let received: Result<F64, _> = t.receive();
+        assert_eq!(received, Err(DuplexTranscriptError::Exhausted));
+    }
+
+    #[test]
+    fn sampling_rejects_out_of_range_bytes() {
+        let p = 0xFFFF_FFFF_0000_0001u64;
+        assert_eq!(F64::sample_bytes(), 8);
+        assert_eq!(F64Ext3::sample_bytes(), 24);
+        assert_eq!(F64::from_uniform_bytes(&p.to_le_bytes()), None);
+        assert_eq!(
+            F64::from_uniform_bytes(&(p - 1).to_le_bytes()),
+            Some(-F64::ONE)
+        );
+        let mut bytes = [0u8; 24];
+        bytes[16..].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert_eq!(F64Ext3::from_uniform_bytes(&bytes), None);
+    }
+}
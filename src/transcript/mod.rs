@@ -1,8 +1,23 @@
+mod duplex;
+mod poseidon2;
 mod sanity;
 #[cfg(feature = "spongefish")]
 mod spongefish;
 #[allow(clippy::module_inception)]
 mod transcript;
 
+#[cfg(feature = "blake3")]
+pub use duplex::Blake3Sponge;
+#[cfg(feature = "keccak")]
+pub use duplex::KeccakSponge;
+#[cfg(feature = "sha2")]
+pub use duplex::Sha256Sponge;
+pub use duplex::{
+    ChainHash, DuplexProverTranscript, DuplexSponge, DuplexTranscriptError,
+    DuplexVerifierTranscript, HashChainSponge,
+};
+pub use poseidon2::{
+    poseidon2_goldilocks, Poseidon2ProverTranscript, Poseidon2Sponge, Poseidon2VerifierTranscript,
+};
 pub use sanity::{SanityTranscript, TestTranscript};
 pub use transcript::{ProverTranscript, VerifierTranscript};
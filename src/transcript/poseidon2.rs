@@ -0,0 +1,609 @@
+//! Poseidon2 Fiat–Shamir transcript over Goldilocks.
+//!
+//! SHA-256 or Keccak cost thousands of constraints per call inside a
+//! Goldilocks-native circuit (Plonky2/3 style); Poseidon2 works on field
+//! elements directly. [`Poseidon2ProverTranscript`] absorbs each prover
+//! message as its Goldilocks components and records them as the proof;
+//! [`Poseidon2VerifierTranscript`] reads them back from a `&[u64]` slice.
+//! Challenges are squeezed one component at a time, so a base-field
+//! challenge takes one squeezed element and an `F64Ext2` / `F64Ext3`
+//! challenge two or three, each exactly uniform.
+//!
+//! The permutation ([`poseidon2_goldilocks`]) is the width-12 Poseidon2
+//! instance of the reference implementation (x^7 S-box, 8 full and 22
+//! partial rounds); the constants below are taken from it
+//! (HorizenLabs `poseidon2`, `poseidon2_instance_goldilocks.rs`, MIT OR
+//! Apache-2.0). [`Poseidon2Sponge`] runs it as an overwrite-mode duplex
+//! sponge with rate 8 and capacity 4, as Plonky2's challenger does.
+//!
+//! Any field with [`FieldBytes`] over the Goldilocks prime works; other
+//! fields panic on first use.
+//!
+//! ```ignore
+//! let mut t = Poseidon2ProverTranscript::new(b"my-protocol");
+//! let proof = sumcheck(&mut prover, v, &mut t, noop_hook); // F64Ext3 prover
+//! let limbs: Vec<u64> = t.into_proof();
+//!
+//! let mut t = Poseidon2VerifierTranscript::new(b"my-protocol", &limbs);
+//! let result = sumcheck_verify(claimed_sum, degree, v, &mut t, noop_hook_verify)?;
+//! t.finish()?;
+//! ```
+
+use crate::field::{FieldBytes, GOLDILOCKS_P};
+use crate::transcript::{DuplexTranscriptError, ProverTranscript, VerifierTranscript};
+use alloc::vec::Vec;
+
+/// Permutation state width.
+const WIDTH: usize = 12;
+/// Elements absorbed or squeezed per permutation.
+const RATE: usize = 8;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 22;
+
+/// `2^64 mod p`.
+const EPSILON: u64 = 0xFFFF_FFFF;
+
+/// `x mod p`, using `2^64 ≡ 2^32 − 1` and `2^96 ≡ −1`.
+fn reduce128(x: u128) -> u64 {
+    let lo = x as u64;
+    let hi = (x >> 64) as u64;
+    let (hi_hi, hi_lo) = (hi >> 32, hi & EPSILON);
+    let (mut t0, borrow) = lo.overflowing_sub(hi_hi);
+    if borrow {
+        t0 = t0.wrapping_sub(EPSILON);
+    }
+    let (mut t1, carry) = t0.overflowing_add(hi_lo * EPSILON);
+    if carry {
+        t1 = t1.wrapping_add(EPSILON);
+    }
+    if t1 >= GOLDILOCKS_P {
+        t1 - GOLDILOCKS_P
+    } else {
+        t1
+    }
+}
+
+fn add(a: u64, b: u64) -> u64 {
+    reduce128(a as u128 + b as u128)
+}
+
+fn mul(a: u64, b: u64) -> u64 {
+    reduce128(a as u128 * b as u128)
+}
+
+fn sbox(x: u64) -> u64 {
+    let x2 = mul(x, x);
+    let x4 = mul(x2, x2);
+    mul(x4, mul(x2, x))
+}
+
+/// The 4×4 MDS matrix `M_4` of the Poseidon2 paper, applied to each block
+/// of four, then the block-circulant `circ(2·M_4, M_4, M_4)` mixing.
+fn external_layer(state: &mut [u64; WIDTH]) {
+    for block in state.chunks_exact_mut(4) {
+        let t0 = add(block[0], block[1]);
+        let t1 = add(block[2], block[3]);
+        let t2 = add(add(block[1], block[1]), t1);
+        let t3 = add(add(block[3], block[3]), t0);
+        let t4 = add(mul(t1, 4), t3);
+        let t5 = add(mul(t0, 4), t2);
+        block[0] = add(t3, t5);
+        block[1] = t5;
+        block[2] = add(t2, t4);
+        block[3] = t4;
+    }
+    let mut sums = [0u64; 4];
+    for (i, &x) in state.iter().enumerate() {
+        sums[i % 4] = add(sums[i % 4], x);
+    }
+    for (i, x) in state.iter_mut().enumerate() {
+        *x = add(*x, sums[i % 4]);
+    }
+}
+
+/// `M_I = 1 + diag(MAT_DIAG_M_1)`: `x_i ← d_i·x_i + Σ_j x_j`.
+fn internal_layer(state: &mut [u64; WIDTH]) {
+    let sum = state.iter().fold(0, |acc, &x| add(acc, x));
+    for (x, &d) in state.iter_mut().zip(&MAT_DIAG_M_1) {
+        *x = add(mul(*x, d), sum);
+    }
+}
+
+/// The width-12 Poseidon2 permutation over Goldilocks.
+///
+/// Inputs may be any `u64` (read mod `p`); outputs are canonical.
+pub fn poseidon2_goldilocks(state: &mut [u64; WIDTH]) {
+    external_layer(state);
+    let (initial, terminal) = EXTERNAL_CONSTANTS.split_at(ROUNDS_F / 2);
+    for constants in initial {
+        for (x, &c) in state.iter_mut().zip(constants) {
+            *x = sbox(add(*x, c));
+        }
+        external_layer(state);
+    }
+    for &c in &INTERNAL_CONSTANTS {
+        state[0] = sbox(add(state[0], c));
+        internal_layer(state);
+    }
+    for constants in terminal {
+        for (x, &c) in state.iter_mut().zip(constants) {
+            *x = sbox(add(*x, c));
+        }
+        external_layer(state);
+    }
+}
+
+/// Overwrite-mode duplex sponge over [`poseidon2_goldilocks`] with rate 8
+/// and capacity 4.
+///
+/// Absorbed elements overwrite the rate part; a full rate, or a squeeze
+/// after absorbing, permutes. Squeezes read the rate part in order and
+/// permute once it is used up.
+#[derive(Clone, Debug, Default)]
+pub struct Poseidon2Sponge {
+    state: [u64; WIDTH],
+    /// Elements absorbed since the last permutation.
+    absorbed: usize,
+    /// Rate elements not squeezed yet.
+    available: usize,
+}
+
+impl Poseidon2Sponge {
+    /// The all-zero sponge.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorb `x mod p`.
+    pub fn absorb(&mut self, x: u64) {
+        self.available = 0;
+        self.state[self.absorbed] = reduce128(x as u128);
+        self.absorbed += 1;
+        if self.absorbed == RATE {
+            self.permute();
+        }
+    }
+
+    /// Squeeze one canonical element.
+    pub fn squeeze(&mut self) -> u64 {
+        if self.absorbed > 0 || self.available == 0 {
+            self.permute();
+        }
+        let x = self.state[RATE - self.available];
+        self.available -= 1;
+        x
+    }
+
+    fn permute(&mut self) {
+        poseidon2_goldilocks(&mut self.state);
+        self.absorbed = 0;
+        self.available = RATE;
+    }
+}
+
+/// Fresh sponge with `domain` absorbed: its length, then 7-byte
+/// little-endian chunks (each below `p`).
+fn domain_sponge(domain: &[u8]) -> Poseidon2Sponge {
+    let mut sponge = Poseidon2Sponge::new();
+    sponge.absorb(domain.len() as u64);
+    for chunk in domain.chunks(7) {
+        let mut bytes = [0u8; 8];
+        bytes[..chunk.len()].copy_from_slice(chunk);
+        sponge.absorb(u64::from_le_bytes(bytes));
+    }
+    sponge
+}
+
+/// Number of Goldilocks components of `F`.
+///
+/// Read off the [`FieldBytes`] encoding: one 8-byte limb per component,
+/// the first of which holds `p - 1` for `-1`.
+///
+/// # Panics
+///
+/// If `F` is not Goldilocks or an extension of it.
+fn num_limbs<F: FieldBytes>() -> usize {
+    let mut minus_one = Vec::with_capacity(F::num_bytes());
+    (-F::ONE).write_bytes(&mut minus_one);
+    assert!(
+        F::num_bytes() % 8 == 0
+            && minus_one.get(..8) == Some(&(GOLDILOCKS_P - 1).to_le_bytes()[..]),
+        "Poseidon2 transcript needs Goldilocks or an extension of it"
+    );
+    F::num_bytes() / 8
+}
+
+fn from_limbs<F: FieldBytes>(limbs: &[u64]) -> Option<F> {
+    let bytes: Vec<u8> = limbs.iter().flat_map(|x| x.to_le_bytes()).collect();
+    F::read_bytes(&bytes)
+}
+
+fn squeeze_field<F: FieldBytes>(sponge: &mut Poseidon2Sponge) -> F {
+    let limbs: Vec<u64> = (0..num_limbs::<F>()).map(|_| sponge.squeeze()).collect();
+    from_limbs(&limbs).expect("squeezed elements are canonical")
+}
+
+/// Prover side: appends each message's components to the proof and
+/// absorbs them.
+#[derive(Clone, Debug)]
+pub struct Poseidon2ProverTranscript {
+    sponge: Poseidon2Sponge,
+    proof: Vec<u64>,
+}
+
+impl Poseidon2ProverTranscript {
+    /// Start a transcript for the protocol identified by `domain`.
+    pub fn new(domain: &[u8]) -> Self {
+        Self {
+            sponge: domain_sponge(domain),
+            proof: Vec::new(),
+        }
+    }
+
+    /// The proof elements written so far.
+    pub fn proof(&self) -> &[u64] {
+        &self.proof
+    }
+
+    /// Consume the transcript, returning the proof elements.
+    pub fn into_proof(self) -> Vec<u64> {
+        self.proof
+    }
+}
+
+impl<F: FieldBytes> ProverTranscript<F> for Poseidon2ProverTranscript {
+    fn send(&mut self, value: F) {
+        let mut bytes = Vec::with_capacity(8 * num_limbs::<F>());
+        value.write_bytes(&mut bytes);
+        for limb in bytes.chunks_exact(8) {
+            let x = u64::from_le_bytes(limb.try_into().expect("8-byte chunk"));
+            self.sponge.absorb(x);
+            self.proof.push(x);
+        }
+    }
+
+    fn challenge(&mut self) -> F {
+        squeeze_field(&mut self.sponge)
+    }
+}
+
+/// Verifier side: reads each message's components from the proof and
+/// absorbs them.
+#[derive(Clone, Debug)]
+pub struct Poseidon2VerifierTranscript<'a> {
+    sponge: Poseidon2Sponge,
+    proof: &'a [u64],
+}
+
+impl<'a> Poseidon2VerifierTranscript<'a> {
+    /// Read `proof` for the protocol identified by `domain`.
+    pub fn new(domain: &[u8], proof: &'a [u64]) -> Self {
+        Self {
+            sponge: domain_sponge(domain),
+            proof,
+        }
+    }
+
+    /// The proof elements not read yet.
+    pub fn remaining(&self) -> &'a [u64] {
+        self.proof
+    }
+
+    /// Check that the whole proof was read.
+    pub fn finish(self) -> Result<(), DuplexTranscriptError> {
+        if self.proof.is_empty() {
+            Ok(())
+        } else {
+            Err(DuplexTranscriptError::TrailingBytes)
+        }
+    }
+}
+
+impl<'a, F: FieldBytes> VerifierTranscript<F> for Poseidon2VerifierTranscript<'a> {
+    type Error = DuplexTranscriptError;
+
+    fn receive(&mut self) -> Result<F, Self::Error> {
+        let len = num_limbs::<F>();
+        if self.proof.len() < len {
+            return Err(DuplexTranscriptError::Exhausted);
+        }
+        let (limbs, rest) = self.proof.split_at(len);
+        let value = from_limbs(limbs).ok_or(DuplexTranscriptError::NonCanonical)?;
+        for &x in limbs {
+            self.sponge.absorb(x);
+        }
+        self.proof = rest;
+        Ok(value)
+    }
+
+    fn challenge(&mut self) -> F {
+        squeeze_field(&mut self.sponge)
+    }
+}
+
+// ─── Constants (Poseidon2 reference, Goldilocks, t = 12) ─────────────────────
+
+/// `diag(M_I) − 1` for the internal linear layer.
+const MAT_DIAG_M_1: [u64; WIDTH] = [
+    0xC3B6_C08E_23BA_9300,
+    0xD84B_5DE9_4A32_4FB6,
+    0x0D0C_371C_5B35_B84F,
+    0x7964_F570_E718_8037,
+    0x5DAF_18BB_D996_604B,
+    0x6743_BC47_B959_5257,
+    0x5528_B936_2C59_BB70,
+    0xAC45_E25B_7127_B68B,
+    0xA207_7D7D_FBB6_06B5,
+    0xF3FA_AC6F_AEE3_78AE,
+    0x0C63_88B5_1545_E883,
+    0xD27D_BB69_4491_7B60,
+];
+
+/// Round constants of the four initial and four final full rounds.
+const EXTERNAL_CONSTANTS: [[u64; WIDTH]; ROUNDS_F] = [
+    [
+        0x13DC_F33A_BA21_4F46,
+        0x30B3_B654_A1DA_6D83,
+        0x1FC6_34AD_A615_9B56,
+        0x9374_5996_4DC0_3466,
+        0xEDD2_EF2C_A794_9924,
+        0xEDE9_AFFD_E0E2_2F68,
+        0x8515_B9D6_BAC9_282D,
+        0x6B5C_07B4_E9E9_00D8,
+        0x1EC6_6368_838C_8A08,
+        0x9042_367D_80D1_FBAB,
+        0x4002_8356_4A3C_3799,
+        0x4A00_BE04_66BC_A75E,
+    ],
+    [
+        0x7913_BEEE_58E3_817F,
+        0xF545_E885_3223_7D90,
+        0x22F8_CB87_3604_2005,
+        0x6F04_990E_247A_2623,
+        0xFE22_E87B_A37C_38CD,
+        0xD20E_32C8_5FFE_2815,
+        0x1172_2767_4048_FE73,
+        0x4E9F_B7EA_98A6_B145,
+        0xE086_6C23_2B8A_F08B,
+        0x00BB_C779_1688_4964,
+        0x7031_C0FB_990D_7116,
+        0x240A_9E87_CF35_108F,
+    ],
+    [
+        0x2E63_63A5_A122_44B3,
+        0x5E1C_3787_D1B5_011C,
+        0x4132_660E_2A19_6E8B,
+        0x3A01_3B64_8D3D_4327,
+        0xF798_39F4_9888_EA43,
+        0xFE85_658E_BAFE_1439,
+        0xB688_9825_A142_40BD,
+        0x5784_5360_5541_382B,
+        0x4508_CDA8_F6B6_3CE9,
+        0x9C3E_F358_4868_4C91,
+        0x0812_BDE2_3C87_178C,
+        0xFE49_638F_7F72_2C14,
+    ],
+    [
+        0x8E3F_688C_E885_CBF5,
+        0xB8E1_10AC_F746_A87D,
+        0xB4B2_E897_3A6D_ABEF,
+        0x9E71_4C5D_A3D4_62EC,
+        0x6438_F903_3D3D_0C15,
+        0x2431_2F7C_F1A2_7199,
+        0x23F8_43BB_47AC_BF71,
+        0x9183_F11A_34BE_9F01,
+        0x8390_62FB_B9D4_5DBF,
+        0x24B5_6E7E_6C2E_43FA,
+        0xE168_3DA6_1C96_2A72,
+        0xA95C_6397_1A19_BFA7,
+    ],
+    [
+        0xC68B_E7C9_4882_A24D,
+        0xAF99_6D5D_5CDA_EDD9,
+        0x9717_F025_E7DA_F6A5,
+        0x6436_679E_6E72_16F4,
+        0x8A22_3D99_047A_F267,
+        0xBB51_2E35_A133_BA9A,
+        0xFBBF_4409_7671_AA03,
+        0xF040_58EB_F681_1E61,
+        0x5CCA_8470_3FAC_7FFB,
+        0x9B55_C794_5DE6_469F,
+        0x8E05_BF09_808E_934F,
+        0x2EA9_00DE_8763_07D7,
+    ],
+    [
+        0x7748_FFF2_B38D_FB89,
+        0x6B99_A676_DD3B_5D81,
+        0xAC4B_B7C6_27CF_7C13,
+        0xADB6_EBE5_E9E2_F5BA,
+        0x2D33_378C_AFA2_4AE3,
+        0x1E5B_7380_7543_F8C2,
+        0x0920_8814_BFEB_B10F,
+        0x782E_64B6_BB5B_93DD,
+        0xADD5_A48E_AC90_B50F,
+        0xADD4_C54C_736E_A4B1,
+        0xD58D_BB86_ED81_7FD8,
+        0x6D5E_D1A5_33F3_4DDD,
+    ],
+    [
+        0x2868_6AA3_E36B_7CB9,
+        0x591A_BD34_7668_9F36,
+        0x047D_7666_78F1_3875,
+        0xA2A1_1112_625F_5B49,
+        0x21FD_10A3_F830_4958,
+        0xF9B4_0711_443B_0280,
+        0xD269_7EB8_B2BD_E88E,
+        0x3493_790B_5173_1B3F,
+        0x11CA_F9DD_7376_4023,
+        0x7ACF_B8F7_2878_164E,
+        0x744E_C4DB_23CE_FC26,
+        0x1E00_E58F_422C_6340,
+    ],
+    [
+        0x21DD_28D9_06A6_2DDA,
+        0xF32A_46AB_5F46_5B5F,
+        0xBFCE_1320_1F3F_7E6B,
+        0xF30D_2E7A_DB53_04E2,
+        0xECDF_4EE4_ABAD_48E9,
+        0xF94E_8218_2D39_5019,
+        0x4EE5_2E37_44D8_87C5,
+        0xA134_1C7C_AC00_83B2,
+        0x2302_FB26_C30C_834A,
+        0xAEA3_C587_273B_F7D3,
+        0xF798_E249_6182_3EC7,
+        0x962D_EBA3_E9A2_CD94,
+    ],
+];
+
+/// Round constants of the partial rounds (first state element only).
+const INTERNAL_CONSTANTS: [u64; ROUNDS_P] = [
+    0x4ADF_842A_A75D_4316,
+    0xF8FB_B871_AA4A_B4EB,
+    0x68E8_5B6E_B2DD_6AEB,
+    0x07A0_B06B_2D27_0380,
+    0xD94E_0228_BD28_2DE4,
+    0x8BDD_91D3_250C_5278,
+    0x209C_68B8_8BBA_778F,
+    0xB5E1_8CDA_B77F_3877,
+    0xB296_A3E8_08DA_93FA,
+    0x8370_ECBD_A11A_327E,
+    0x3F90_7528_3775_DAD8,
+    0xB780_95BB_23C6_AA84,
+    0x3F36_B9FE_72AD_4E5F,
+    0x69BC_9678_0B10_B553,
+    0x3F1D_341F_2EB7_B881,
+    0x4E93_9E98_1583_8818,
+    0xDA36_6B3A_E2A3_1604,
+    0xBC89_DB1E_7287_D509,
+    0x6102_F411_F9EF_5659,
+    0x5872_5C5E_7AC1_F0AB,
+    0x0DF5_856C_7988_83E7,
+    0xF7BB_62A8_DA4C_961B,
+];
+
+#[cfg(all(test, feature = "arkworks"))]
+mod tests {
+    use super::*;
+    use crate::provers::inner_product::InnerProductProver;
+    use crate::runner::sumcheck;
+    use crate::tests::{F64Ext2, F64Ext3, F64};
+    use crate::verifier::sumcheck_verify;
+    use ark_ff::UniformRand;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    const DOMAIN: &[u8] = b"effsc-poseidon2-test";
+
+    #[test]
+    fn permutation_known_answer() {
+        // Reference implementation test vector: input (0, 1, ..., 11).
+        let mut state: [u64; WIDTH] = core::array::from_fn(|i| i as u64);
+        poseidon2_goldilocks(&mut state);
+        assert_eq!(
+            state,
+            [
+                0x01EA_EF96_BDF1_C0C1,
+                0x1F0D_2CC5_25B2_540C,
+                0x6282_C1DF_E1E0_358D,
+                0xE780_D721_F698_E1E6,
+                0x280C_0B6F_753D_833B,
+                0x1B94_2DD5_0231_56AB,
+                0x43F0_DF3F_CCCB_8398,
+                0xE8E8_1905_8548_9025,
+                0x56BD_BF72_F77A_DA22,
+                0x7911_C32B_F9DC_D705,
+                0xEC46_7926_508F_BE67,
+                0x6A50_450D_DF85_A6ED,
+            ]
+        );
+    }
+
+    #[test]
+    fn reduction_matches_modulo() {
+        let p = GOLDILOCKS_P as u128;
+        let mut x = 0x9E37_79B9_7F4A_7C15_F39C_C060_5CED_C834u128;
+        for edge in [0, 1, p - 1, p, p + 1, u64::MAX as u128, u128::MAX] {
+            assert_eq!(reduce128(edge) as u128, edge % p);
+        }
+        for _ in 0..1000 {
+            x = x.wrapping_mul(0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645) ^ (x >> 17);
+            assert_eq!(reduce128(x) as u128, x % p);
+        }
+    }
+
+    #[test]
+    fn transcript_regression_vectors() {
+        let mut t = Poseidon2ProverTranscript::new(b"effsc-poseidon2-kat");
+        for i in 1..=3u64 {
+            t.send(F64::from(i));
+        }
+        let base: F64 = t.challenge();
+        let ext: F64Ext3 = t.challenge();
+        assert_eq!(t.proof(), &[1, 2, 3]);
+        // Recorded from this implementation: there is no external reference
+        // for this absorb/squeeze layout, so these only pin the transcript
+        // against accidental changes (the permutation itself is checked
+        // against the reference above).
+        assert_eq!(base, F64::from(0x3AFF_D7F7_2190_BCE0u64));
+        assert_eq!(
+            ext,
+            F64Ext3::new(
+                F64::from(0x8DD0_5977_F21D_D4DDu64),
+                F64::from(0xC917_3B4A_7CDA_8D84u64),
+                F64::from(0xDD44_CE46_A9B7_D944u64),
+            )
+        );
+    }
+
+    fn roundtrip<F: FieldBytes + UniformRand>() {
+        let mut rng = StdRng::seed_from_u64(0x50);
+        let a: Vec<F> = (0..16).map(|_| F::rand(&mut rng)).collect();
+        let b: Vec<F> = (0..16).map(|_| F::rand(&mut rng)).collect();
+        let claim: F = a.iter().zip(&b).map(|(x, y)| *x * *y).sum();
+        let d = F::extension_degree() as usize;
+
+        let mut t = Poseidon2ProverTranscript::new(DOMAIN);
+        let proof = sumcheck(&mut InnerProductProver::new(a, b), 4, &mut t, |_, _| {});
+        let limbs = t.into_proof();
+        assert_eq!(limbs.len(), 4 * 2 * d);
+
+        let mut t = Poseidon2VerifierTranscript::new(DOMAIN, &limbs);
+        let result = sumcheck_verify(claim, 2, 4, &mut t, |_, _| Ok(())).unwrap();
+        t.finish().unwrap();
+        assert_eq!(result.challenges, proof.challenges);
+        assert_eq!(result.final_claim, proof.final_value);
+
+        let mut tampered = limbs.clone();
+        tampered[d] += 1;
+        let mut t = Poseidon2VerifierTranscript::new(DOMAIN, &tampered);
+        let forged = sumcheck_verify(claim, 2, 4, &mut t, |_, _| Ok(())).unwrap();
+        assert_ne!(forged.final_claim, proof.final_value);
+
+        let mut t = Poseidon2VerifierTranscript::new(DOMAIN, &limbs[..limbs.len() - 1]);
+        assert!(sumcheck_verify(claim, 2, 4, &mut t, |_, _| Ok(())).is_err());
+    }
+
+    #[test]
+    fn transcripts_roundtrip_over_base_and_extensions() {
+        roundtrip::<F64>();
+        roundtrip::<F64Ext2>();
+        roundtrip::<F64Ext3>();
+    }
+
+    #[test]
+    fn non_canonical_limbs_rejected() {
+        let limbs = [GOLDILOCKS_P];
+        let mut t = Poseidon2VerifierTranscript::new(DOMAIN, &limbs);
+        let received: Result<F64, _> = t.receive();
+        assert_eq!(received, Err(DuplexTranscriptError::NonCanonical));
+        let mut t = Poseidon2VerifierTranscript::new(DOMAIN, &[1, 2]);
+        let received: Result<F64Ext3, _> = t.receive();
+        assert_eq!(received, Err(DuplexTranscriptError::Exhausted));
+    }
+
+    #[test]
+    #[should_panic(expected = "needs Goldilocks")]
+    fn other_fields_rejected() {
+        let mut t = Poseidon2ProverTranscript::new(DOMAIN);
+        let _: crate::tests::BabyBear = t.challenge();
+    }
+}
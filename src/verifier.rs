@@ -22,13 +22,39 @@
 //! - `d >= 2`: `[h(0), h(∞), h(2), ..., h(d-1)]`, where `h(∞)` is the
 //!   leading coefficient. Verifier derives `h(1) = claim - h(0)` from the
 //!   consistency constraint `h(0) + h(1) = claim`.
+//!
+//! Points `2, 3, ...` are the field's evaluation-domain nodes
+//! [`SumcheckField::eval_node`], so binary fields can use distinct
+//! non-integer nodes.
+//!
+//! When the degree differs per variable, [`sumcheck_verify_with_degrees()`]
+//! takes a [`DegreeSchedule`] and applies the format round by round.
+//!
+//! [`verify_proof()`] checks a stored [`SumcheckProof`] instead of reading
+//! from a live transcript: it absorbs the proof's round polynomials into a
+//! fresh Fiat–Shamir transcript and re-derives the challenges;
+//! [`verify_round_polys()`] does the same for bare round-polynomial slices.
+//! Both reject rounds of the wrong length with
+//! [`SumcheckError::DegreeMismatch`].
+//!
+//! The `*_detailed` variants return a [`VerifierError`], which carries both
+//! sides of the failing check and the transcript's own error.
+//!
+//! [`sumcheck_verify_with_config()`] refuses to verify when the
+//! [`soundness`](crate::soundness) bound is below a configured number of
+//! bits.
 
 extern crate alloc;
 use crate::field::SumcheckField;
-use crate::proof::SumcheckError;
-use crate::transcript::VerifierTranscript;
+use crate::oracle::OracleCheck;
+use crate::polynomial::{eval_horner, BarycentricWeights, SkipDomain};
+use crate::proof::{SumcheckError, SumcheckProof, VerifierError};
+use crate::session::{VerifierRoundContext, VerifierSession};
+use crate::soundness::SecurityConfig;
+use crate::transcript::{ProverTranscript, VerifierTranscript};
 use alloc::vec;
 use alloc::vec::Vec;
+use core::convert::Infallible;
 
 /// Output of [`sumcheck_verify`]: the verifier challenges and final claim.
 ///
@@ -63,138 +89,524 @@ pub fn sumcheck_verify<F: SumcheckField, T: VerifierTranscript<F>>(
     expected_degree: usize,
     num_rounds: usize,
     transcript: &mut T,
+    hook: impl FnMut(usize, &mut T) -> Result<(), SumcheckError>,
+) -> Result<SumcheckResult<F>, SumcheckError> {
+    Ok(sumcheck_verify_detailed(
+        claimed_sum,
+        &|_| expected_degree,
+        num_rounds,
+        transcript,
+        hook,
+    )?)
+}
+
+/// Verify a sum-check proof and run the oracle check in one call.
+///
+/// Runs [`sumcheck_verify`] and then checks
+/// `final_claim == oracle.evaluate(challenges)`, returning
+/// [`SumcheckError::FinalEvaluation`] on mismatch. Use when the verifier
+/// can evaluate `g` itself (see [`crate::oracle`]); composed protocols that
+/// hand `final_claim` to the next layer use [`sumcheck_verify`].
+///
+/// ```ignore
+/// let oracle = MultilinearOracle::new(&evals);
+/// let result = sumcheck_verify_with_oracle(sum, 1, v, &oracle, &mut t, noop_hook_verify)?;
+/// ```
+pub fn sumcheck_verify_with_oracle<F, T, O>(
+    claimed_sum: F,
+    expected_degree: usize,
+    num_rounds: usize,
+    oracle: &O,
+    transcript: &mut T,
+    hook: impl FnMut(usize, &mut T) -> Result<(), SumcheckError>,
+) -> Result<SumcheckResult<F>, SumcheckError>
+where
+    F: SumcheckField,
+    T: VerifierTranscript<F>,
+    O: OracleCheck<F> + ?Sized,
+{
+    let result = sumcheck_verify(claimed_sum, expected_degree, num_rounds, transcript, hook)?;
+    oracle.check(&result)?;
+    Ok(result)
+}
+
+/// Verify a sum-check proof only if it meets a minimum security level.
+///
+/// Checks the [`SoundnessBound`](crate::soundness::SoundnessBound) of
+/// `num_rounds` degree-`expected_degree` rounds over `F` against `config`
+/// before reading the transcript, returning
+/// [`SumcheckError::InsufficientSecurity`] if it falls short; otherwise
+/// identical to [`sumcheck_verify`].
+///
+/// ```ignore
+/// // Refuse Goldilocks base-field challenges for 100-bit security.
+/// let config = SecurityConfig::new(100);
+/// let result = sumcheck_verify_with_config(sum, 3, v, &config, &mut t, noop_hook_verify)?;
+/// ```
+pub fn sumcheck_verify_with_config<F: SumcheckField, T: VerifierTranscript<F>>(
+    claimed_sum: F,
+    expected_degree: usize,
+    num_rounds: usize,
+    config: &SecurityConfig,
+    transcript: &mut T,
+    hook: impl FnMut(usize, &mut T) -> Result<(), SumcheckError>,
+) -> Result<SumcheckResult<F>, SumcheckError> {
+    config.check::<F, _>(&|_| expected_degree, num_rounds)?;
+    sumcheck_verify(claimed_sum, expected_degree, num_rounds, transcript, hook)
+}
+
+/// Per-round degrees of a sumcheck, for [`sumcheck_verify_with_degrees`].
+///
+/// Implemented for slices, arrays and `Vec`s of degrees (indexed by round)
+/// and for closures `Fn(usize) -> usize`.
+pub trait DegreeSchedule {
+    /// Degree of the round polynomial in round `round`.
+    fn degree(&self, round: usize) -> usize;
+}
+
+impl DegreeSchedule for [usize] {
+    fn degree(&self, round: usize) -> usize {
+        self[round]
+    }
+}
+
+impl<const N: usize> DegreeSchedule for [usize; N] {
+    fn degree(&self, round: usize) -> usize {
+        self[round]
+    }
+}
+
+impl DegreeSchedule for Vec<usize> {
+    fn degree(&self, round: usize) -> usize {
+        self[round]
+    }
+}
+
+impl<G: Fn(usize) -> usize> DegreeSchedule for G {
+    fn degree(&self, round: usize) -> usize {
+        self(round)
+    }
+}
+
+/// Verify a sum-check proof whose round polynomials have per-round degrees.
+///
+/// Identical to [`sumcheck_verify`], except that round `j` reads
+/// `degrees.degree(j)` values (EvalsInfty for that degree) — matching a
+/// prover whose [`round_degree`](crate::sumcheck_prover::SumcheckProver::round_degree)
+/// varies. Sending each round at its own degree instead of the maximum
+/// saves `max_d − d_j` field elements per round.
+///
+/// ```ignore
+/// // Degree 3 in the first two variables, 1 in the rest.
+/// let result = sumcheck_verify_with_degrees(sum, &|j| if j < 2 { 3 } else { 1 }, v, &mut t, noop_hook_verify)?;
+/// let result = sumcheck_verify_with_degrees(sum, &[3, 3, 1, 1], 4, &mut t, noop_hook_verify)?;
+/// ```
+///
+/// # Panics
+///
+/// If a slice schedule is shorter than `num_rounds`.
+pub fn sumcheck_verify_with_degrees<F, T, D>(
+    claimed_sum: F,
+    degrees: &D,
+    num_rounds: usize,
+    transcript: &mut T,
+    hook: impl FnMut(usize, &mut T) -> Result<(), SumcheckError>,
+) -> Result<SumcheckResult<F>, SumcheckError>
+where
+    F: SumcheckField,
+    T: VerifierTranscript<F>,
+    D: DegreeSchedule + ?Sized,
+{
+    Ok(sumcheck_verify_detailed(
+        claimed_sum,
+        degrees,
+        num_rounds,
+        transcript,
+        hook,
+    )?)
+}
+
+/// [`sumcheck_verify_with_degrees`], returning the underlying error.
+///
+/// Transcript failures keep the transcript's own error
+/// ([`VerifierError::Transcript`]) and hook failures the hook's
+/// ([`VerifierError::Hook`]) instead of reducing them to a
+/// [`SumcheckError`].
+///
+/// ```ignore
+/// match sumcheck_verify_detailed(sum, &|_| 2, v, &mut t, noop_hook_verify) {
+///     Err(VerifierError::Transcript { round, source }) => log::warn!("round {round}: {source}"),
+///     ...
+/// }
+/// ```
+pub fn sumcheck_verify_detailed<F, T, D>(
+    claimed_sum: F,
+    degrees: &D,
+    num_rounds: usize,
+    transcript: &mut T,
+    mut hook: impl FnMut(usize, &mut T) -> Result<(), SumcheckError>,
+) -> Result<SumcheckResult<F>, VerifierError<F, T::Error>>
+where
+    F: SumcheckField,
+    T: VerifierTranscript<F>,
+    D: DegreeSchedule + ?Sized,
+{
+    run_session(
+        VerifierSession::with_degrees(claimed_sum, degrees, num_rounds),
+        transcript,
+        &mut |context, transcript| hook(context.round, transcript),
+    )
+}
+
+/// [`sumcheck_verify_with_degrees`] with a hook that sees the round's
+/// context.
+///
+/// `hook` receives a [`VerifierRoundContext`] — the round polynomial just
+/// received and the claim it is checked against — along with the
+/// transcript, after the message is read and before the challenge is
+/// squeezed. Errors are reported as by [`sumcheck_verify_detailed`].
+///
+/// ```ignore
+/// let result = sumcheck_verify_with_context(sum, &|_| 2, v, &mut t, |ctx, t| {
+///     check_pow(t, ctx.message).map_err(|_| SumcheckError::HookError { round: ctx.round })
+/// })?;
+/// ```
+pub fn sumcheck_verify_with_context<F, T, D>(
+    claimed_sum: F,
+    degrees: &D,
+    num_rounds: usize,
+    transcript: &mut T,
+    mut hook: impl FnMut(&VerifierRoundContext<'_, F>, &mut T) -> Result<(), SumcheckError>,
+) -> Result<SumcheckResult<F>, VerifierError<F, T::Error>>
+where
+    F: SumcheckField,
+    T: VerifierTranscript<F>,
+    D: DegreeSchedule + ?Sized,
+{
+    run_session(
+        VerifierSession::with_degrees(claimed_sum, degrees, num_rounds),
+        transcript,
+        &mut hook,
+    )
+}
+
+/// Verify a sum-check proof whose first round is a univariate skip.
+///
+/// The first `k = domain.num_vars()` Boolean variables are replaced by one
+/// round over the multiplicative [`SkipDomain`] `H` of size `N = 2^k`:
+///
+/// 1. Reads `skip_degree` coefficients `[c_1, ..., c_D]` of the skip-round
+///    polynomial `h`.
+/// 2. Derives `c_0` from the domain-sum consistency constraint
+///    `Σ_{y ∈ H} h(y) = N·(c_0 + c_N + c_{2N} + ...) = claim` (in place of
+///    `h(0) + h(1) = claim`).
+/// 3. Invokes `hook(0, transcript)`, reads `r_0` and sets `claim = h(r_0)`.
+///
+/// The remaining `num_rounds − 1` rounds are standard degree-`expected_degree`
+/// rounds, exactly as in [`sumcheck_verify`]. `num_rounds` counts the skip
+/// round, so a `v`-variate sumcheck has `num_rounds = v − k + 1`.
+///
+/// `result.challenges[0]` is the skip challenge; the oracle check must
+/// evaluate the skipped variables through the Lagrange basis of `H`
+/// ([`SkipDomain::lagrange_at`]) rather than as a Boolean point.
+pub fn sumcheck_verify_with_skip<F: SumcheckField, T: VerifierTranscript<F>>(
+    claimed_sum: F,
+    domain: &SkipDomain<F>,
+    skip_degree: usize,
+    expected_degree: usize,
+    num_rounds: usize,
+    transcript: &mut T,
     mut hook: impl FnMut(usize, &mut T) -> Result<(), SumcheckError>,
 ) -> Result<SumcheckResult<F>, SumcheckError> {
-    let mut claim = claimed_sum;
     let mut challenges = Vec::with_capacity(num_rounds);
-    let d = expected_degree;
+    if num_rounds == 0 {
+        return Ok(SumcheckResult {
+            challenges,
+            final_claim: claimed_sum,
+        });
+    }
 
-    for round in 0..num_rounds {
-        // EvalsInfty wire format: receive `d` values per round (min 1).
-        let n_wire = d.max(1);
-        let mut recv = Vec::with_capacity(n_wire);
-        for _ in 0..n_wire {
-            let v = transcript
-                .receive()
-                .map_err(|_| SumcheckError::TranscriptError { round })?;
-            recv.push(v);
-        }
+    // Skip round: coefficients [c_1, ..., c_D]; c_0 from the domain sum.
+    let mut coeffs = Vec::with_capacity(skip_degree + 1);
+    coeffs.push(F::ZERO);
+    for _ in 0..skip_degree {
+        let v = transcript
+            .receive()
+            .map_err(|_| SumcheckError::TranscriptError { round: 0 })?;
+        coeffs.push(v);
+    }
+    coeffs[0] = domain.constant_term(claimed_sum, &coeffs[1..]);
 
-        let h0 = recv[0];
-        let h1 = claim - h0;
+    hook(0, transcript)?;
+    let r = transcript.challenge();
+    challenges.push(r);
+    let claim = eval_horner(&coeffs, r);
 
-        // Per-round hook (e.g., PoW verification for WHIR).
-        hook(round, transcript)?;
+    let mut degrees = vec![expected_degree; num_rounds];
+    degrees[0] = skip_degree;
+    let session = VerifierSession::resume(claim, degrees, challenges);
+    Ok(run_session(
+        session,
+        transcript,
+        &mut |context, transcript| hook(context.round, transcript),
+    )?)
+}
 
-        // Squeeze verifier challenge.
-        let r = transcript.challenge();
-        challenges.push(r);
+/// Verify a stored [`SumcheckProof`] by replaying it through a fresh
+/// Fiat–Shamir transcript.
+///
+/// Absorbs each round polynomial of `proof` into `transcript` (via
+/// [`ProverTranscript::send`], exactly as the [`sumcheck`](crate::runner::sumcheck)
+/// runner did) and squeezes the challenges, running the same round checks
+/// as [`sumcheck_verify`]. `transcript` must start in the state the
+/// prover's transcript was in, and the prover must not have used a
+/// per-round hook that wrote to the transcript.
+///
+/// Errors:
+/// - [`SumcheckError::DegreeMismatch`] if round `j` does not carry
+///   `max(degree, 1)` values.
+/// - [`SumcheckError::ChallengeMismatch`] if `proof.challenges` differs
+///   from the re-derived challenges (first differing round; a missing or
+///   extra challenge counts as a difference).
+/// - [`SumcheckError::FinalEvaluation`] if `proof.final_value` differs
+///   from the reduced claim.
+///
+/// On success, `final_claim == proof.final_value`; the caller must still
+/// check it against `g(r_1, ..., r_v)`.
+///
+/// ```ignore
+/// let proof = sumcheck(&mut prover, v, &mut Sponge::new(domain), noop_hook);
+/// let result = verify_proof(&proof, claimed_sum, prover.degree(), &mut Sponge::new(domain))?;
+/// ```
+pub fn verify_proof<F: SumcheckField, T: ProverTranscript<F>>(
+    proof: &SumcheckProof<F>,
+    claimed_sum: F,
+    degree: usize,
+    transcript: &mut T,
+) -> Result<SumcheckResult<F>, SumcheckError> {
+    Ok(verify_proof_with(
+        &RoundKernel::new(degree),
+        proof,
+        claimed_sum,
+        transcript,
+    )?)
+}
 
-        // Update claim: h_j(r_j).
-        claim = if d == 0 {
-            // Constant polynomial — claim stays equal to h0.
-            h0
-        } else {
-            // h_inf = leading coefficient.
-            // For d == 1: derive as h(1) - h(0) (slope).
-            // For d >= 2: prover sends it explicitly as recv[1].
-            let h_inf = if d >= 2 { recv[1] } else { h1 - h0 };
-
-            // Build q-values at points {0, 1, ..., d-1}, where
-            // q(x) = p(x) - h_inf * x^d has degree d-1.
-            //   q(0) = h(0)
-            //   q(1) = h(1) - h_inf
-            //   q(i) = h(i) - h_inf * i^d   for i in 2..d  (d >= 3 only)
-            let mut q_vals = Vec::with_capacity(d);
-            q_vals.push(h0);
-            if d >= 1 {
-                q_vals.push(h1 - h_inf);
-            }
-            for (offset, &hi) in recv.get(2..d).unwrap_or(&[]).iter().enumerate() {
-                let i = offset + 2;
-                let i_f = F::from_u64(i as u64);
-                let mut i_d = F::ONE;
-                for _ in 0..d {
-                    i_d *= i_f;
-                }
-                q_vals.push(hi - h_inf * i_d);
-            }
+/// [`verify_proof`], returning the values behind a failed check.
+///
+/// [`VerifierError::ChallengeMismatch`] carries the re-derived and stored
+/// challenges, [`VerifierError::FinalEvaluation`] the reduced claim and
+/// `proof.final_value`.
+pub fn verify_proof_detailed<F: SumcheckField, T: ProverTranscript<F>>(
+    proof: &SumcheckProof<F>,
+    claimed_sum: F,
+    degree: usize,
+    transcript: &mut T,
+) -> Result<SumcheckResult<F>, VerifierError<F, Infallible>> {
+    verify_proof_with(&RoundKernel::new(degree), proof, claimed_sum, transcript)
+}
 
-            // Degree-(d-1) Lagrange interpolation of q over {0, 1, ..., d-1}.
-            let q_r = evaluate_from_evals(&q_vals, r);
+/// Verify degree-`degree` round polynomials given as slices, deriving the
+/// challenges through a Fiat–Shamir transcript.
+///
+/// Like [`verify_proof`] without stored challenges or final value: each
+/// `round_polys[j]` must hold exactly `max(degree, 1)` EvalsInfty values
+/// ([`VerifierError::DegreeMismatch`] otherwise) and is absorbed before
+/// round `j`'s challenge is squeezed. The caller checks `final_claim`.
+///
+/// ```ignore
+/// let result = verify_round_polys(sum, 2, &proof.round_polys, &mut Sponge::new(domain))?;
+/// ```
+pub fn verify_round_polys<F, T, R>(
+    claimed_sum: F,
+    degree: usize,
+    round_polys: &[R],
+    transcript: &mut T,
+) -> Result<SumcheckResult<F>, VerifierError<F, Infallible>>
+where
+    F: SumcheckField,
+    T: ProverTranscript<F>,
+    R: AsRef<[F]>,
+{
+    replay_rounds(
+        &RoundKernel::new(degree),
+        round_polys,
+        None,
+        claimed_sum,
+        transcript,
+    )
+}
 
-            // p(r) = q(r) + h_inf * r^d
-            let mut r_d = F::ONE;
-            for _ in 0..d {
-                r_d *= r;
-            }
-            q_r + h_inf * r_d
-        };
+/// [`verify_proof`] with a precomputed [`RoundKernel`], shared across
+/// proofs of the same degree by the batch verifier.
+pub(crate) fn verify_proof_with<F: SumcheckField, T: ProverTranscript<F>>(
+    kernel: &RoundKernel<F>,
+    proof: &SumcheckProof<F>,
+    claimed_sum: F,
+    transcript: &mut T,
+) -> Result<SumcheckResult<F>, VerifierError<F, Infallible>> {
+    let result = replay_rounds(
+        kernel,
+        &proof.round_polys,
+        Some(&proof.challenges),
+        claimed_sum,
+        transcript,
+    )?;
+    let num_rounds = proof.round_polys.len();
+    if proof.challenges.len() != num_rounds {
+        return Err(VerifierError::ChallengeMismatch {
+            round: num_rounds,
+            expected: None,
+            actual: proof.challenges.get(num_rounds).copied(),
+        });
     }
+    if proof.final_value != result.final_claim {
+        return Err(VerifierError::FinalEvaluation {
+            expected: result.final_claim,
+            actual: proof.final_value,
+        });
+    }
+    Ok(result)
+}
 
+/// Absorb `round_polys` into `transcript` round by round, checking each
+/// round's shape and, if `stored` is given, each re-derived challenge.
+fn replay_rounds<F: SumcheckField, T: ProverTranscript<F>, R: AsRef<[F]>>(
+    kernel: &RoundKernel<F>,
+    round_polys: &[R],
+    stored: Option<&[F]>,
+    claimed_sum: F,
+    transcript: &mut T,
+) -> Result<SumcheckResult<F>, VerifierError<F, Infallible>> {
+    let degree = kernel.degree;
+    let mut challenges = Vec::with_capacity(round_polys.len());
+    let mut claim = claimed_sum;
+    for (round, poly) in round_polys.iter().enumerate() {
+        let poly = poly.as_ref();
+        if poly.len() != degree.max(1) {
+            return Err(VerifierError::DegreeMismatch {
+                round,
+                expected: degree.max(1),
+                got: poly.len(),
+            });
+        }
+        for &v in poly {
+            transcript.send(v);
+        }
+        let r = transcript.challenge();
+        if let Some(stored) = stored {
+            if stored.get(round) != Some(&r) {
+                return Err(VerifierError::ChallengeMismatch {
+                    round,
+                    expected: Some(r),
+                    actual: stored.get(round).copied(),
+                });
+            }
+        }
+        challenges.push(r);
+        claim = kernel.eval(claim, poly, r);
+    }
     Ok(SumcheckResult {
         challenges,
         final_claim: claim,
     })
 }
 
-/// Evaluate a univariate polynomial from its evaluations at `{0, 1, ..., d-1}`
-/// at an arbitrary point `r`.
-///
-/// Uses Lagrange interpolation:
-///   g(r) = Σ_i g(i) · Π_{j≠i} (r − j) / (i − j)
-pub(crate) fn evaluate_from_evals<F: SumcheckField>(evals: &[F], r: F) -> F {
-    let d = evals.len(); // number of interpolation nodes
-    if d == 0 {
-        return F::ZERO;
-    }
-    if d == 1 {
-        return evals[0];
-    }
-    if d == 2 {
-        // Linear: g(r) = g(0) + r·(g(1) − g(0)).
-        return evals[0] + r * (evals[1] - evals[0]);
-    }
+/// Degree-`d` EvalsInfty round update `claim ← h(r)` with the
+/// interpolation data precomputed: the barycentric weights of the `d`
+/// nodes `{x_0, ..., x_{d-1}}` and the powers `x_i^d`.
+#[derive(Clone, Debug)]
+pub(crate) struct RoundKernel<F: SumcheckField> {
+    degree: usize,
+    /// `x_i^d` for `i ∈ {0, ..., d-1}`.
+    node_pows: Vec<F>,
+    /// Weights for the degree-`(d-1)` remainder `q(x) = h(x) − h(∞)·x^d`.
+    weights: BarycentricWeights<F>,
+}
 
-    // General case: Lagrange interpolation at {0, 1, ..., d-1}.
-    // Precompute (r − j) for all j.
-    let r_minus: Vec<F> = (0..d).map(|j| r - F::from_u64(j as u64)).collect();
+impl<F: SumcheckField> RoundKernel<F> {
+    pub(crate) fn new(degree: usize) -> Self {
+        let node_pows = (0..degree)
+            .map(|i| {
+                let x = F::eval_node(i);
+                (0..degree).fold(F::ONE, |acc, _| acc * x)
+            })
+            .collect();
+        Self {
+            degree,
+            node_pows,
+            weights: BarycentricWeights::new(degree.saturating_sub(1)),
+        }
+    }
 
-    // Precompute prefix/suffix products of (r − j).
-    let mut prefix = vec![F::ONE; d + 1];
-    for i in 0..d {
-        prefix[i + 1] = prefix[i] * r_minus[i];
+    /// The round degree `d`.
+    pub(crate) fn degree(&self) -> usize {
+        self.degree
     }
-    let mut suffix = vec![F::ONE; d + 1];
-    for i in (0..d).rev() {
-        suffix[i] = suffix[i + 1] * r_minus[i];
+
+    /// The kernel for `degree` in `kernels`, built and cached on first use.
+    pub(crate) fn cached(kernels: &mut Vec<Self>, degree: usize) -> &Self {
+        let i = match kernels.iter().position(|k| k.degree == degree) {
+            Some(i) => i,
+            None => {
+                kernels.push(Self::new(degree));
+                kernels.len() - 1
+            }
+        };
+        &kernels[i]
     }
 
-    // Precompute 1 / (i − j) for all j ≠ i, accumulated as products.
-    // barycentric_weight[i] = 1 / Π_{j≠i} (i − j) = 1 / (i! · (d-1-i)! · (-1)^{d-1-i})
-    let mut result = F::ZERO;
-    for i in 0..d {
-        let numerator = prefix[i] * suffix[i + 1]; // Π_{j≠i} (r − j)
-        let denom: F = barycentric_weight(i, d);
-        if let Some(inv) = denom.inverse() {
-            result += evals[i] * numerator * inv;
+    /// `h(r)` for the round polynomial with wire values `wire` and
+    /// `h(0) + h(1) = claim`.
+    pub(crate) fn eval(&self, claim: F, wire: &[F], r: F) -> F {
+        let d = self.degree;
+        let h0 = wire[0];
+        let h1 = claim - h0;
+        match d {
+            0 => h0,
+            1 => h0 + r * (h1 - h0),
+            _ => {
+                let h_inf = wire[1];
+                let mut q = Vec::with_capacity(d);
+                q.push(h0);
+                q.push(h1 - h_inf);
+                for (&hi, &x_d) in wire[2..d].iter().zip(&self.node_pows[2..]) {
+                    q.push(hi - h_inf * x_d);
+                }
+                let r_d = (0..d).fold(F::ONE, |acc, _| acc * r);
+                self.weights.eval(&q, r) + h_inf * r_d
+            }
         }
     }
-    result
-}
-
-/// Barycentric weight: Π_{j≠i, 0≤j<d} (i − j).
-fn barycentric_weight<F: SumcheckField>(i: usize, d: usize) -> F {
-    let mut w = F::ONE;
-    for j in 0..d {
-        if j != i {
-            let diff = i as i64 - j as i64;
-            if diff > 0 {
-                w *= F::from_u64(diff as u64);
-            } else {
-                w *= -F::from_u64((-diff) as u64);
-            }
+}
+
+/// Drive `session` to completion over a live transcript: per round, read
+/// the message, invoke `hook`, then squeeze the challenge.
+fn run_session<F: SumcheckField, T: VerifierTranscript<F>>(
+    mut session: VerifierSession<F>,
+    transcript: &mut T,
+    hook: &mut impl FnMut(&VerifierRoundContext<'_, F>, &mut T) -> Result<(), SumcheckError>,
+) -> Result<SumcheckResult<F>, VerifierError<F, T::Error>> {
+    while let Some(len) = session.message_len() {
+        let round = session.round();
+        // EvalsInfty wire format: `max(d, 1)` values per round.
+        let mut message = Vec::with_capacity(len);
+        for _ in 0..len {
+            let v = transcript
+                .receive()
+                .map_err(|source| VerifierError::Transcript { round, source })?;
+            message.push(v);
         }
+        // Exactly `len` values were read, so the shape check cannot fail.
+        let accepted = session.receive_message(&message);
+        debug_assert!(accepted.is_ok());
+
+        // Per-round hook (e.g., PoW verification for WHIR).
+        let context = session.context().expect("message received");
+        hook(&context, transcript).map_err(|source| VerifierError::Hook { round, source })?;
+
+        // Squeeze verifier challenge; the session updates the claim to h_j(r_j).
+        session.receive_challenge(transcript.challenge());
     }
-    w
+    Ok(session.finish())
 }
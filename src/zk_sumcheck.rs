@@ -0,0 +1,605 @@
+//! Zero-knowledge sumcheck via a masking polynomial (Libra; Chiesa–Forbes–Spooner).
+//!
+//! The round polynomials of a plain sumcheck are partial sums of the
+//! witness and leak information about it. [`ZkSumcheck`] wraps any
+//! [`SumcheckProver`] and proves the masked combination `g + ρ·p` instead,
+//! where `p` is a random polynomial that is a sum of univariates:
+//!
+//! ```text
+//! p(x) = a_0 + Σ_j p_j(x_j),   p_j(X) = Σ_{k=1..d} c_{j,k}·X^k
+//! ```
+//!
+//! with `d` the degree of `g` in each variable. Its round polynomials, and
+//! its sum over `{0,1}^v`, have closed forms, so masking adds `O(v·d)` work
+//! per round.
+//!
+//! # Protocol
+//!
+//! 1. The prover samples `p`, commits to it through a [`MaskCommitment`],
+//!    and sends `P = Σ_x p(x)`. The verifier squeezes `ρ`.
+//! 2. Both sides run the sumcheck for `Σ_x (g + ρ·p)(x) = H + ρ·P`, ending
+//!    at `r`. Only masked round polynomials are sent.
+//! 3. The prover sends `p(r)` and opens the commitment at `r`. The verifier
+//!    checks the opening and reduces to the claim
+//!    `g(r) = final_claim − ρ·p(r)`.
+//!
+//! ```ignore
+//! // prover
+//! let mut zk = ZkSumcheck::new(prover, num_vars, || F::rand(&mut rng));
+//! let proof = zk.prove(&scheme, &mut pt);
+//! // verifier
+//! let result = zk_sumcheck_verify(claimed_sum, degree, num_vars, &scheme, &mut vt)?;
+//! // caller: check g(result.challenges) == result.final_claim
+//! ```
+//!
+//! The wrapped prover must send degree-`d` round polynomials in every round
+//! (no per-round schedules or univariate skip).
+
+use crate::field::SumcheckField;
+use crate::proof::{SumcheckError, SumcheckProof};
+use crate::runner::sumcheck;
+use crate::sumcheck_prover::SumcheckProver;
+use crate::transcript::{ProverTranscript, VerifierTranscript};
+use crate::verifier::{sumcheck_verify, SumcheckResult};
+
+use alloc::vec::Vec;
+
+/// `2^n` as a field element.
+fn pow2<F: SumcheckField>(n: usize) -> F {
+    (0..n).fold(F::ONE, |acc, _| acc.double())
+}
+
+/// A masking polynomial `p(x) = a_0 + Σ_j p_j(x_j)` with `p_j(0) = 0`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MaskPolynomial<F> {
+    /// Constant term `a_0`.
+    pub constant: F,
+    /// `univariates[j][k - 1]` is the coefficient of `x_j^k`, `k = 1..=d`.
+    pub univariates: Vec<Vec<F>>,
+}
+
+impl<F: SumcheckField> MaskPolynomial<F> {
+    /// Sample a mask over `num_vars` variables with per-variable degree
+    /// `degree`. `sample` must return uniform field elements.
+    pub fn random(num_vars: usize, degree: usize, mut sample: impl FnMut() -> F) -> Self {
+        let constant = sample();
+        let univariates = (0..num_vars)
+            .map(|_| (0..degree).map(|_| sample()).collect())
+            .collect();
+        Self {
+            constant,
+            univariates,
+        }
+    }
+
+    /// Number of variables `v`.
+    pub fn num_vars(&self) -> usize {
+        self.univariates.len()
+    }
+
+    /// `p_j(x)`.
+    fn univariate(&self, j: usize, x: F) -> F {
+        self.univariates[j]
+            .iter()
+            .rev()
+            .fold(F::ZERO, |acc, &c| (acc + c) * x)
+    }
+
+    /// `p_j(1) = Σ_k c_{j,k}`.
+    fn univariate_at_one(&self, j: usize) -> F {
+        self.univariates[j].iter().copied().sum()
+    }
+
+    /// Evaluate `p(point)` (MSB order: `point[j]` binds `x_j`).
+    pub fn evaluate(&self, point: &[F]) -> F {
+        assert_eq!(point.len(), self.num_vars(), "point has wrong arity");
+        point
+            .iter()
+            .enumerate()
+            .fold(self.constant, |acc, (j, &x)| acc + self.univariate(j, x))
+    }
+
+    /// `Σ_{x ∈ {0,1}^v} p(x) = 2^v·a_0 + 2^{v-1}·Σ_j p_j(1)`.
+    pub fn sum_over_hypercube(&self) -> F {
+        let v = self.num_vars();
+        let mut sum = pow2::<F>(v) * self.constant;
+        if v > 0 {
+            let tail: F = (0..v).map(|j| self.univariate_at_one(j)).sum();
+            sum += pow2::<F>(v - 1) * tail;
+        }
+        sum
+    }
+
+    /// All coefficients, `[a_0, c_{0,1}, ..., c_{0,d}, c_{1,1}, ...]`.
+    pub fn to_coeffs(&self) -> Vec<F> {
+        let mut out = Vec::with_capacity(1 + self.univariates.iter().map(Vec::len).sum::<usize>());
+        out.push(self.constant);
+        for u in &self.univariates {
+            out.extend_from_slice(u);
+        }
+        out
+    }
+
+    /// Inverse of [`to_coeffs`](Self::to_coeffs).
+    pub fn from_coeffs(num_vars: usize, degree: usize, coeffs: &[F]) -> Self {
+        assert_eq!(
+            coeffs.len(),
+            1 + num_vars * degree,
+            "wrong coefficient count"
+        );
+        Self {
+            constant: coeffs[0],
+            univariates: coeffs[1..]
+                .chunks(degree.max(1))
+                .map(<[F]>::to_vec)
+                .collect(),
+        }
+    }
+}
+
+/// Commitment scheme for the masking polynomial.
+///
+/// Both sides talk through the transcript, so the commitment is bound into
+/// the Fiat-Shamir state before `ρ` is squeezed.
+pub trait MaskCommitment<F: SumcheckField> {
+    /// Prover-side state kept between [`commit`](Self::commit) and
+    /// [`open`](Self::open).
+    type ProverState;
+    /// Verifier-side view of the commitment.
+    type Commitment;
+
+    /// Commit to `mask`, writing the commitment to the transcript.
+    fn commit<T: ProverTranscript<F>>(
+        &self,
+        mask: &MaskPolynomial<F>,
+        transcript: &mut T,
+    ) -> Self::ProverState;
+
+    /// Prove the value `mask(point)` (already sent by the caller).
+    fn open<T: ProverTranscript<F>>(
+        &self,
+        state: Self::ProverState,
+        point: &[F],
+        transcript: &mut T,
+    );
+
+    /// Read a commitment to a mask with `num_vars` variables of degree
+    /// `degree`.
+    fn receive_commitment<T: VerifierTranscript<F>>(
+        &self,
+        num_vars: usize,
+        degree: usize,
+        transcript: &mut T,
+    ) -> Result<Self::Commitment, T::Error>;
+
+    /// Check an opening of `commitment` to `value` at `point`.
+    fn verify_opening<T: VerifierTranscript<F>>(
+        &self,
+        commitment: &Self::Commitment,
+        point: &[F],
+        value: F,
+        transcript: &mut T,
+    ) -> Result<bool, T::Error>;
+}
+
+/// Trivial hash commitment: the digest `H(coefficients)` is sent at commit
+/// time and the opening reveals every coefficient.
+///
+/// Revealing the mask defeats zero knowledge, so this scheme only exercises
+/// the protocol plumbing (binding holds if `H` is collision-resistant). Use
+/// a hiding polynomial commitment in production.
+pub struct HashCommitment<H> {
+    hash: H,
+}
+
+impl<H> HashCommitment<H> {
+    /// A commitment over `hash`, which maps coefficient vectors to digests.
+    pub fn new(hash: H) -> Self {
+        Self { hash }
+    }
+}
+
+/// The digest and mask shape, as received by the verifier.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HashCommitmentDigest<F> {
+    /// `H(coefficients)`.
+    pub digest: F,
+    /// Number of mask variables.
+    pub num_vars: usize,
+    /// Per-variable mask degree.
+    pub degree: usize,
+}
+
+impl<F: SumcheckField, H: Fn(&[F]) -> F> MaskCommitment<F> for HashCommitment<H> {
+    type ProverState = MaskPolynomial<F>;
+    type Commitment = HashCommitmentDigest<F>;
+
+    fn commit<T: ProverTranscript<F>>(
+        &self,
+        mask: &MaskPolynomial<F>,
+        transcript: &mut T,
+    ) -> Self::ProverState {
+        transcript.send((self.hash)(&mask.to_coeffs()));
+        mask.clone()
+    }
+
+    fn open<T: ProverTranscript<F>>(
+        &self,
+        state: Self::ProverState,
+        _point: &[F],
+        transcript: &mut T,
+    ) {
+        for c in state.to_coeffs() {
+            transcript.send(c);
+        }
+    }
+
+    fn receive_commitment<T: VerifierTranscript<F>>(
+        &self,
+        num_vars: usize,
+        degree: usize,
+        transcript: &mut T,
+    ) -> Result<Self::Commitment, T::Error> {
+        Ok(HashCommitmentDigest {
+            digest: transcript.receive()?,
+            num_vars,
+            degree,
+        })
+    }
+
+    fn verify_opening<T: VerifierTranscript<F>>(
+        &self,
+        commitment: &Self::Commitment,
+        point: &[F],
+        value: F,
+        transcript: &mut T,
+    ) -> Result<bool, T::Error> {
+        let n = 1 + commitment.num_vars * commitment.degree;
+        let coeffs = (0..n)
+            .map(|_| transcript.receive())
+            .collect::<Result<Vec<F>, _>>()?;
+        let mask = MaskPolynomial::from_coeffs(commitment.num_vars, commitment.degree, &coeffs);
+        Ok((self.hash)(&coeffs) == commitment.digest && mask.evaluate(point) == value)
+    }
+}
+
+/// Zero-knowledge wrapper around a [`SumcheckProver`]: its round
+/// polynomials are those of `g + ρ·p` for a random mask `p`.
+///
+/// Runs only through [`prove`](Self::prove), which samples `ρ` before the
+/// first round; it is deliberately not a [`SumcheckProver`] itself, so it
+/// cannot be driven by the runner with the mask left out.
+///
+/// See [module docs](self) for details.
+pub struct ZkSumcheck<F: SumcheckField, P: SumcheckProver<F>> {
+    inner: P,
+    mask: MaskPolynomial<F>,
+    /// Number of completed rounds.
+    rounds_elapsed: usize,
+    /// `a_0 + Σ_{i<j} p_i(r_i)` over the bound variables.
+    bound: F,
+    /// `Σ_{i>j} p_i(1)` over the variables after the current one.
+    free: F,
+}
+
+impl<F: SumcheckField, P: SumcheckProver<F>> ZkSumcheck<F, P> {
+    /// Wrap `prover` (a sumcheck over `num_vars` variables) with a fresh
+    /// mask of the prover's degree. `sample` must return uniform field
+    /// elements.
+    pub fn new(prover: P, num_vars: usize, sample: impl FnMut() -> F) -> Self {
+        let degree = prover.degree();
+        assert!(degree >= 1, "masking needs a round degree of at least 1");
+        let mask = MaskPolynomial::random(num_vars, degree, sample);
+        Self::with_mask(prover, mask)
+    }
+
+    /// Wrap `prover` with a given mask (per-variable degree must equal the
+    /// prover's degree).
+    pub fn with_mask(prover: P, mask: MaskPolynomial<F>) -> Self {
+        assert!(
+            mask.univariates.iter().all(|u| u.len() == prover.degree()),
+            "mask degree must match the prover degree"
+        );
+        let free = (1..mask.num_vars())
+            .map(|j| mask.univariate_at_one(j))
+            .sum();
+        Self {
+            bound: mask.constant,
+            inner: prover,
+            mask,
+            rounds_elapsed: 0,
+            free,
+        }
+    }
+
+    /// The wrapped prover, e.g. for post-sumcheck inspection.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// The masking polynomial.
+    pub fn mask(&self) -> &MaskPolynomial<F> {
+        &self.mask
+    }
+
+    /// Run the masked protocol: commit to the mask, send its sum, squeeze
+    /// `ρ`, run all rounds and open the mask at the sumcheck point.
+    ///
+    /// The returned proof holds the masked round polynomials that were
+    /// sent; its `final_value` is the unmasked `g(r)`.
+    pub fn prove<C: MaskCommitment<F>, T: ProverTranscript<F>>(
+        &mut self,
+        scheme: &C,
+        transcript: &mut T,
+    ) -> SumcheckProof<F> {
+        let state = scheme.commit(&self.mask, transcript);
+        transcript.send(self.mask.sum_over_hypercube());
+        let rho = transcript.challenge();
+
+        let num_vars = self.mask.num_vars();
+        let mut masked = MaskedRounds { zk: self, rho };
+        let mut proof = sumcheck(&mut masked, num_vars, transcript, |_, _| {});
+
+        let mask_value = self.bound;
+        transcript.send(mask_value);
+        scheme.open(state, &proof.challenges, transcript);
+
+        proof.final_value = self.inner.final_value();
+        proof
+    }
+
+    /// Bind the current variable `j` of the mask to `r`.
+    fn bind(&mut self, r: F) {
+        let j = self.rounds_elapsed - 1;
+        self.bound += self.mask.univariate(j, r);
+        if j + 1 < self.mask.num_vars() {
+            self.free -= self.mask.univariate_at_one(j + 1);
+        }
+    }
+
+    /// The mask's round polynomial for round `j` in EvalsInfty format:
+    /// `q(X) = 2^{v-1-j}·(bound + p_j(X)) + 2^{v-2-j}·free`.
+    fn mask_round(&self, degree: usize) -> Vec<F> {
+        let v = self.mask.num_vars();
+        let j = self.rounds_elapsed;
+        let scale = pow2::<F>(v - 1 - j);
+        let mut base = scale * self.bound;
+        if j + 1 < v {
+            base += pow2::<F>(v - 2 - j) * self.free;
+        }
+        let mut out = Vec::with_capacity(degree);
+        out.push(base);
+        if degree >= 2 {
+            out.push(scale * self.mask.univariates[j][degree - 1]);
+            for k in 2..degree {
+                let x = F::eval_node(k);
+                out.push(base + scale * self.mask.univariate(j, x));
+            }
+        }
+        out
+    }
+}
+
+/// The rounds of `g + ρ·p`, as run by [`ZkSumcheck::prove`] once `ρ` is
+/// known.
+struct MaskedRounds<'a, F: SumcheckField, P: SumcheckProver<F>> {
+    zk: &'a mut ZkSumcheck<F, P>,
+    rho: F,
+}
+
+impl<F: SumcheckField, P: SumcheckProver<F>> SumcheckProver<F> for MaskedRounds<'_, F, P> {
+    fn degree(&self) -> usize {
+        self.zk.inner.degree()
+    }
+
+    fn round(&mut self, challenge: Option<F>) -> Vec<F> {
+        let zk = &mut *self.zk;
+        if let Some(r) = challenge {
+            zk.bind(r);
+        }
+        let degree = zk.inner.degree();
+        let mut round_poly = zk.inner.round(challenge);
+        assert_eq!(
+            round_poly.len(),
+            degree,
+            "wrapped prover must send degree-d round polynomials"
+        );
+        for (h, m) in round_poly.iter_mut().zip(zk.mask_round(degree)) {
+            *h += self.rho * m;
+        }
+        zk.rounds_elapsed += 1;
+        round_poly
+    }
+
+    fn finalize(&mut self, last_challenge: F) {
+        self.zk.inner.finalize(last_challenge);
+        self.zk.bind(last_challenge);
+    }
+
+    fn final_value(&self) -> F {
+        self.zk.inner.final_value() + self.rho * self.zk.bound
+    }
+}
+
+/// Verifier side of the zero-knowledge sumcheck.
+///
+/// Checks the masked sumcheck for `claimed_sum` and the mask opening, and
+/// returns the challenges with the unmasked claim `g(r)`, which the caller
+/// must check.
+///
+/// Errors:
+/// - [`SumcheckError::TranscriptError`] (round 0, or round `num_vars` for
+///   the opening) if the commitment or a mask message is malformed.
+/// - [`SumcheckError::FinalEvaluation`] if the mask opening is rejected.
+/// - Any error from [`sumcheck_verify`].
+pub fn zk_sumcheck_verify<F, C, T>(
+    claimed_sum: F,
+    degree: usize,
+    num_vars: usize,
+    scheme: &C,
+    transcript: &mut T,
+) -> Result<SumcheckResult<F>, SumcheckError>
+where
+    F: SumcheckField,
+    C: MaskCommitment<F>,
+    T: VerifierTranscript<F>,
+{
+    let start = SumcheckError::TranscriptError { round: 0 };
+    let end = SumcheckError::TranscriptError { round: num_vars };
+
+    let commitment = scheme
+        .receive_commitment(num_vars, degree, transcript)
+        .map_err(|_| start)?;
+    let mask_sum = transcript.receive().map_err(|_| start)?;
+    let rho = transcript.challenge();
+
+    let result = sumcheck_verify(
+        claimed_sum + rho * mask_sum,
+        degree,
+        num_vars,
+        transcript,
+        |_, _| Ok(()),
+    )?;
+
+    let mask_value = transcript.receive().map_err(|_| end)?;
+    if !scheme
+        .verify_opening(&commitment, &result.challenges, mask_value, transcript)
+        .map_err(|_| end)?
+    {
+        return Err(SumcheckError::FinalEvaluation);
+    }
+
+    Ok(SumcheckResult {
+        challenges: result.challenges,
+        final_claim: result.final_claim - rho * mask_value,
+    })
+}
+
+#[cfg(all(test, feature = "arkworks"))]
+mod tests {
+    use super::*;
+    use crate::provers::eq_product::EqProductProver;
+    use crate::provers::inner_product::InnerProductProver;
+    use crate::provers::multilinear::MultilinearProver;
+    use crate::tests::{ReplayTranscript, F64};
+    use ark_ff::UniformRand;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    /// Toy digest for tests: Horner evaluation at a fixed point.
+    fn toy_hash(coeffs: &[F64]) -> F64 {
+        let x = F64::from(0x5eed_u64);
+        coeffs.iter().fold(F64::from(0u64), |acc, &c| acc * x + c)
+    }
+
+    fn random_vec(n: usize, rng: &mut StdRng) -> Vec<F64> {
+        (0..n).map(|_| F64::rand(rng)).collect()
+    }
+
+    #[test]
+    fn mask_sum_and_evaluation() {
+        let mut rng = StdRng::seed_from_u64(0xD00);
+        for v in 0..=5 {
+            let mask = MaskPolynomial::random(v, 3, || F64::rand(&mut rng));
+            let mut sum = F64::from(0u64);
+            for x in 0..1usize << v {
+                let point: Vec<F64> = (0..v)
+                    .map(|j| F64::from(((x >> (v - 1 - j)) & 1) as u64))
+                    .collect();
+                sum += mask.evaluate(&point);
+            }
+            assert_eq!(mask.sum_over_hypercube(), sum, "v={v}");
+            let coeffs = mask.to_coeffs();
+            assert_eq!(MaskPolynomial::from_coeffs(v, 3, &coeffs), mask);
+        }
+    }
+
+    /// The masked transcript verifies for every prover degree and reduces
+    /// to the unmasked claim `g(r)`.
+    #[test]
+    fn zk_roundtrip() {
+        let mut rng = StdRng::seed_from_u64(0xD01);
+        let scheme = HashCommitment::new(toy_hash);
+        for v in 1..=5 {
+            let f = random_vec(1 << v, &mut rng);
+            let g = random_vec(1 << v, &mut rng);
+            let w = random_vec(v, &mut rng);
+
+            let claim_ml: F64 = f.iter().sum();
+            let mut zk =
+                ZkSumcheck::new(MultilinearProver::new(f.clone()), v, || F64::rand(&mut rng));
+            let mut t = ReplayTranscript::new(0xD02);
+            let proof = zk.prove(&scheme, &mut t);
+            let mut vt = t.replay();
+            let result = zk_sumcheck_verify(claim_ml, 1, v, &scheme, &mut vt).unwrap();
+            assert_eq!(result.final_claim, proof.final_value, "v={v}");
+            assert_eq!(vt.cursor, vt.tape.len(), "verifier consumed the whole tape");
+
+            let claim_ip: F64 = f.iter().zip(&g).map(|(&a, &b)| a * b).sum();
+            let mut zk = ZkSumcheck::new(InnerProductProver::new(f.clone(), g.clone()), v, || {
+                F64::rand(&mut rng)
+            });
+            let mut t = ReplayTranscript::new(0xD03);
+            let proof = zk.prove(&scheme, &mut t);
+            let mut vt = t.replay();
+            let result = zk_sumcheck_verify(claim_ip, 2, v, &scheme, &mut vt).unwrap();
+            assert_eq!(result.final_claim, proof.final_value, "v={v}");
+
+            let eq = crate::provers::eq_factored::build_eq_table(&w);
+            let claim_ep: F64 = (0..1 << v).map(|i| eq[i] * f[i] * g[i]).sum();
+            let prover = EqProductProver::new(w.clone(), f.clone(), g.clone());
+            let mut zk = ZkSumcheck::new(prover, v, || F64::rand(&mut rng));
+            let mut t = ReplayTranscript::new(0xD04);
+            let proof = zk.prove(&scheme, &mut t);
+            let mut vt = t.replay();
+            let result = zk_sumcheck_verify(claim_ep, 3, v, &scheme, &mut vt).unwrap();
+            assert_eq!(result.final_claim, proof.final_value, "v={v}");
+        }
+    }
+
+    /// Masked round polynomials differ from the plain ones.
+    #[test]
+    fn zk_masks_round_polynomials() {
+        let mut rng = StdRng::seed_from_u64(0xD05);
+        let v = 4;
+        let f = random_vec(1 << v, &mut rng);
+        let g = random_vec(1 << v, &mut rng);
+
+        let mut plain = InnerProductProver::new(f.clone(), g.clone());
+        let mut t = ReplayTranscript::new(0xD06);
+        let plain_proof = sumcheck(&mut plain, v, &mut t, |_, _| {});
+
+        let mut zk = ZkSumcheck::new(InnerProductProver::new(f, g), v, || F64::rand(&mut rng));
+        let mut t = ReplayTranscript::new(0xD06);
+        let zk_proof = zk.prove(&HashCommitment::new(toy_hash), &mut t);
+        assert_ne!(zk_proof.round_polys[0], plain_proof.round_polys[0]);
+    }
+
+    #[test]
+    fn zk_rejects_wrong_claim_and_bad_opening() {
+        let mut rng = StdRng::seed_from_u64(0xD07);
+        let v = 4;
+        let f = random_vec(1 << v, &mut rng);
+        let g = random_vec(1 << v, &mut rng);
+        let claim: F64 = f.iter().zip(&g).map(|(&a, &b)| a * b).sum();
+        let scheme = HashCommitment::new(toy_hash);
+
+        let mut zk = ZkSumcheck::new(InnerProductProver::new(f, g), v, || F64::rand(&mut rng));
+        let mut t = ReplayTranscript::new(0xD08);
+        let proof = zk.prove(&scheme, &mut t);
+
+        // A wrong claim propagates to the unmasked final claim, which the
+        // caller's oracle check rejects.
+        let mut vt = t.replay();
+        let result = zk_sumcheck_verify(claim + F64::from(1u64), 2, v, &scheme, &mut vt).unwrap();
+        assert_ne!(result.final_claim, proof.final_value);
+
+        // Tamper with the last revealed mask coefficient.
+        let last = t.tape.len() - 1;
+        t.tape[last] += F64::from(1u64);
+        let mut vt = t.replay();
+        assert_eq!(
+            zk_sumcheck_verify(claim, 2, v, &scheme, &mut vt).unwrap_err(),
+            SumcheckError::FinalEvaluation
+        );
+    }
+}
@@ -1,7 +1,8 @@
 //! Adversarial tests for `sumcheck_verify`.
 //!
 //! Verifies that honest proofs are accepted and corrupted proofs are rejected
-//! across all three prover types: multilinear, inner product, and GKR.
+//! across the prover types: multilinear, inner product, eq-factored, GKR and
+//! coefficient, in both MSB and LSB layouts.
 
 use ark_ff::{AdditiveGroup, UniformRand};
 use ark_std::rand::{rngs::StdRng, SeedableRng};
@@ -11,74 +12,16 @@ use effsc::proof::SumcheckProof;
 use effsc::provers::inner_product::InnerProductProver;
 use effsc::provers::multilinear::MultilinearProver;
 use effsc::runner::sumcheck;
-use effsc::tests::F64;
-use effsc::transcript::{ProverTranscript, VerifierTranscript};
+use effsc::tests::{ReplayTranscript, F64};
 use effsc::verifier::sumcheck_verify;
 
-// ─── Replay transcript ────────────────────────────────────────────────────
-//
-// Records prover messages and challenges during proving, then replays them
-// to sumcheck_verify. Both sides see the same sequence of field elements.
-
-struct ReplayTranscript {
-    /// Recorded field elements (round poly evals interleaved with challenges).
-    tape: Vec<F64>,
-    /// Current read position for the verifier.
-    cursor: usize,
-    /// RNG for generating challenges.
-    rng: StdRng,
-}
-
-impl ReplayTranscript {
-    fn new(seed: u64) -> Self {
-        Self {
-            tape: Vec::new(),
-            cursor: 0,
-            rng: StdRng::seed_from_u64(seed),
-        }
-    }
-
-    /// Reset the cursor to replay from the beginning.
-    fn rewind(&mut self) {
-        self.cursor = 0;
-    }
-}
-
-impl ProverTranscript<F64> for ReplayTranscript {
-    fn send(&mut self, value: F64) {
-        self.tape.push(value);
-    }
-
-    fn challenge(&mut self) -> F64 {
-        let c = F64::rand(&mut self.rng);
-        self.tape.push(c);
-        c
-    }
-}
-
-impl VerifierTranscript<F64> for ReplayTranscript {
-    type Error = core::convert::Infallible;
-
-    fn receive(&mut self) -> Result<F64, Self::Error> {
-        let v = self.tape[self.cursor];
-        self.cursor += 1;
-        Ok(v)
-    }
-
-    fn challenge(&mut self) -> F64 {
-        let v = self.tape[self.cursor];
-        self.cursor += 1;
-        v
-    }
-}
-
 // ─── Helpers ───────────────────────────────────────────────────────────────
 
 const SEED: u64 = 0xAD0E_0001;
 
 fn make_multilinear_proof(
     num_vars: usize,
-    transcript: &mut ReplayTranscript,
+    transcript: &mut ReplayTranscript<F64>,
 ) -> (F64, SumcheckProof<F64>) {
     let n = 1 << num_vars;
     let mut rng = StdRng::seed_from_u64(SEED);
@@ -92,7 +35,7 @@ fn make_multilinear_proof(
 
 fn make_inner_product_proof(
     num_vars: usize,
-    transcript: &mut ReplayTranscript,
+    transcript: &mut ReplayTranscript<F64>,
 ) -> (F64, SumcheckProof<F64>) {
     let n = 1 << num_vars;
     let mut rng = StdRng::seed_from_u64(SEED);
@@ -243,7 +186,7 @@ mod gkr_tests {
 
     fn make_gkr_proof(
         k: usize,
-        transcript: &mut ReplayTranscript,
+        transcript: &mut ReplayTranscript<F64>,
     ) -> (F64, SumcheckProof<F64>, GkrProver<F64>) {
         let n = 1 << k;
         let n_bc = n * n;
@@ -354,7 +297,7 @@ mod eq_factored_tests {
 
     fn make_eq_factored_proof(
         v: usize,
-        transcript: &mut ReplayTranscript,
+        transcript: &mut ReplayTranscript<F64>,
     ) -> (F64, SumcheckProof<F64>) {
         let n = 1usize << v;
         let mut rng = StdRng::seed_from_u64(EQ_SEED);
@@ -468,7 +411,7 @@ mod coefficient_degree3_tests {
 
     fn make_triple_product_proof(
         v: usize,
-        transcript: &mut ReplayTranscript,
+        transcript: &mut ReplayTranscript<F64>,
     ) -> (F64, SumcheckProof<F64>) {
         let n = 1usize << v;
         let mut rng = StdRng::seed_from_u64(TRIPLE_SEED);
@@ -537,7 +480,7 @@ mod inner_product_lsb_tests {
 
     fn make_ip_lsb_proof(
         num_vars: usize,
-        transcript: &mut ReplayTranscript,
+        transcript: &mut ReplayTranscript<F64>,
     ) -> (F64, SumcheckProof<F64>) {
         let n = 1 << num_vars;
         let mut rng = StdRng::seed_from_u64(IP_LSB_SEED);
@@ -617,7 +560,7 @@ mod coefficient_lsb_degree3_tests {
 
     fn make_triple_product_lsb_proof(
         v: usize,
-        transcript: &mut ReplayTranscript,
+        transcript: &mut ReplayTranscript<F64>,
     ) -> (F64, SumcheckProof<F64>) {
         let n = 1usize << v;
         let mut rng = StdRng::seed_from_u64(TRIPLE_LSB_SEED);
@@ -665,3 +608,315 @@ mod coefficient_lsb_degree3_tests {
         assert_ne!(r.final_claim, proof.final_value);
     }
 }
+
+#[cfg(feature = "arkworks")]
+mod eq_factored_lsb_tests {
+    use super::*;
+    use effsc::provers::eq_factored_lsb::EqFactoredProverLSB;
+
+    const EQ_LSB_SEED: u64 = 0xEC_1B_C7_ED;
+
+    fn make_eq_factored_lsb_proof(
+        v: usize,
+        transcript: &mut ReplayTranscript<F64>,
+    ) -> (F64, SumcheckProof<F64>, EqFactoredProverLSB<F64>) {
+        let n = 1usize << v;
+        let mut rng = StdRng::seed_from_u64(EQ_LSB_SEED);
+        let w: Vec<F64> = (0..v).map(|_| F64::rand(&mut rng)).collect();
+        let p_evals: Vec<F64> = (0..n).map(|_| F64::rand(&mut rng)).collect();
+
+        // LSB layout: w[j] pairs with bit j of the index.
+        let eq_at_boolean = |x_bits: usize| -> F64 {
+            let mut acc = F64::from(1u64);
+            for (j, &wj) in w.iter().enumerate() {
+                acc *= if (x_bits >> j) & 1 == 1 {
+                    wj
+                } else {
+                    F64::from(1u64) - wj
+                };
+            }
+            acc
+        };
+        let claimed_sum: F64 = (0..n).map(|x| eq_at_boolean(x) * p_evals[x]).sum();
+
+        let mut prover = EqFactoredProverLSB::new(w, p_evals);
+        let proof = sumcheck(&mut prover, v, transcript, |_, _| {});
+        (claimed_sum, proof, prover)
+    }
+
+    #[test]
+    fn eq_factored_lsb_honest_proof_accepted() {
+        let v = 5;
+        let mut t = ReplayTranscript::new(0xE1);
+        let (claimed_sum, proof, prover) = make_eq_factored_lsb_proof(v, &mut t);
+
+        t.rewind();
+        let r = sumcheck_verify(claimed_sum, 2, v, &mut t, noop_hook_verify).unwrap();
+        assert_eq!(r.final_claim, proof.final_value);
+        let (p_r, eq_wr) = prover.final_factors();
+        assert_eq!(r.final_claim, p_r * eq_wr);
+    }
+
+    #[test]
+    fn eq_factored_lsb_corrupted_round_poly_rejected() {
+        let v = 5;
+        let mut t = ReplayTranscript::new(0xE1);
+        let (claimed_sum, proof, _prover) = make_eq_factored_lsb_proof(v, &mut t);
+
+        // Degree-2 EvalsInfty: 2 evals + 1 challenge per round. Corrupt q(∞)
+        // of round 3 at offset 3·3 + 1 = 10.
+        t.tape[10] += F64::from(1u64);
+
+        t.rewind();
+        let r = sumcheck_verify(claimed_sum, 2, v, &mut t, noop_hook_verify)
+            .expect("verifier returns Ok; oracle check catches it");
+        assert_ne!(r.final_claim, proof.final_value);
+    }
+
+    #[test]
+    fn eq_factored_lsb_wrong_claimed_sum_rejected() {
+        let v = 5;
+        let mut t = ReplayTranscript::new(0xE1);
+        let (claimed_sum, proof, _prover) = make_eq_factored_lsb_proof(v, &mut t);
+
+        t.rewind();
+        let r = sumcheck_verify(
+            claimed_sum + F64::from(1u64),
+            2,
+            v,
+            &mut t,
+            noop_hook_verify,
+        )
+        .expect("verifier returns Ok; oracle check catches it");
+        assert_ne!(r.final_claim, proof.final_value);
+    }
+}
+
+#[cfg(feature = "arkworks")]
+mod gkr_lsb_tests {
+    use super::*;
+    use effsc::provers::gkr_lsb::GkrProverLSB;
+
+    const GKR_LSB_SEED: u64 = 0xBEEF_1B70;
+
+    fn make_gkr_lsb_proof(
+        k: usize,
+        transcript: &mut ReplayTranscript<F64>,
+    ) -> (F64, SumcheckProof<F64>, GkrProverLSB<F64>) {
+        let n = 1 << k;
+        let n_bc = n * n;
+        let mut rng = StdRng::seed_from_u64(GKR_LSB_SEED);
+
+        let add_evals: Vec<F64> = (0..n_bc).map(|_| F64::rand(&mut rng)).collect();
+        let mult_evals: Vec<F64> = (0..n_bc).map(|_| F64::rand(&mut rng)).collect();
+        let w_evals: Vec<F64> = (0..n).map(|_| F64::rand(&mut rng)).collect();
+
+        let mut expected_sum = F64::ZERO;
+        for b in 0..n {
+            for c in 0..n {
+                let idx = b * n + c;
+                let wb = w_evals[b];
+                let wc = w_evals[c];
+                expected_sum += add_evals[idx] * (wb + wc) + mult_evals[idx] * (wb * wc);
+            }
+        }
+
+        let mut prover = GkrProverLSB::new(add_evals, mult_evals, w_evals);
+        let proof = sumcheck(&mut prover, 2 * k, transcript, |_, _| {});
+        (expected_sum, proof, prover)
+    }
+
+    #[test]
+    fn gkr_lsb_honest_proof_accepted() {
+        let k = 3;
+        let mut t = ReplayTranscript::new(0x1B99);
+        let (claimed_sum, proof, prover) = make_gkr_lsb_proof(k, &mut t);
+
+        t.rewind();
+        let r = sumcheck_verify(claimed_sum, 2, 2 * k, &mut t, noop_hook_verify).unwrap();
+        assert_eq!(r.final_claim, proof.final_value);
+        let (w_b, w_c) = prover.claimed_w_values();
+        assert_ne!(w_b, F64::ZERO);
+        assert_ne!(w_c, F64::ZERO);
+    }
+
+    #[test]
+    fn gkr_lsb_corrupted_round_poly_rejected() {
+        let k = 3;
+        let mut t = ReplayTranscript::new(0x1B99);
+        let (claimed_sum, proof, _prover) = make_gkr_lsb_proof(k, &mut t);
+
+        // Degree-2 EvalsInfty: 3 tape elements per round. Corrupt q(0) of
+        // round 3 (first b-bit round) at offset 3 * 3 = 9.
+        t.tape[9] += F64::from(1u64);
+
+        t.rewind();
+        let r = sumcheck_verify(claimed_sum, 2, 2 * k, &mut t, noop_hook_verify)
+            .expect("verifier returns Ok even under corruption");
+        assert_ne!(r.final_claim, proof.final_value);
+    }
+
+    #[test]
+    fn gkr_lsb_wrong_claimed_sum_rejected() {
+        let k = 3;
+        let mut t = ReplayTranscript::new(0x1B99);
+        let (claimed_sum, proof, _prover) = make_gkr_lsb_proof(k, &mut t);
+
+        t.rewind();
+        let r = sumcheck_verify(
+            claimed_sum + F64::from(1u64),
+            2,
+            2 * k,
+            &mut t,
+            noop_hook_verify,
+        )
+        .expect("verifier returns Ok even with wrong claimed sum");
+        assert_ne!(r.final_claim, proof.final_value);
+    }
+}
+
+// ─── Per-round degree schedules ───────────────────────────────────────────
+//
+// `g(x) = a(x) · b(x_0)`: degree 2 in the first variable, 1 in the rest.
+// The prover sends each round at its own degree.
+
+mod degree_schedule_tests {
+    use super::*;
+    use effsc::sumcheck_prover::SumcheckProver;
+    use effsc::verifier::sumcheck_verify_with_degrees;
+
+    const SCHEDULE_SEED: u64 = 0xDE6_5C4E;
+
+    struct SelectorProver {
+        inner: InnerProductProver<F64>,
+        rounds_elapsed: usize,
+    }
+
+    impl SumcheckProver<F64> for SelectorProver {
+        fn degree(&self) -> usize {
+            2
+        }
+
+        fn round_degree(&self, round: usize) -> usize {
+            if round == 0 {
+                2
+            } else {
+                1
+            }
+        }
+
+        fn round(&mut self, challenge: Option<F64>) -> Vec<F64> {
+            let mut evals = self.inner.round(challenge);
+            let d = self.round_degree(self.rounds_elapsed);
+            // Rounds after the first have zero leading coefficient: b no
+            // longer depends on the bound variable.
+            assert!(evals[d..].iter().all(|&e| e == F64::ZERO));
+            evals.truncate(d);
+            self.rounds_elapsed += 1;
+            evals
+        }
+
+        fn finalize(&mut self, last_challenge: F64) {
+            self.inner.finalize(last_challenge);
+        }
+
+        fn final_value(&self) -> F64 {
+            self.inner.final_value()
+        }
+    }
+
+    fn make_selector_proof(
+        v: usize,
+        transcript: &mut ReplayTranscript<F64>,
+    ) -> (F64, SumcheckProof<F64>) {
+        let n = 1 << v;
+        let mut rng = StdRng::seed_from_u64(SCHEDULE_SEED);
+        let a: Vec<F64> = (0..n).map(|_| F64::rand(&mut rng)).collect();
+        let (s0, s1) = (F64::rand(&mut rng), F64::rand(&mut rng));
+        let b: Vec<F64> = (0..n).map(|i| if i < n / 2 { s0 } else { s1 }).collect();
+        let claimed_sum: F64 = a.iter().zip(&b).map(|(&x, &y)| x * y).sum();
+
+        let mut prover = SelectorProver {
+            inner: InnerProductProver::new(a, b),
+            rounds_elapsed: 0,
+        };
+        let proof = sumcheck(&mut prover, v, transcript, |_, _| {});
+        (claimed_sum, proof)
+    }
+
+    #[test]
+    fn mixed_degree_honest_proof_accepted() {
+        let v = 5;
+        let mut t = ReplayTranscript::new(0xD5);
+        let (claimed_sum, proof) = make_selector_proof(v, &mut t);
+
+        let wire: usize = proof.round_polys.iter().map(Vec::len).sum();
+        assert_eq!(wire, 2 + (v - 1), "one element saved per degree-1 round");
+
+        let schedule = [2, 1, 1, 1, 1];
+        t.rewind();
+        let r = sumcheck_verify_with_degrees(claimed_sum, &schedule, v, &mut t, noop_hook_verify)
+            .unwrap();
+        assert_eq!(r.final_claim, proof.final_value);
+        assert_eq!(r.challenges, proof.challenges);
+
+        t.rewind();
+        let by_closure = |j: usize| if j == 0 { 2 } else { 1 };
+        let r = sumcheck_verify_with_degrees(claimed_sum, &by_closure, v, &mut t, noop_hook_verify)
+            .unwrap();
+        assert_eq!(r.final_claim, proof.final_value);
+
+        t.rewind();
+        let r = sumcheck_verify_with_degrees(
+            claimed_sum,
+            schedule.as_slice(),
+            v,
+            &mut t,
+            noop_hook_verify,
+        )
+        .unwrap();
+        assert_eq!(r.final_claim, proof.final_value);
+    }
+
+    #[test]
+    fn mixed_degree_corrupted_round_poly_rejected() {
+        let v = 5;
+        let mut t = ReplayTranscript::new(0xD5);
+        let (claimed_sum, proof) = make_selector_proof(v, &mut t);
+
+        // Tape: [q0, q∞, r0] then [h0, r_j] per degree-1 round. Corrupt h(0)
+        // of round 2 at offset 3 + 2 = 5.
+        t.tape[5] += F64::from(1u64);
+
+        t.rewind();
+        let r = sumcheck_verify_with_degrees(
+            claimed_sum,
+            &[2, 1, 1, 1, 1],
+            v,
+            &mut t,
+            noop_hook_verify,
+        )
+        .expect("verifier returns Ok; oracle check catches it");
+        assert_ne!(r.final_claim, proof.final_value);
+    }
+
+    #[test]
+    fn mixed_degree_wrong_schedule_rejected() {
+        let v = 5;
+        let mut t = ReplayTranscript::new(0xD5);
+        let (claimed_sum, proof) = make_selector_proof(v, &mut t);
+
+        // Reading round 0 at degree 1 misaligns every later round.
+        t.tape.extend([F64::ZERO; 4]);
+        t.rewind();
+        let r = sumcheck_verify_with_degrees(
+            claimed_sum,
+            &[1, 2, 1, 1, 1],
+            v,
+            &mut t,
+            noop_hook_verify,
+        )
+        .expect("verifier returns Ok; oracle check catches it");
+        assert_ne!(r.final_claim, proof.final_value);
+    }
+}
@@ -0,0 +1,221 @@
+//! Characteristic-2 sumcheck over the reference `Gf2_128` field.
+//!
+//! Runs the canonical provers (including the degree-3 eq-product and
+//! coefficient provers, which send evaluations at node 2) and checks that
+//! `sumcheck_verify` accepts honest proofs and rejects tampered ones.
+
+use ark_std::rand::{rngs::StdRng, RngCore, SeedableRng};
+
+use effsc::binary_field::Gf2_128;
+use effsc::coefficient_sumcheck::RoundPolyEvaluator;
+use effsc::field::SumcheckField;
+use effsc::proof::SumcheckError;
+use effsc::provers::coefficient::CoefficientProver;
+use effsc::provers::eq_factored::EqFactoredProver;
+use effsc::provers::eq_product::EqProductProver;
+use effsc::provers::gkr::GkrProver;
+use effsc::provers::inner_product::InnerProductProver;
+use effsc::provers::multilinear::MultilinearProver;
+use effsc::runner::sumcheck;
+use effsc::sumcheck_prover::SumcheckProver;
+use effsc::transcript::{ProverTranscript, VerifierTranscript};
+use effsc::verifier::sumcheck_verify;
+
+// ─── Replay transcript ────────────────────────────────────────────────────
+
+/// Records prover messages and uniformly random GF(2^128) challenges, then
+/// replays them to the verifier.
+struct ReplayTranscript {
+    tape: Vec<Gf2_128>,
+    cursor: usize,
+    rng: StdRng,
+}
+
+impl ReplayTranscript {
+    fn new(seed: u64) -> Self {
+        Self {
+            tape: Vec::new(),
+            cursor: 0,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl ProverTranscript<Gf2_128> for ReplayTranscript {
+    fn send(&mut self, value: Gf2_128) {
+        self.tape.push(value);
+    }
+
+    fn challenge(&mut self) -> Gf2_128 {
+        let c = random(&mut self.rng);
+        self.tape.push(c);
+        c
+    }
+}
+
+impl VerifierTranscript<Gf2_128> for ReplayTranscript {
+    type Error = ();
+
+    fn receive(&mut self) -> Result<Gf2_128, ()> {
+        let v = self.tape.get(self.cursor).copied().ok_or(())?;
+        self.cursor += 1;
+        Ok(v)
+    }
+
+    fn challenge(&mut self) -> Gf2_128 {
+        let v = self.tape[self.cursor];
+        self.cursor += 1;
+        v
+    }
+}
+
+// ─── Helpers ───────────────────────────────────────────────────────────────
+
+const SEED: u64 = 0xB1_0002;
+
+fn random(rng: &mut StdRng) -> Gf2_128 {
+    Gf2_128::new(((rng.next_u64() as u128) << 64) | rng.next_u64() as u128)
+}
+
+fn random_vec(n: usize, rng: &mut StdRng) -> Vec<Gf2_128> {
+    (0..n).map(|_| random(rng)).collect()
+}
+
+/// `eq(w, x)` over `{0,1}^v` (MSB: `w[0]` pairs with the top index bit).
+fn eq_table(w: &[Gf2_128]) -> Vec<Gf2_128> {
+    let v = w.len();
+    (0..1usize << v)
+        .map(|x| {
+            (0..v)
+                .map(|j| {
+                    if (x >> (v - 1 - j)) & 1 == 1 {
+                        w[j]
+                    } else {
+                        Gf2_128::ONE - w[j]
+                    }
+                })
+                .fold(Gf2_128::ONE, |acc, t| acc * t)
+        })
+        .collect()
+}
+
+/// Prove, verify the honest transcript, then verify a tampered one.
+fn assert_roundtrip(prover: &mut impl SumcheckProver<Gf2_128>, claim: Gf2_128, num_vars: usize) {
+    let degree = prover.degree();
+    let mut t = ReplayTranscript::new(SEED);
+    let proof = sumcheck(prover, num_vars, &mut t, |_, _| {});
+
+    t.cursor = 0;
+    let result = sumcheck_verify(claim, degree, num_vars, &mut t, |_, _| Ok(()))
+        .unwrap_or_else(|e| panic!("degree {degree}: {e}"));
+    assert_eq!(result.final_claim, proof.final_value, "degree {degree}");
+
+    // Flip the last wire element of round 0.
+    t.tape[degree.max(1) - 1] += Gf2_128::ONE;
+    t.cursor = 0;
+    match sumcheck_verify(claim, degree, num_vars, &mut t, |_, _| Ok(())) {
+        Ok(result) => assert_ne!(result.final_claim, proof.final_value),
+        Err(e) => assert!(matches!(e, SumcheckError::ConsistencyCheck { .. })),
+    }
+}
+
+/// `a · b · c` over three pairwise tables (degree 3).
+struct TripleProduct;
+
+impl RoundPolyEvaluator<Gf2_128> for TripleProduct {
+    fn degree(&self) -> usize {
+        3
+    }
+
+    fn accumulate_pair(
+        &self,
+        coeffs: &mut [Gf2_128],
+        _tablewise: &[(&[Gf2_128], &[Gf2_128])],
+        pw: &[(Gf2_128, Gf2_128)],
+    ) {
+        let lin = |(e, o): (Gf2_128, Gf2_128)| (e, o - e);
+        let ((a0, a1), (b0, b1), (c0, c1)) = (lin(pw[0]), lin(pw[1]), lin(pw[2]));
+        coeffs[0] += a0 * b0 * c0;
+        coeffs[1] += a1 * b0 * c0 + a0 * b1 * c0 + a0 * b0 * c1;
+        coeffs[2] += a1 * b1 * c0 + a1 * b0 * c1 + a0 * b1 * c1;
+        coeffs[3] += a1 * b1 * c1;
+    }
+
+    fn parallelize(&self) -> bool {
+        false
+    }
+}
+
+// ─── Tests ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn eval_nodes_are_distinct() {
+    let nodes: Vec<Gf2_128> = (0..16).map(Gf2_128::eval_node).collect();
+    assert_eq!(nodes[0], Gf2_128::ZERO);
+    assert_eq!(nodes[1], Gf2_128::ONE);
+    for i in 0..nodes.len() {
+        for j in 0..i {
+            assert_ne!(nodes[i], nodes[j]);
+        }
+    }
+}
+
+#[test]
+fn degree_one_and_two_provers() {
+    let mut rng = StdRng::seed_from_u64(SEED);
+    for v in 1..=6 {
+        let a = random_vec(1 << v, &mut rng);
+        let b = random_vec(1 << v, &mut rng);
+        let w = random_vec(v, &mut rng);
+
+        let claim: Gf2_128 = a.iter().copied().sum();
+        assert_roundtrip(&mut MultilinearProver::new(a.clone()), claim, v);
+
+        let claim: Gf2_128 = a.iter().zip(&b).map(|(&x, &y)| x * y).sum();
+        assert_roundtrip(&mut InnerProductProver::new(a.clone(), b.clone()), claim, v);
+
+        let claim: Gf2_128 = eq_table(&w).iter().zip(&a).map(|(&e, &x)| e * x).sum();
+        assert_roundtrip(&mut EqFactoredProver::new(w, a), claim, v);
+    }
+}
+
+#[test]
+fn gkr_prover_without_division_by_two() {
+    let mut rng = StdRng::seed_from_u64(SEED ^ 1);
+    for k in 1..=3 {
+        let n = 1 << k;
+        let add = random_vec(n * n, &mut rng);
+        let mult = random_vec(n * n, &mut rng);
+        let w = random_vec(n, &mut rng);
+        let mut claim = Gf2_128::ZERO;
+        for b in 0..n {
+            for c in 0..n {
+                claim += add[b * n + c] * (w[b] + w[c]) + mult[b * n + c] * (w[b] * w[c]);
+            }
+        }
+        assert_roundtrip(&mut GkrProver::new(add, mult, w), claim, 2 * k);
+    }
+}
+
+#[test]
+fn degree_three_provers_use_node_two() {
+    let mut rng = StdRng::seed_from_u64(SEED ^ 2);
+    for v in 1..=5 {
+        let a = random_vec(1 << v, &mut rng);
+        let b = random_vec(1 << v, &mut rng);
+        let c = random_vec(1 << v, &mut rng);
+        let w = random_vec(v, &mut rng);
+
+        let eq = eq_table(&w);
+        let claim: Gf2_128 = (0..1 << v).map(|i| eq[i] * a[i] * b[i]).sum();
+        assert_roundtrip(&mut EqProductProver::new(w, a.clone(), b.clone()), claim, v);
+
+        let claim: Gf2_128 = (0..1 << v).map(|i| a[i] * b[i] * c[i]).sum();
+        let evaluator = TripleProduct;
+        assert_roundtrip(
+            &mut CoefficientProver::new(&evaluator, vec![], vec![a, b, c]),
+            claim,
+            v,
+        );
+    }
+}
@@ -0,0 +1,342 @@
+//! Tests for `verify_proof`: checking a stored `SumcheckProof` by replaying
+//! its round polynomials through a fresh Fiat–Shamir transcript.
+
+use ark_ff::UniformRand;
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+use effsc::proof::{SumcheckError, SumcheckProof, VerifierError};
+use effsc::provers::eq_product::EqProductProver;
+use effsc::provers::inner_product::InnerProductProver;
+use effsc::provers::multilinear::MultilinearProver;
+use effsc::runner::sumcheck;
+use effsc::sumcheck_prover::SumcheckProver;
+use effsc::tests::{ChainTranscript, ReplayTranscript, F64};
+use effsc::transcript::VerifierTranscript;
+use effsc::verifier::{
+    sumcheck_verify_detailed, verify_proof, verify_proof_detailed, verify_round_polys,
+};
+
+// ─── Helpers ───────────────────────────────────────────────────────────────
+
+const DOMAIN: u64 = 0x5EED;
+
+fn random_vec(n: usize, rng: &mut StdRng) -> Vec<F64> {
+    (0..n).map(|_| F64::rand(rng)).collect()
+}
+
+/// `eq(w, x)` over `{0,1}^v` (MSB: `w[0]` pairs with the top index bit).
+fn eq_table(w: &[F64]) -> Vec<F64> {
+    let v = w.len();
+    (0..1usize << v)
+        .map(|x| {
+            (0..v)
+                .map(|j| {
+                    if (x >> (v - 1 - j)) & 1 == 1 {
+                        w[j]
+                    } else {
+                        F64::from(1u64) - w[j]
+                    }
+                })
+                .product()
+        })
+        .collect()
+}
+
+fn prove(prover: &mut impl SumcheckProver<F64>, num_vars: usize) -> SumcheckProof<F64> {
+    sumcheck(
+        prover,
+        num_vars,
+        &mut ChainTranscript::new(DOMAIN),
+        |_, _| {},
+    )
+}
+
+fn inner_product_proof(num_vars: usize) -> (SumcheckProof<F64>, F64) {
+    let mut rng = StdRng::seed_from_u64(0x38 ^ num_vars as u64);
+    let a = random_vec(1 << num_vars, &mut rng);
+    let b = random_vec(1 << num_vars, &mut rng);
+    let claim = a.iter().zip(&b).map(|(x, y)| *x * y).sum();
+    (prove(&mut InnerProductProver::new(a, b), num_vars), claim)
+}
+
+fn verify(proof: &SumcheckProof<F64>, claim: F64, degree: usize) -> Result<F64, SumcheckError> {
+    verify_proof(proof, claim, degree, &mut ChainTranscript::new(DOMAIN)).map(|r| {
+        assert_eq!(r.challenges, proof.challenges);
+        r.final_claim
+    })
+}
+
+// ─── Tests ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn honest_proofs_accepted() {
+    let mut rng = StdRng::seed_from_u64(0x380);
+    for v in 0..=6 {
+        let a = random_vec(1 << v, &mut rng);
+        let b = random_vec(1 << v, &mut rng);
+        let w = random_vec(v, &mut rng);
+
+        let claim: F64 = a.iter().sum();
+        let proof = prove(&mut MultilinearProver::new(a.clone()), v);
+        assert_eq!(verify(&proof, claim, 1), Ok(proof.final_value));
+
+        let (proof, claim) = inner_product_proof(v);
+        assert_eq!(verify(&proof, claim, 2), Ok(proof.final_value));
+
+        let eq = eq_table(&w);
+        let claim = (0..1 << v).map(|i| eq[i] * a[i] * b[i]).sum();
+        let proof = prove(&mut EqProductProver::new(w, a, b), v);
+        assert_eq!(verify(&proof, claim, 3), Ok(proof.final_value));
+    }
+}
+
+#[test]
+fn wrong_claimed_sum_rejected() {
+    let (proof, claim) = inner_product_proof(5);
+    assert!(verify(&proof, claim + F64::from(1u64), 2).is_err());
+}
+
+#[test]
+fn tampered_challenge_rejected() {
+    let (mut proof, claim) = inner_product_proof(5);
+    proof.challenges[3] += F64::from(1u64);
+    assert_eq!(
+        verify(&proof, claim, 2),
+        Err(SumcheckError::ChallengeMismatch { round: 3 })
+    );
+
+    let (mut proof, claim) = inner_product_proof(5);
+    proof.challenges.pop();
+    assert_eq!(
+        verify(&proof, claim, 2),
+        Err(SumcheckError::ChallengeMismatch { round: 4 })
+    );
+}
+
+#[test]
+fn tampered_round_poly_changes_challenges() {
+    // Changing g_2(∞) moves every later challenge, so the stored ones no
+    // longer match even though the round-2 sum check still holds.
+    let (mut proof, claim) = inner_product_proof(5);
+    proof.round_polys[2][1] += F64::from(1u64);
+    assert_eq!(
+        verify(&proof, claim, 2),
+        Err(SumcheckError::ChallengeMismatch { round: 2 })
+    );
+}
+
+#[test]
+fn tampered_final_value_rejected() {
+    let (mut proof, claim) = inner_product_proof(5);
+    proof.final_value += F64::from(1u64);
+    assert_eq!(
+        verify(&proof, claim, 2),
+        Err(SumcheckError::FinalEvaluation)
+    );
+}
+
+#[test]
+fn wrong_shape_rejected() {
+    let (mut proof, claim) = inner_product_proof(4);
+    proof.round_polys[1].push(F64::from(0u64));
+    assert_eq!(
+        verify(&proof, claim, 2),
+        Err(SumcheckError::DegreeMismatch {
+            round: 1,
+            expected: 2,
+            got: 3
+        })
+    );
+}
+
+#[test]
+fn fresh_transcript_domain_matters() {
+    let (proof, claim) = inner_product_proof(3);
+    let result = verify_proof(&proof, claim, 2, &mut ChainTranscript::new(DOMAIN + 1));
+    assert_eq!(
+        result.unwrap_err(),
+        SumcheckError::ChallengeMismatch { round: 0 }
+    );
+}
+
+#[test]
+fn detailed_errors_carry_both_sides() {
+    let detailed = |proof: &SumcheckProof<F64>, claim| {
+        let result = verify_proof_detailed(proof, claim, 2, &mut ChainTranscript::new(DOMAIN));
+        // The plain verifier reports the same kind and round.
+        assert_eq!(
+            result
+                .clone()
+                .map(|r| r.final_claim)
+                .map_err(SumcheckError::from),
+            verify(proof, claim, 2)
+        );
+        result
+    };
+
+    let (honest, claim) = inner_product_proof(5);
+    let mut proof = honest.clone();
+    proof.challenges[3] += F64::from(1u64);
+    assert_eq!(
+        detailed(&proof, claim).unwrap_err(),
+        VerifierError::ChallengeMismatch {
+            round: 3,
+            expected: Some(honest.challenges[3]),
+            actual: Some(proof.challenges[3]),
+        }
+    );
+
+    let mut proof = honest.clone();
+    proof.challenges.pop();
+    assert_eq!(
+        detailed(&proof, claim).unwrap_err(),
+        VerifierError::ChallengeMismatch {
+            round: 4,
+            expected: Some(honest.challenges[4]),
+            actual: None,
+        }
+    );
+
+    let mut proof = honest.clone();
+    proof.challenges.push(F64::from(9u64));
+    assert_eq!(
+        detailed(&proof, claim).unwrap_err(),
+        VerifierError::ChallengeMismatch {
+            round: 5,
+            expected: None,
+            actual: Some(F64::from(9u64)),
+        }
+    );
+
+    let mut proof = honest.clone();
+    proof.final_value += F64::from(1u64);
+    assert_eq!(
+        detailed(&proof, claim).unwrap_err(),
+        VerifierError::FinalEvaluation {
+            expected: honest.final_value,
+            actual: proof.final_value,
+        }
+    );
+}
+
+#[test]
+fn round_poly_slices_are_shape_checked() {
+    // A degree-0 round still carries one value.
+    let empty: [&[F64]; 1] = [&[]];
+    assert_eq!(
+        verify_round_polys(
+            F64::from(0u64),
+            0,
+            &empty,
+            &mut ChainTranscript::new(DOMAIN)
+        )
+        .unwrap_err(),
+        VerifierError::DegreeMismatch {
+            round: 0,
+            expected: 1,
+            got: 0,
+        }
+    );
+
+    let (proof, claim) = inner_product_proof(4);
+    let result = verify_round_polys(
+        claim,
+        2,
+        &proof.round_polys,
+        &mut ChainTranscript::new(DOMAIN),
+    )
+    .unwrap();
+    assert_eq!(result.challenges, proof.challenges);
+    assert_eq!(result.final_claim, proof.final_value);
+
+    // Borrowed slices work too; a short round is caught before absorbing it.
+    let mut rounds: Vec<&[F64]> = proof.round_polys.iter().map(Vec::as_slice).collect();
+    rounds[2] = &rounds[2][..1];
+    assert_eq!(
+        verify_round_polys(claim, 2, &rounds, &mut ChainTranscript::new(DOMAIN)).unwrap_err(),
+        VerifierError::DegreeMismatch {
+            round: 2,
+            expected: 2,
+            got: 1,
+        }
+    );
+}
+
+/// Transcript error with its own type, to check it survives verification.
+#[derive(Debug, PartialEq)]
+struct TapeExhausted {
+    position: usize,
+}
+
+impl std::fmt::Display for TapeExhausted {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "tape exhausted at {}", self.position)
+    }
+}
+
+impl std::error::Error for TapeExhausted {}
+
+struct StrictTranscript(ReplayTranscript<F64>);
+
+impl VerifierTranscript<F64> for StrictTranscript {
+    type Error = TapeExhausted;
+
+    fn receive(&mut self) -> Result<F64, TapeExhausted> {
+        let position = self.0.cursor;
+        self.0.receive().map_err(|()| TapeExhausted { position })
+    }
+
+    fn challenge(&mut self) -> F64 {
+        VerifierTranscript::challenge(&mut self.0)
+    }
+}
+
+#[test]
+fn detailed_verifier_preserves_transcript_and_hook_errors() {
+    let mut rng = StdRng::seed_from_u64(0x440);
+    let a = random_vec(8, &mut rng);
+    let b = random_vec(8, &mut rng);
+    let claim = a.iter().zip(&b).map(|(x, y)| *x * y).sum();
+    let mut t = ReplayTranscript::new(0x441);
+    sumcheck(&mut InnerProductProver::new(a, b), 3, &mut t, |_, _| {});
+
+    // Three rounds of 2 values + 1 challenge: round 3 finds the tape empty.
+    let err = sumcheck_verify_detailed(
+        claim,
+        &|_| 2,
+        4,
+        &mut StrictTranscript(t.replay()),
+        |_, _| Ok(()),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        VerifierError::Transcript {
+            round: 3,
+            source: TapeExhausted { position: 9 },
+        }
+    );
+    let source = std::error::Error::source(&err).unwrap();
+    assert_eq!(source.to_string(), "tape exhausted at 9");
+    assert_eq!(
+        SumcheckError::from(err),
+        SumcheckError::TranscriptError { round: 3 }
+    );
+
+    let err = sumcheck_verify_detailed(claim, &|_| 2, 3, &mut t.replay(), |round, _| {
+        if round == 1 {
+            Err(SumcheckError::HookError { round })
+        } else {
+            Ok(())
+        }
+    })
+    .unwrap_err();
+    assert_eq!(
+        err,
+        VerifierError::Hook {
+            round: 1,
+            source: SumcheckError::HookError { round: 1 },
+        }
+    );
+    assert_eq!(err.to_string(), "round 1: hook error: round 1: hook error");
+}